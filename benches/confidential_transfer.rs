@@ -6,7 +6,7 @@ use zero_elgamal::EncryptedNumber;
 use zero_jubjub::*;
 use zero_kzg::KeyPair;
 use zero_pairing::TatePairing;
-use zero_plonk::prelude::*;
+use zero_r1cs::prelude::*;
 use zkstd::common::{CurveGroup, Group};
 
 #[allow(unused_must_use)]
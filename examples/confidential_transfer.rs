@@ -16,7 +16,7 @@ use sp_runtime::{
 use zero_elgamal::EncryptedNumber;
 use zero_jubjub::{Fp, JubjubAffine, JubjubExtended};
 use zero_pairing::TatePairing;
-use zero_plonk::prelude::Compiler;
+use zero_r1cs::prelude::Compiler;
 use zkstd::behave::Group;
 use zkstd::common::CurveGroup;
 
@@ -4,7 +4,7 @@ use pallet::*;
 use pallet_plonk::{BlsScalar, Circuit, FullcodecRng, Proof};
 use zero_jubjub::{Fp as JubJubScalar, JubjubAffine, JubjubExtended};
 use zero_pairing::TatePairing;
-use zero_plonk::{composer::Composer, prelude::*};
+use zero_r1cs::prelude::*;
 use zkstd::common::{CurveGroup, Pairing};
 
 use frame_support::{assert_ok, construct_runtime, parameter_types};
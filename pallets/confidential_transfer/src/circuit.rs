@@ -1,25 +1,41 @@
-use zero_elgamal::{ConfidentialTransferPublicInputs, EncryptedNumber};
+use zero_elgamal::{
+    decrypt_gadget, encrypt_gadget, ConfidentialTransferPublicInputs, EncryptedNumber,
+};
 use zero_jubjub::{Fp as JubJubScalar, JubjubAffine};
 use zero_pairing::TatePairing;
-use zero_plonk::prelude::*;
-use zkstd::common::{Curve, CurveGroup, Decode, Encode, Group, Pairing};
+use zero_r1cs::prelude::*;
+use zkstd::common::{CurveGroup, Decode, Encode, Pairing, Vec};
 
 pub const BALANCE_BITS: usize = 16;
-pub const CONFIDENTIAL_TRANSFER_PUBLIC_INPUT_LENGTH: usize = 8;
 
 /// Confidential transfer circuit
-#[derive(Debug, PartialEq)]
+///
+/// The public points are declared under the names their public inputs are
+/// serialized with by [`ConfidentialTransferTransaction`]
+#[derive(Circuit, Debug, PartialEq)]
 pub struct ConfidentialTransferCircuit {
+    #[public_point]
+    sender_t_encrypted_transfer_amount: JubjubAffine,
+    #[public_point]
+    recipient_encrypted_transfer_amount: JubjubAffine,
+    #[public_point]
+    sender_s_encrypted_transfer_amount: JubjubAffine,
+    #[public_point]
     sender_public_key: JubjubAffine,
+    #[point]
     recipient_public_key: JubjubAffine,
-    sender_encrypted_balance: EncryptedNumber,
-    sender_encrypted_transfer_amount: EncryptedNumber,
-    recipient_encrypted_transfer_amount: JubjubAffine,
+    #[point]
+    sender_t_encrypted_balance: JubjubAffine,
+    #[point]
+    sender_s_encrypted_balance: JubjubAffine,
+    #[witness]
     sender_private_key: JubJubScalar,
+    #[witness]
     transfer_amount: JubJubScalar,
+    #[witness]
     sender_after_balance: JubJubScalar,
+    #[witness]
     randomness: JubJubScalar,
-    bits: usize,
 }
 
 impl ConfidentialTransferCircuit {
@@ -37,34 +53,23 @@ impl ConfidentialTransferCircuit {
         sender_after_balance: JubJubScalar,
         randomness: JubJubScalar,
     ) -> Self {
+        let (sender_t_encrypted_balance, sender_s_encrypted_balance) =
+            sender_encrypted_balance.get();
+        let (sender_t_encrypted_transfer_amount, sender_s_encrypted_transfer_amount) =
+            sender_encrypted_transfer_amount.get();
+
         Self {
+            sender_t_encrypted_transfer_amount,
+            recipient_encrypted_transfer_amount,
+            sender_s_encrypted_transfer_amount,
             sender_public_key,
             recipient_public_key,
-            sender_encrypted_balance,
-            sender_encrypted_transfer_amount,
-            recipient_encrypted_transfer_amount,
+            sender_t_encrypted_balance,
+            sender_s_encrypted_balance,
             sender_private_key,
             transfer_amount,
             sender_after_balance,
             randomness,
-            bits: BALANCE_BITS,
-        }
-    }
-}
-
-impl Default for ConfidentialTransferCircuit {
-    fn default() -> Self {
-        Self {
-            sender_public_key: JubjubAffine::ADDITIVE_IDENTITY,
-            recipient_public_key: JubjubAffine::ADDITIVE_IDENTITY,
-            sender_encrypted_balance: EncryptedNumber::default(),
-            sender_encrypted_transfer_amount: EncryptedNumber::default(),
-            recipient_encrypted_transfer_amount: JubjubAffine::ADDITIVE_IDENTITY,
-            sender_private_key: JubJubScalar::ADDITIVE_IDENTITY,
-            transfer_amount: JubJubScalar::ADDITIVE_IDENTITY,
-            sender_after_balance: JubJubScalar::ADDITIVE_IDENTITY,
-            randomness: JubJubScalar::ADDITIVE_IDENTITY,
-            bits: BALANCE_BITS,
         }
     }
 }
@@ -74,68 +79,60 @@ impl Circuit<TatePairing> for ConfidentialTransferCircuit {
     where
         C: Composer<TatePairing>,
     {
-        let (alice_t_balance, alice_s_balance) = self.sender_encrypted_balance.get();
-        let (alice_t_transfer_amount, alice_s_transfer_amount) =
-            self.sender_encrypted_transfer_amount.get();
-        let sender_public_key = composer.append_point(self.sender_public_key);
-        let recipient_public_key = composer.append_point(self.recipient_public_key);
-        let alice_t_encrypted_balance = composer.append_point(alice_t_balance);
-        let alice_s_encrypted_balance = composer.append_point(alice_s_balance);
-        let alice_t_encrypted_transfer_amount = composer.append_point(alice_t_transfer_amount);
-        let alice_s_encrypted_transfer_amount = composer.append_point(alice_s_transfer_amount);
-        let sender_private_key = composer.append_witness(self.sender_private_key);
-        let transfer_amount = composer.append_witness(self.transfer_amount);
-        let sender_after_balance = composer.append_witness(self.sender_after_balance);
-        let randomness = composer.append_witness(self.randomness);
-        let neg = composer.append_witness(-JubJubScalar::one());
-
-        // Alice left encrypted transfer check
-        let g_pow_balance = composer
-            .component_mul_generator(transfer_amount, JubjubExtended::ADDITIVE_GENERATOR)?;
-        let alice_pk_powered_by_randomness =
-            composer.component_mul_point(randomness, sender_public_key);
-        let s_alice_transfer =
-            composer.component_add_point(g_pow_balance, alice_pk_powered_by_randomness);
-        composer.assert_equal_public_point(s_alice_transfer, alice_t_transfer_amount);
+        let inputs = self.append_inputs(composer);
+
+        // Alice encrypted transfer check
+        let (alice_t_transfer, alice_s_transfer) = encrypt_gadget(
+            composer,
+            inputs.sender_public_key,
+            inputs.transfer_amount,
+            inputs.randomness,
+        )?;
+        composer.assert_equal_point(alice_t_transfer, inputs.sender_t_encrypted_transfer_amount);
+        composer.assert_equal_point(alice_s_transfer, inputs.sender_s_encrypted_transfer_amount);
 
         // Bob left encrypted transfer check
-        let bob_pk_powered_by_randomness =
-            composer.component_mul_point(randomness, recipient_public_key);
-        let s_bob_transfer =
-            composer.component_add_point(g_pow_balance, bob_pk_powered_by_randomness);
-        composer
-            .assert_equal_public_point(s_bob_transfer, self.recipient_encrypted_transfer_amount);
-
-        // Alice right encrypted transfer check
-        let g_pow_randomness =
-            composer.component_mul_generator(randomness, JubjubExtended::ADDITIVE_GENERATOR)?;
-        composer.assert_equal_public_point(g_pow_randomness, alice_s_transfer_amount);
+        let (bob_t_transfer, _) = encrypt_gadget(
+            composer,
+            inputs.recipient_public_key,
+            inputs.transfer_amount,
+            inputs.randomness,
+        )?;
+        composer.assert_equal_point(bob_t_transfer, inputs.recipient_encrypted_transfer_amount);
 
         // Alice after balance check
-        let g_pow_after_balance = composer
-            .component_mul_generator(sender_after_balance, JubjubExtended::ADDITIVE_GENERATOR)?;
-        let alice_t_transfer_neg =
-            composer.component_mul_point(neg, alice_t_encrypted_transfer_amount);
-        let alice_s_transfer_neg =
-            composer.component_mul_point(neg, alice_s_encrypted_transfer_amount);
-        let s_after_balance =
-            composer.component_add_point(alice_t_encrypted_balance, alice_t_transfer_neg);
-        let right_after_balance = {
-            let right_after_balance =
-                composer.component_add_point(alice_s_encrypted_balance, alice_s_transfer_neg);
-            composer.component_mul_point(sender_private_key, right_after_balance)
+        let t_after_balance = {
+            let t_transfer_neg =
+                composer.component_neg_point(inputs.sender_t_encrypted_transfer_amount);
+            composer.component_add_point(inputs.sender_t_encrypted_balance, t_transfer_neg)
+        };
+        let s_after_balance = {
+            let s_transfer_neg =
+                composer.component_neg_point(inputs.sender_s_encrypted_transfer_amount);
+            composer.component_add_point(inputs.sender_s_encrypted_balance, s_transfer_neg)
         };
-        let x = composer.component_add_point(g_pow_after_balance, right_after_balance);
-        composer.assert_equal_point(s_after_balance, x);
+        let after_balance = decrypt_gadget(
+            composer,
+            t_after_balance,
+            s_after_balance,
+            inputs.sender_private_key,
+        );
+        let g_pow_after_balance = composer.component_mul_generator(
+            inputs.sender_after_balance,
+            JubjubExtended::ADDITIVE_GENERATOR,
+        )?;
+        composer.assert_equal_point(after_balance, g_pow_after_balance);
 
         // Public key calculation check
-        let calculated_pk = composer
-            .component_mul_generator(sender_private_key, JubjubExtended::ADDITIVE_GENERATOR)?;
-        composer.assert_equal_public_point(calculated_pk, self.sender_public_key);
+        let calculated_pk = composer.component_mul_generator(
+            inputs.sender_private_key,
+            JubjubExtended::ADDITIVE_GENERATOR,
+        )?;
+        composer.assert_equal_point(calculated_pk, inputs.sender_public_key);
 
         // Transfer amount and ramaining balance range check
-        composer.component_range(transfer_amount, self.bits);
-        composer.component_range(sender_after_balance, self.bits);
+        composer.component_range(inputs.transfer_amount, BALANCE_BITS);
+        composer.component_range(inputs.sender_after_balance, BALANCE_BITS);
 
         Ok(())
     }
@@ -175,23 +172,11 @@ impl<E: ConfidentialTransferPublicInputs<P>, P: Pairing> ConfidentialTransferTra
     }
 
     /// output public inputs for confidential transfer transaction
-    pub fn public_inputs(self) -> [P::ScalarField; CONFIDENTIAL_TRANSFER_PUBLIC_INPUT_LENGTH] {
-        let mut public_inputs = [P::ScalarField::zero(); CONFIDENTIAL_TRANSFER_PUBLIC_INPUT_LENGTH];
-        let (sender_t, sender_s) = self.sender_encrypted_transfer_amount.get();
-        for (i, public_point) in [
-            sender_t,
-            self.recipient_encrypted_transfer_amount,
-            sender_s,
-            self.sender_public_key,
-        ]
-        .iter()
-        .enumerate()
-        {
-            let (x, y) = (-public_point.get_x(), -public_point.get_y());
-            public_inputs[i * 2] = x.into();
-            public_inputs[i * 2 + 1] = y.into();
-        }
-        public_inputs
+    pub fn public_inputs(self) -> Vec<P::ScalarField>
+    where
+        E: Clone,
+    {
+        self.values().scalars().to_vec()
     }
 
     /// output transfer amount encrypted for each
@@ -205,3 +190,20 @@ impl<E: ConfidentialTransferPublicInputs<P>, P: Pairing> ConfidentialTransferTra
         )
     }
 }
+
+impl<E: ConfidentialTransferPublicInputs<P> + Clone, P: Pairing> PublicInputs<P>
+    for ConfidentialTransferTransaction<E, P>
+{
+    fn append(&self, values: &mut PublicInputValues<P>) {
+        let (sender_t, sender_s) = self.sender_encrypted_transfer_amount.clone().get();
+
+        values
+            .point("sender_t_encrypted_transfer_amount", sender_t)
+            .point(
+                "recipient_encrypted_transfer_amount",
+                self.recipient_encrypted_transfer_amount,
+            )
+            .point("sender_s_encrypted_transfer_amount", sender_s)
+            .point("sender_public_key", self.sender_public_key);
+    }
+}
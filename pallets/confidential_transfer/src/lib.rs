@@ -121,9 +121,8 @@ impl<T: Config> ConfidentialTransfer<T::AccountId, <T as pallet_encrypted_balanc
             <T as pallet_encrypted_balance::Config>::P,
         >,
     ) -> DispatchResultWithPostInfo {
-        let public_inputs = transaction_params.clone().public_inputs();
+        T::Plonk::verify_public_inputs(who, proof, &transaction_params)?;
         let (sender_amount, recipient_amount) = transaction_params.transaction_amount();
-        T::Plonk::verify(who, proof, public_inputs.to_vec())?;
         T::EncryptedCurrency::transfer(who, dest, sender_amount, recipient_amount)?;
         Ok(().into())
     }
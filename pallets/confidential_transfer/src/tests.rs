@@ -11,7 +11,7 @@ mod plonk_test {
     use frame_support::assert_ok;
     use pallet_plonk::FullcodecRng;
     use zero_pairing::TatePairing;
-    use zero_plonk::prelude::Compiler;
+    use zero_r1cs::prelude::{Builder, Compiler};
 
     use ark_std::{end_timer, start_timer};
     use rand::SeedableRng;
@@ -83,4 +83,22 @@ mod plonk_test {
             assert_eq!(bob_raw_balance.unwrap(), BOB_AFTER_BALANCE);
         });
     }
+
+    #[test]
+    fn confidential_transfer_circuit_json() {
+        let (confidential_transfer_circuit, _) = generate_confidential_transfer_params();
+
+        let builder = Compiler::build::<_, TatePairing>(&confidential_transfer_circuit)
+            .expect("failed to build circuit");
+        let json = builder.to_json();
+        let imported = Builder::<TatePairing>::from_json(&json).expect("failed to import circuit");
+
+        assert_eq!(imported.to_json(), json);
+
+        // the values of the transfer, such as the private key of the
+        // sender, aren't exported
+        let default = Compiler::build::<_, TatePairing>(&ConfidentialTransferCircuit::default())
+            .expect("failed to build circuit");
+        assert_eq!(default.to_json(), json);
+    }
 }
@@ -27,6 +27,7 @@
 //! - Setup public parameters API
 //! - Get public parameters RPC
 //! - Verify zkp proof API
+//! - Batch verify zkp proofs API
 //!
 //! ### Terminology
 //!
@@ -45,8 +46,8 @@
 //! 3. Use `plonk-pallet` in your pallet
 //! 4. Open `get_public_parameters` RPC
 //!
-//! `get_public_parameters` is the RPC method and, `trusted_setup` and `verify` are
-//! the dispatchable function and API for other pallet.
+//! `get_public_parameters` is the RPC method and, `trusted_setup`, `verify` and
+//! `batch_verify` are the dispatchable function and API for other pallet.
 //!
 //! You can see the details with [tutorial](https://astarnetwork.github.io/plonk)
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -71,7 +72,7 @@ use frame_support::dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo};
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use zero_kzg::KeyPair;
-use zero_plonk::prelude::Compiler;
+use zero_r1cs::prelude::Compiler;
 use zkstd::behave::Group;
 use zkstd::common::{Pairing, Vec};
 
@@ -143,6 +144,17 @@ pub mod pallet {
             <Self as Plonk<_, T::P>>::verify(&transactor, proof, public_inputs)?;
             Ok(().into())
         }
+
+        /// The function called when we verify many statements at once
+        #[pallet::weight(10_000)]
+        pub fn batch_verify(
+            origin: OriginFor<T>,
+            proofs: ProofBatch<T::P>,
+        ) -> DispatchResultWithPostInfo {
+            let transactor = ensure_signed(origin)?;
+            <Self as Plonk<_, T::P>>::batch_verify(&transactor, proofs)?;
+            Ok(().into())
+        }
     }
 }
 
@@ -205,4 +217,55 @@ impl<T: Config> Plonk<T::AccountId, T::P> for Pallet<T> {
             }),
         }
     }
+
+    /// The API method to verify the proof validity with typed public inputs
+    fn verify_public_inputs<I: PublicInputs<T::P>>(
+        _who: &T::AccountId,
+        proof: Proof<T::P>,
+        public_inputs: &I,
+    ) -> DispatchResultWithPostInfo {
+        match Self::keypair() {
+            Some(mut pp) => {
+                let label = b"verify";
+                let (_, verifier) = Compiler::compile::<T::CustomCircuit, T::P>(&mut pp, label)
+                    .expect("failed to compile circuit");
+                match verifier.verify_public_inputs(&proof, public_inputs) {
+                    Ok(_) => Ok(().into()),
+                    Err(_) => Err(DispatchErrorWithPostInfo {
+                        post_info: PostDispatchInfo::from(()),
+                        error: DispatchError::Other("invalid proof"),
+                    }),
+                }
+            }
+            None => Err(DispatchErrorWithPostInfo {
+                post_info: PostDispatchInfo::from(()),
+                error: DispatchError::Other("setup not yet"),
+            }),
+        }
+    }
+
+    /// The API method to verify many proofs with a single pairing check
+    fn batch_verify(
+        _who: &T::AccountId,
+        proofs: ProofBatch<T::P>,
+    ) -> DispatchResultWithPostInfo {
+        match Self::keypair() {
+            Some(mut pp) => {
+                let label = b"verify";
+                let (_, verifier) = Compiler::compile::<T::CustomCircuit, T::P>(&mut pp, label)
+                    .expect("failed to compile circuit");
+                match verifier.batch_verify(&proofs) {
+                    Ok(_) => Ok(().into()),
+                    Err(_) => Err(DispatchErrorWithPostInfo {
+                        post_info: PostDispatchInfo::from(()),
+                        error: DispatchError::Other("invalid proof"),
+                    }),
+                }
+            }
+            None => Err(DispatchErrorWithPostInfo {
+                post_info: PostDispatchInfo::from(()),
+                error: DispatchError::Other("setup not yet"),
+            }),
+        }
+    }
 }
@@ -55,7 +55,7 @@ impl system::Config for Test {
 }
 
 use zero_pairing::TatePairing;
-use zero_plonk::prelude::{Error as CircuitError, *};
+use zero_r1cs::prelude::{Error as CircuitError, *};
 use zkstd::common::CurveGroup;
 
 #[derive(Debug)]
@@ -69,7 +69,7 @@ mod plonk_test {
     use crate::types::JubjubScalar;
     use rand::SeedableRng;
     use zero_pairing::TatePairing;
-    use zero_plonk::prelude::Compiler;
+    use zero_r1cs::prelude::Compiler;
     use zkstd::behave::Group;
 
     fn get_rng() -> FullcodecRng {
@@ -120,4 +120,91 @@ mod plonk_test {
                 .expect("failed to verify proof");
         });
     }
+
+    fn batch_proofs(rng: &mut FullcodecRng) -> ProofBatch<TatePairing> {
+        let mut pp = Plonk::keypair().unwrap();
+
+        let (prover, _) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+            .expect("failed to compile circuit");
+
+        (0..3)
+            .map(|_| {
+                let a = JubjubScalar::random(&mut *rng);
+                prover
+                    .prove(&mut *rng, &DummyCircuit::new(a))
+                    .expect("failed to prove")
+            })
+            .collect()
+    }
+
+    fn invalid_proof() -> DispatchErrorWithPostInfo {
+        DispatchErrorWithPostInfo {
+            post_info: PostDispatchInfo::from(()),
+            error: DispatchError::Other("invalid proof"),
+        }
+    }
+
+    #[test]
+    fn batch_verify() {
+        let rng = get_rng();
+
+        new_test_ext().execute_with(|| {
+            assert_ok!(Plonk::trusted_setup(Origin::signed(1), 12, rng));
+
+            let proofs = batch_proofs(&mut get_rng());
+
+            assert_ok!(Plonk::batch_verify(Origin::signed(1), proofs));
+        });
+    }
+
+    #[test]
+    fn batch_verify_tampered_proof() {
+        let rng = get_rng();
+
+        new_test_ext().execute_with(|| {
+            assert_ok!(Plonk::trusted_setup(Origin::signed(1), 12, rng));
+
+            // the proof of another statement has the expected length but
+            // doesn't hold for these public inputs
+            let mut proofs = batch_proofs(&mut get_rng());
+            proofs[1].0 = proofs[0].0.clone();
+
+            assert_eq!(
+                Plonk::batch_verify(Origin::signed(1), proofs),
+                Err(invalid_proof())
+            );
+        });
+    }
+
+    #[test]
+    fn batch_verify_extra_public_input() {
+        let rng = get_rng();
+
+        new_test_ext().execute_with(|| {
+            assert_ok!(Plonk::trusted_setup(Origin::signed(1), 12, rng));
+
+            // a public input the circuit doesn't have
+            let mut proofs = batch_proofs(&mut get_rng());
+            proofs[0].1.push(Fr::one());
+
+            assert_eq!(
+                Plonk::batch_verify(Origin::signed(1), proofs),
+                Err(invalid_proof())
+            );
+        });
+    }
+
+    #[test]
+    fn batch_verify_empty_batch() {
+        let rng = get_rng();
+
+        new_test_ext().execute_with(|| {
+            assert_ok!(Plonk::trusted_setup(Origin::signed(1), 12, rng));
+
+            assert_eq!(
+                Plonk::batch_verify(Origin::signed(1), Vec::new()),
+                Err(invalid_proof())
+            );
+        });
+    }
 }
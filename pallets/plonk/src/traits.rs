@@ -18,4 +18,16 @@ pub trait Plonk<AccountId, P: Pairing> {
         proof: Proof<P>,
         public_inputs: Vec<P::ScalarField>,
     ) -> DispatchResultWithPostInfo;
+
+    /// The typed proof verify function
+    /// This fails if the public inputs aren't serialized in the order the circuit declares them
+    fn verify_public_inputs<I: PublicInputs<P>>(
+        who: &AccountId,
+        proof: Proof<P>,
+        public_inputs: &I,
+    ) -> DispatchResultWithPostInfo;
+
+    /// The batch proof verify function
+    /// This verifies all the proofs with a single pairing check and fails if any of them is invalid
+    fn batch_verify(who: &AccountId, proofs: ProofBatch<P>) -> DispatchResultWithPostInfo;
 }
@@ -1,9 +1,14 @@
 use codec::{Decode, Encode};
 pub use rand_xorshift::XorShiftRng as FullcodecRng;
 pub use zero_bls12_381::Fr;
-pub use zero_plonk::prelude::{
+pub use zero_r1cs::prelude::{
     BlsScalar, Circuit, Constraint, Error as PlonkError, JubjubAffine, JubjubScalar, Proof,
+    PublicInputs,
 };
+use zkstd::common::{Pairing, Vec};
+
+/// Proofs along with their public inputs, verified at once by `batch_verify`
+pub type ProofBatch<P> = Vec<(Proof<P>, Vec<<P as Pairing>::ScalarField>)>;
 
 /// The struct for Merlin transcript and used for proof verify
 #[derive(Debug, PartialEq, Clone, Encode)]
@@ -81,7 +81,7 @@ impl SigUtils<48> for Fq {
         let (_, borrow) = sbb(l5, MODULUS[5], borrow);
 
         if borrow & 1 == 1 {
-            Some(Self([l5, l4, l3, l2, l1, l0]) * Self(R2))
+            Some(Self([l0, l1, l2, l3, l4, l5]) * Self(R2))
         } else {
             None
         }
@@ -384,6 +384,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compressed_serialization() {
+        let points = [
+            G1Affine::ADDITIVE_IDENTITY,
+            G1Affine::ADDITIVE_GENERATOR,
+            G1Affine::from(G1Projective::random(OsRng)),
+        ];
+
+        for point in points {
+            assert_eq!(G1Affine::from_bytes(point.to_bytes()), Some(point));
+        }
+    }
+
     #[test]
     #[allow(clippy::op_ref)]
     fn bls_operations() {
@@ -0,0 +1,66 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zero_jubjub::JubjubExtended;
+use zero_r1cs::prelude::{Composer, Error, Witness, WitnessPoint};
+use zkstd::common::{CurveGroup, Pairing};
+
+/// Constrain the encryption of `value` under `public_key` with `randomness`
+/// and return its `(s, t)` points
+///
+/// The points are `value · G + randomness · public_key` and `randomness · G`
+/// like the ones of [`EncryptedNumber::encrypt`](crate::EncryptedNumber::encrypt).
+///
+/// Will error if `value` or `randomness` isn't a jubjub scalar.
+pub fn encrypt_gadget<P, C>(
+    composer: &mut C,
+    public_key: WitnessPoint,
+    value: Witness,
+    randomness: Witness,
+) -> Result<(WitnessPoint, WitnessPoint), Error>
+where
+    P: Pairing<JubjubExtended = JubjubExtended>,
+    C: Composer<P>,
+{
+    let g_value = composer.component_mul_generator(value, JubjubExtended::ADDITIVE_GENERATOR)?;
+    let pk_randomness = composer.component_mul_point(randomness, public_key);
+    let s = composer.component_add_point(g_value, pk_randomness);
+
+    let t = composer.component_mul_generator(randomness, JubjubExtended::ADDITIVE_GENERATOR)?;
+
+    Ok((s, t))
+}
+
+/// Constrain the decryption of the `(s, t)` points under `private_key` and
+/// return the encrypted value as `value · G`
+///
+/// The value is compared with a witness by
+/// [`Composer::component_mul_generator`], so it doesn't have to be brute
+/// forced like in [`EncryptedNumber::decrypt`](crate::EncryptedNumber::decrypt).
+pub fn decrypt_gadget<P, C>(
+    composer: &mut C,
+    s: WitnessPoint,
+    t: WitnessPoint,
+    private_key: Witness,
+) -> WitnessPoint
+where
+    P: Pairing,
+    C: Composer<P>,
+{
+    let t_private_key = composer.component_mul_point(private_key, t);
+    let t_private_key = composer.component_neg_point(t_private_key);
+
+    composer.component_add_point(s, t_private_key)
+}
@@ -17,6 +17,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
+mod gadget;
+
+pub use gadget::{decrypt_gadget, encrypt_gadget};
+
 use core::ops::{Add, Sub};
 use num_traits::{CheckedAdd, CheckedSub};
 use parity_scale_codec::{Decode, Encode};
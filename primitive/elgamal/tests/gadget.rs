@@ -0,0 +1,105 @@
+use rand::rngs::OsRng;
+use rand::Rng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_elgamal::{decrypt_gadget, encrypt_gadget, EncryptedNumber};
+use zero_jubjub::{Fp as JubJubScalar, JubjubAffine, JubjubExtended};
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::{CurveGroup, Group};
+
+#[derive(Debug)]
+struct EncryptionCircuit {
+    public_key: JubjubAffine,
+    value: JubJubScalar,
+    randomness: JubJubScalar,
+    encrypted: EncryptedNumber,
+}
+
+impl Default for EncryptionCircuit {
+    fn default() -> Self {
+        Self {
+            public_key: JubjubAffine::ADDITIVE_IDENTITY,
+            value: JubJubScalar::zero(),
+            randomness: JubJubScalar::zero(),
+            encrypted: EncryptedNumber::default(),
+        }
+    }
+}
+
+impl Circuit<TatePairing> for EncryptionCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let public_key = composer.append_point(self.public_key);
+        let value = composer.append_witness(self.value);
+        let randomness = composer.append_witness(self.randomness);
+
+        let (s, t) = encrypt_gadget(composer, public_key, value, randomness)?;
+
+        let (encrypted_s, encrypted_t) = self.encrypted.get_coordinate();
+        composer.assert_equal_public_point(s, encrypted_s);
+        composer.assert_equal_public_point(t, encrypted_t);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn encryption_proof() {
+    let mut pp = KeyPair::<TatePairing>::setup(12, BlsScalar::random(OsRng));
+    let (prover, verifier) =
+        Compiler::compile::<EncryptionCircuit, TatePairing>(&mut pp, b"elgamal")
+            .expect("failed to compile circuit");
+
+    let private_key = JubJubScalar::random(OsRng);
+    let public_key = JubjubAffine::from(JubjubExtended::ADDITIVE_GENERATOR * private_key);
+    let value = OsRng.gen::<u16>() as u32;
+    let randomness = JubJubScalar::random(OsRng);
+    let circuit = EncryptionCircuit {
+        public_key,
+        value: JubJubScalar::from(value as u64),
+        randomness,
+        encrypted: EncryptedNumber::encrypt(private_key, value, randomness),
+    };
+
+    let prove = |circuit: &EncryptionCircuit| {
+        prover
+            .prove(&mut OsRng, circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs))
+    };
+
+    prove(&circuit).expect("the ciphertext encrypts the value");
+
+    // the ciphertext encrypts another value
+    let encrypted = EncryptedNumber::encrypt(private_key, value + 1, randomness);
+    assert!(prove(&EncryptionCircuit {
+        encrypted,
+        ..circuit
+    })
+    .is_err());
+}
+
+#[test]
+fn decrypt_gadget_matches_native() {
+    let private_key = JubJubScalar::random(OsRng);
+    let value = OsRng.gen::<u16>() as u32;
+    let encrypted = EncryptedNumber::encrypt(private_key, value, JubJubScalar::random(OsRng));
+    let (s, t) = encrypted.get_coordinate();
+    let mut builder = Builder::<TatePairing>::initialized(1 << 12);
+    let s = builder.append_point(s);
+    let t = builder.append_point(t);
+    let private_key = builder.append_witness(private_key);
+
+    let decrypted = decrypt_gadget(&mut builder, s, t, private_key);
+
+    let value = builder.append_witness(JubJubScalar::from(value as u64));
+    let g_value = builder
+        .component_mul_generator(value, JubjubExtended::ADDITIVE_GENERATOR)
+        .expect("the value is a jubjub scalar");
+    builder.assert_equal_point(decrypted, g_value);
+    builder
+        .check_satisfied()
+        .expect("the ciphertext decrypts to the value");
+}
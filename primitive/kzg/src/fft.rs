@@ -1,4 +1,5 @@
 use crate::poly::Polynomial;
+#[cfg(feature = "parallel")]
 use rayon::join;
 use zkstd::common::{FftField, Vec};
 
@@ -50,7 +51,7 @@ impl<F: FftField> Fft<F> {
 
         // precompute cosets
         let mul_g = F::MULTIPLICATIVE_GENERATOR;
-        let cosets = (0..half_n)
+        let cosets = (0..n)
             .scan(F::one(), |w, _| {
                 let tw = *w;
                 *w *= mul_g;
@@ -184,6 +185,13 @@ fn classic_fft_arithmetic<F: FftField>(
     }
 }
 
+// runs the halves of the fft one after the other without the `parallel`
+// feature
+#[cfg(not(feature = "parallel"))]
+fn join<A, B>(a: impl FnOnce() -> A, b: impl FnOnce() -> B) -> (A, B) {
+    (a(), b())
+}
+
 // butterfly arithmetic polynomial evaluation
 fn butterfly_arithmetic<F: FftField>(
     left: &mut [F],
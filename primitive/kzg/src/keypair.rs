@@ -17,7 +17,9 @@ pub struct KeyPair<P: Pairing> {
 }
 
 impl<P: Pairing> KeyPair<P> {
-    const ADDED_BLINDING_DEGREE: usize = 6;
+    /// Degree the commit key exceeds the domain size by to commit to the
+    /// blinded polynomials
+    pub const ADDED_BLINDING_DEGREE: usize = 6;
 
     // setup polynomial evaluation domain
     pub fn setup(k: u64, r: P::ScalarField) -> Self {
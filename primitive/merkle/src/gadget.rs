@@ -0,0 +1,129 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zero_bls12_381::Fr;
+use zero_poseidon::hash_gadget;
+use zero_r1cs::prelude::{Composer, Constraint, Witness};
+use zkstd::common::Pairing;
+
+use crate::path::MerklePath;
+
+/// Constrain the root of the tree holding `leaf` at the position of `path`
+/// and return it
+///
+/// The siblings and the bits of the position are private witnesses, each bit
+/// being constrained to be boolean.
+pub fn root_gadget<P, C, const DEPTH: usize>(
+    composer: &mut C,
+    leaf: Witness,
+    path: &MerklePath<DEPTH>,
+) -> Witness
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    path.siblings
+        .iter()
+        .enumerate()
+        .fold(leaf, |node, (height, sibling)| {
+            let bit = composer.append_witness(Fr::from(path.is_right(height) as u64));
+            composer.component_boolean(bit);
+
+            let sibling = composer.append_witness(*sibling);
+            let (left, right) = swap_gadget(composer, bit, node, sibling);
+
+            hash_gadget(composer, &[left, right])
+        })
+}
+
+/// Constrain `leaf` to belong to the tree of the public `root` at the
+/// position of `path` without revealing it
+pub fn membership_gadget<P, C, const DEPTH: usize>(
+    composer: &mut C,
+    leaf: Witness,
+    path: &MerklePath<DEPTH>,
+    root: Fr,
+) where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    let computed = root_gadget(composer, leaf, path);
+
+    composer.assert_equal_constant(computed, Fr::zero(), Some(-root));
+}
+
+// (node, sibling) if bit is zero and (sibling, node) otherwise
+fn swap_gadget<P, C>(
+    composer: &mut C,
+    bit: Witness,
+    node: Witness,
+    sibling: Witness,
+) -> (Witness, Witness)
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    // diff = sibling - node
+    let diff = composer
+        .value(sibling)
+        .zip(composer.value(node))
+        .map(|(sibling, node)| sibling - node);
+    let diff = composer.append_optional_witness(diff);
+    let constraint = Constraint::new()
+        .left(1)
+        .right(-Fr::one())
+        .output(-Fr::one())
+        .a(sibling)
+        .b(node)
+        .o(diff);
+    composer.append_gate(constraint);
+
+    // left = node + bit * diff
+    let left = composer
+        .value(node)
+        .zip(composer.value(bit))
+        .zip(composer.value(diff))
+        .map(|((node, bit), diff)| node + bit * diff);
+    let left = composer.append_optional_witness(left);
+    let constraint = Constraint::new()
+        .mult(1)
+        .fourth(1)
+        .output(-Fr::one())
+        .a(bit)
+        .b(diff)
+        .d(node)
+        .o(left);
+    composer.append_gate(constraint);
+
+    // right = node + sibling - left
+    let right = composer
+        .value(node)
+        .zip(composer.value(sibling))
+        .zip(composer.value(left))
+        .map(|((node, sibling), left)| node + sibling - left);
+    let right = composer.append_optional_witness(right);
+    let constraint = Constraint::new()
+        .left(1)
+        .right(1)
+        .fourth(-Fr::one())
+        .output(-Fr::one())
+        .a(node)
+        .b(sibling)
+        .d(left)
+        .o(right);
+    composer.append_gate(constraint);
+
+    (left, right)
+}
@@ -0,0 +1,32 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![doc = include_str!("../README.md")]
+
+mod gadget;
+mod path;
+mod tree;
+
+pub use gadget::{membership_gadget, root_gadget};
+pub use path::MerklePath;
+pub use tree::MerkleTree;
+
+use zero_bls12_381::Fr;
+use zero_poseidon::hash;
+
+// parent node of `left` and `right`
+pub(crate) fn hash_node(left: Fr, right: Fr) -> Fr {
+    hash(&[left, right])
+}
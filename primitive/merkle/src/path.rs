@@ -0,0 +1,67 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zero_bls12_381::Fr;
+
+use crate::hash_node;
+
+/// Siblings of a leaf from the leaves to the root along with its position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MerklePath<const DEPTH: usize> {
+    pub(crate) position: usize,
+    pub(crate) siblings: [Fr; DEPTH],
+}
+
+impl<const DEPTH: usize> MerklePath<DEPTH> {
+    /// Create the path of the leaf at `position`
+    pub fn new(position: usize, siblings: [Fr; DEPTH]) -> Self {
+        Self { position, siblings }
+    }
+
+    /// Position of the leaf
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Siblings from the leaves to the root
+    pub fn siblings(&self) -> &[Fr; DEPTH] {
+        &self.siblings
+    }
+
+    /// Root of the tree holding `leaf` at the position of the path
+    pub fn root(&self, leaf: Fr) -> Fr {
+        self.siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (height, sibling)| {
+                if self.is_right(height) {
+                    hash_node(*sibling, node)
+                } else {
+                    hash_node(node, *sibling)
+                }
+            })
+    }
+
+    /// Whether `leaf` belongs to the tree of `root` at the position of the
+    /// path
+    pub fn verify(&self, leaf: Fr, root: Fr) -> bool {
+        self.root(leaf) == root
+    }
+
+    // whether the node of the level `height` is a right child
+    pub(crate) fn is_right(&self, height: usize) -> bool {
+        (self.position >> height) & 1 == 1
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zero_bls12_381::Fr;
+use zkstd::common::{vec, Vec};
+
+use crate::hash_node;
+use crate::path::MerklePath;
+
+/// Fixed depth Merkle tree whose leaves are appended from left to right
+///
+/// The empty leaves are zero and every level only stores its nodes up to the
+/// last appended leaf, the others being the roots of empty subtrees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree<const DEPTH: usize> {
+    /// Filled nodes of each level, from the leaves to the root
+    levels: Vec<Vec<Fr>>,
+    /// Root of an empty subtree of each height
+    empty: Vec<Fr>,
+}
+
+impl<const DEPTH: usize> Default for MerkleTree<DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const DEPTH: usize> MerkleTree<DEPTH> {
+    /// Create an empty tree
+    pub fn new() -> Self {
+        assert!(DEPTH < usize::BITS as usize, "the leaves must be indexable");
+
+        let mut empty = vec![Fr::zero()];
+        (0..DEPTH).for_each(|height| {
+            let node = empty[height];
+            empty.push(hash_node(node, node));
+        });
+
+        Self {
+            levels: vec![Vec::new(); DEPTH + 1],
+            empty,
+        }
+    }
+
+    /// Leaves count
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether no leaf was appended
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum leaves count
+    pub fn capacity(&self) -> usize {
+        1 << DEPTH
+    }
+
+    /// Root of the tree
+    pub fn root(&self) -> Fr {
+        self.node(DEPTH, 0)
+    }
+
+    /// Append a leaf and return its position, or `None` if the tree is full
+    pub fn append(&mut self, leaf: Fr) -> Option<usize> {
+        let position = self.len();
+
+        if position == self.capacity() {
+            return None;
+        }
+
+        self.levels[0].push(leaf);
+
+        let mut index = position;
+        (0..DEPTH).for_each(|height| {
+            let parent = index >> 1;
+            let node = hash_node(
+                self.node(height, parent << 1),
+                self.node(height, (parent << 1) + 1),
+            );
+            let level = &mut self.levels[height + 1];
+
+            if parent < level.len() {
+                level[parent] = node;
+            } else {
+                level.push(node);
+            }
+
+            index = parent;
+        });
+
+        Some(position)
+    }
+
+    /// Path of the leaf at `position`, or `None` if it wasn't appended
+    pub fn path(&self, position: usize) -> Option<MerklePath<DEPTH>> {
+        if position >= self.len() {
+            return None;
+        }
+
+        let mut siblings = [Fr::zero(); DEPTH];
+        siblings
+            .iter_mut()
+            .enumerate()
+            .for_each(|(height, sibling)| *sibling = self.node(height, (position >> height) ^ 1));
+
+        Some(MerklePath::new(position, siblings))
+    }
+
+    // node of the level `height` at `index`, empty unless it was filled
+    fn node(&self, height: usize, index: usize) -> Fr {
+        self.levels[height]
+            .get(index)
+            .copied()
+            .unwrap_or(self.empty[height])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+    use zero_bls12_381::Fr;
+    use zkstd::common::Group;
+
+    use super::MerkleTree;
+    use crate::hash_node;
+
+    #[test]
+    fn empty_tree_root() {
+        let tree = MerkleTree::<2>::new();
+        let empty = hash_node(Fr::zero(), Fr::zero());
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.root(), hash_node(empty, empty));
+        assert_eq!(tree.path(0), None);
+    }
+
+    #[test]
+    fn append_updates_the_root() {
+        let mut tree = MerkleTree::<2>::new();
+        let leaves = [(); 4].map(|_| Fr::random(OsRng));
+
+        leaves.iter().enumerate().for_each(|(i, leaf)| {
+            assert_eq!(tree.append(*leaf), Some(i));
+        });
+
+        let left = hash_node(leaves[0], leaves[1]);
+        let right = hash_node(leaves[2], leaves[3]);
+
+        assert_eq!(tree.root(), hash_node(left, right));
+        assert_eq!(tree.append(Fr::random(OsRng)), None);
+        assert_eq!(tree.len(), tree.capacity());
+    }
+
+    #[test]
+    fn paths_of_a_partial_tree() {
+        let mut tree = MerkleTree::<8>::new();
+        let leaves: Vec<_> = (0..37).map(|_| Fr::random(OsRng)).collect();
+
+        leaves.iter().for_each(|leaf| {
+            tree.append(*leaf);
+        });
+
+        leaves.iter().enumerate().for_each(|(position, leaf)| {
+            let path = tree.path(position).expect("the leaf was appended");
+
+            assert!(path.verify(*leaf, tree.root()));
+            assert!(!path.verify(*leaf + Fr::one(), tree.root()));
+        });
+        assert_eq!(tree.path(leaves.len()), None);
+    }
+}
@@ -0,0 +1,100 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_merkle::{membership_gadget, root_gadget, MerklePath, MerkleTree};
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+const DEPTH: usize = 3;
+
+#[derive(Debug)]
+struct MembershipCircuit {
+    leaf: BlsScalar,
+    path: MerklePath<DEPTH>,
+    root: BlsScalar,
+}
+
+impl Default for MembershipCircuit {
+    fn default() -> Self {
+        Self {
+            leaf: BlsScalar::zero(),
+            path: MerklePath::new(0, [BlsScalar::zero(); DEPTH]),
+            root: BlsScalar::zero(),
+        }
+    }
+}
+
+impl Circuit<TatePairing> for MembershipCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let leaf = composer.append_witness(self.leaf);
+
+        membership_gadget(composer, leaf, &self.path, self.root);
+
+        Ok(())
+    }
+}
+
+fn random_tree(leaves: usize) -> (MerkleTree<DEPTH>, Vec<BlsScalar>) {
+    let mut tree = MerkleTree::new();
+    let leaves: Vec<_> = (0..leaves).map(|_| BlsScalar::random(OsRng)).collect();
+
+    leaves.iter().for_each(|leaf| {
+        tree.append(*leaf).expect("the tree isn't full");
+    });
+
+    (tree, leaves)
+}
+
+#[test]
+fn root_gadget_matches_native() {
+    let (tree, leaves) = random_tree(6);
+
+    leaves.iter().enumerate().for_each(|(position, leaf)| {
+        let path = tree.path(position).expect("the leaf was appended");
+        let mut builder = Builder::<TatePairing>::initialized(1 << 11);
+        let leaf = builder.append_witness(*leaf);
+
+        let root = root_gadget(&mut builder, leaf, &path);
+
+        assert_eq!(builder[root], tree.root());
+        builder.check_satisfied().expect("the path is satisfied");
+    })
+}
+
+#[test]
+fn membership_proof() {
+    let mut pp = KeyPair::<TatePairing>::setup(11, BlsScalar::random(OsRng));
+    let (prover, verifier) =
+        Compiler::compile::<MembershipCircuit, TatePairing>(&mut pp, b"merkle")
+            .expect("failed to compile circuit");
+
+    let (tree, leaves) = random_tree(5);
+    let prove = |circuit: &MembershipCircuit| {
+        prover
+            .prove(&mut OsRng, circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs))
+    };
+
+    // every position is proven by the same circuit
+    leaves.iter().enumerate().for_each(|(position, leaf)| {
+        let circuit = MembershipCircuit {
+            leaf: *leaf,
+            path: tree.path(position).expect("the leaf was appended"),
+            root: tree.root(),
+        };
+
+        prove(&circuit).expect("the leaf belongs to the tree");
+    });
+
+    // a leaf outside of the tree
+    let circuit = MembershipCircuit {
+        leaf: BlsScalar::random(OsRng),
+        path: tree.path(0).expect("the leaf was appended"),
+        root: tree.root(),
+    };
+    assert!(prove(&circuit).is_err());
+}
@@ -24,6 +24,9 @@ use zero_jubjub::{Fp, JubjubAffine, JubjubExtended};
 use zkstd::common::*;
 use zkstd::common::{G2Pairing, Group, Pairing, PairingRange, PrimeField, Ring, Vec};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 // tate pairing with miller algorithm
 #[derive(Debug, Clone, Eq, PartialEq, Default, Encode, Decode)]
 pub struct TatePairing;
@@ -134,13 +137,19 @@ pub fn msm_variable_base<P: Pairing>(
 
     let zero = P::G1Projective::ADDITIVE_IDENTITY;
 
-    let window_starts_iter = (0..num_bits).step_by(c);
+    let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+
+    // The windows are only processed in parallel with the `parallel` feature
+    #[cfg(feature = "parallel")]
+    let window_starts_iter = window_starts.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let window_starts_iter = window_starts.iter();
 
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
     // in parallel process each such window.
     let window_sums: Vec<_> = window_starts_iter
-        .map(|w_start| {
+        .map(|&w_start| {
             let mut res = zero;
             // We don't need the "zero" bucket, so we only have 2^c - 1 buckets
             let mut buckets = vec![zero; (1 << c) - 1];
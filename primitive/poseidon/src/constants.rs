@@ -0,0 +1,1301 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Round constants and MDS matrix of the permutation
+//!
+//! The `i`-th round constant is the Blake2b-512 digest of
+//! `b"zero-poseidon" || (i as u32).to_le_bytes()` read as a little endian
+//! integer and reduced modulo the scalar field order.
+//!
+//! The MDS matrix is the Cauchy matrix `M[i][j] = 1 / (x_i + y_j)` with
+//! `x_i = i` and `y_j = WIDTH + j`.
+
+use zero_bls12_381::Fr;
+
+use crate::{FULL_ROUNDS, PARTIAL_ROUNDS, WIDTH};
+
+pub(crate) const ROUND_CONSTANTS: [Fr; WIDTH * (FULL_ROUNDS + PARTIAL_ROUNDS)] = [
+    Fr::to_mont_form([
+        0xef8c868d6b299740,
+        0x62e3232c33a2851d,
+        0xe613dcd1af29e1da,
+        0x69e2b1fa93baa904,
+    ]),
+    Fr::to_mont_form([
+        0xb12149aeca032d0b,
+        0xcc1594ec9f5d06fb,
+        0xea5417dfd79d9e9d,
+        0x38789f9bf13e6fa0,
+    ]),
+    Fr::to_mont_form([
+        0xf397df0a158744c6,
+        0x2dd086959e7a5d08,
+        0x69faaaea622a75ff,
+        0x5eb02e35d1f2e84f,
+    ]),
+    Fr::to_mont_form([
+        0xfc034a74a514ed4b,
+        0x3405f1b09c9ef645,
+        0x2ce5f44b33bcb87a,
+        0x3a8f40e0ec77eb44,
+    ]),
+    Fr::to_mont_form([
+        0x70265bdf838f43b0,
+        0x08b8a20080e7465c,
+        0x68618c79c1ab4aa2,
+        0x355e37543d28d3ad,
+    ]),
+    Fr::to_mont_form([
+        0x2dd53d95f0572cf3,
+        0x306e25f9344da942,
+        0x99bb98080d22e70c,
+        0x0a31d7bc3df93308,
+    ]),
+    Fr::to_mont_form([
+        0x10bdc5eac4dcbe38,
+        0x80bae5e283ce4526,
+        0xfa1d12028f145973,
+        0x2f77f587af07ab12,
+    ]),
+    Fr::to_mont_form([
+        0x969c0c54674c6419,
+        0x9eddb750e1fa1ba4,
+        0xe63a56320b2af724,
+        0x2953486d5ae1cdc2,
+    ]),
+    Fr::to_mont_form([
+        0xab793045717cd009,
+        0x28e0b6ad3f8e3e82,
+        0x4443974e1c6ea244,
+        0x0ddcac15cf6b3018,
+    ]),
+    Fr::to_mont_form([
+        0x8de04cbc3c902f51,
+        0x029ce6e848140816,
+        0x01c705e1f2a574cf,
+        0x2570730a4a927176,
+    ]),
+    Fr::to_mont_form([
+        0x9dcee9c90dfd60e6,
+        0x0d6339bb3f108acf,
+        0xf2f144e7c2a8f082,
+        0x55918b7dd19bee89,
+    ]),
+    Fr::to_mont_form([
+        0xf5db00a83910f8f3,
+        0x001d6e1f8c1a9f9e,
+        0x479527eb1eb009d3,
+        0x1ec80ae420359813,
+    ]),
+    Fr::to_mont_form([
+        0xba045da764c417e9,
+        0x31d28ab2f781fb17,
+        0x2e58662382e26bed,
+        0x1f4e875ea20c2080,
+    ]),
+    Fr::to_mont_form([
+        0x6680cbc2732b2a36,
+        0x808cbb1a45ce041a,
+        0x80f92f7a34900b5f,
+        0x4b6324fbee4bbde5,
+    ]),
+    Fr::to_mont_form([
+        0x5c8bc350fb5be07d,
+        0xdd091b2fb4390a17,
+        0xea96f21c3b73efde,
+        0x204cbbec089da234,
+    ]),
+    Fr::to_mont_form([
+        0x27e28223be761764,
+        0x17c914cfdc2d79da,
+        0x3338858d2c7df492,
+        0x1df083752dbe48f4,
+    ]),
+    Fr::to_mont_form([
+        0x0c409d0afb18b89c,
+        0x6d821dac34c27fd6,
+        0x2bcc0059519235f0,
+        0x61b0b85fbc24b8ba,
+    ]),
+    Fr::to_mont_form([
+        0x6c1bae6078384f8d,
+        0x679f121138bbdeca,
+        0xada8c540d8c25f60,
+        0x4731d27ac706a78d,
+    ]),
+    Fr::to_mont_form([
+        0xdb6a1e868ea7f71f,
+        0x9ff28348eca4ad29,
+        0x0b4379739d347a67,
+        0x62a84036a61c5240,
+    ]),
+    Fr::to_mont_form([
+        0xedde630c25916bf8,
+        0x4d5a592ac29ea361,
+        0xd30fca3644068390,
+        0x33b117f2f6a50dd1,
+    ]),
+    Fr::to_mont_form([
+        0xe977726f122a963a,
+        0xd2a5e80016073a18,
+        0xa3c1505c0cec98cd,
+        0x21c998719b61a333,
+    ]),
+    Fr::to_mont_form([
+        0xaffbad84081704e2,
+        0xcb53cddc5b99b788,
+        0x6d4b1946b0930b71,
+        0x1b2ae8102a62ffdd,
+    ]),
+    Fr::to_mont_form([
+        0x695c46ecbcf580fc,
+        0x56e761e6f7017fa8,
+        0x18296c3dda15e698,
+        0x096e6da8f9531c6e,
+    ]),
+    Fr::to_mont_form([
+        0xd78478a0ad5217d6,
+        0xfac686a907951b38,
+        0x371e1bf1ee46a3c8,
+        0x3e7722ea8c18abc5,
+    ]),
+    Fr::to_mont_form([
+        0x70d1201fd5164c82,
+        0x333d6a4694deb062,
+        0x74f9501aaa805abe,
+        0x67bd0e021144c88f,
+    ]),
+    Fr::to_mont_form([
+        0xea4d335211fb9212,
+        0x4d89f7284b60aae4,
+        0xa3f97a38b8ceb04c,
+        0x580865b215ddc7f9,
+    ]),
+    Fr::to_mont_form([
+        0x9bddecd5546a6fd7,
+        0x56253d25e76ca8e8,
+        0x7b3386f9a92b287e,
+        0x10cd1e688711fc64,
+    ]),
+    Fr::to_mont_form([
+        0x164da9b0f65020fd,
+        0x5ced1881e58b62f1,
+        0xe6beaa22352402bf,
+        0x621954c150e808c3,
+    ]),
+    Fr::to_mont_form([
+        0x7e26d843a1b48daf,
+        0x33013adff53655a3,
+        0xefa47ec238bf8122,
+        0x170fdb7a2423ce17,
+    ]),
+    Fr::to_mont_form([
+        0x601f7fed8cc697c5,
+        0xec12b79caf2ba9ef,
+        0xa34c183d01300ee5,
+        0x6c87bf7e6d431914,
+    ]),
+    Fr::to_mont_form([
+        0x339e5602e9a89944,
+        0xa238c98df7210eb1,
+        0xf870108ceb72e715,
+        0x706e15f7b16f29b6,
+    ]),
+    Fr::to_mont_form([
+        0xbb546528794779b6,
+        0x2526a75ce683eae3,
+        0x9dc6b891eba7dc83,
+        0x5958d35cf8acabc0,
+    ]),
+    Fr::to_mont_form([
+        0x3148b2b2642e9db6,
+        0x6160caf3c336b832,
+        0xfe24c162bbe32af0,
+        0x53d6e2a959c39900,
+    ]),
+    Fr::to_mont_form([
+        0x8dd015e270c4b97f,
+        0x32d410e2f2d4503c,
+        0x3ab631fd853a5453,
+        0x1e09267164f2b9a5,
+    ]),
+    Fr::to_mont_form([
+        0xb6ffc35d3842b9f7,
+        0xee5a7f4866650a34,
+        0x71e6ee0a6e9c1fbd,
+        0x603ceedab9c36288,
+    ]),
+    Fr::to_mont_form([
+        0xf0c97988cdc73908,
+        0xb718dba0f92c83f9,
+        0x20ec2713703a4300,
+        0x31a60cd74b238d9a,
+    ]),
+    Fr::to_mont_form([
+        0xfaf1ab6939faad8f,
+        0x978ac0b860f6c3d6,
+        0x40363bfed60cd6b6,
+        0x0e652deb3e8e0f02,
+    ]),
+    Fr::to_mont_form([
+        0x55b5631477645c95,
+        0xa1dfd1a45eac93be,
+        0xc1071175aa550e71,
+        0x3ebad33bcf5f9221,
+    ]),
+    Fr::to_mont_form([
+        0x43c21ad2076f0627,
+        0xf56830352d35a32f,
+        0x7aa3b94260dd1477,
+        0x2289e573cd500e6e,
+    ]),
+    Fr::to_mont_form([
+        0x73c0e6d444b154f5,
+        0x83e03a6f47a88b2f,
+        0x5def2ef2ee1cce37,
+        0x274039b90ac88672,
+    ]),
+    Fr::to_mont_form([
+        0xb363c68988b4e96a,
+        0x89843d46521319a7,
+        0xa098e04c1bd1aa6c,
+        0x13323b24d49f018e,
+    ]),
+    Fr::to_mont_form([
+        0x9afad104b8ff3bd3,
+        0x2dde21d9d36a5057,
+        0x66fd16a451ba0db8,
+        0x3dba0e3a747ab0f7,
+    ]),
+    Fr::to_mont_form([
+        0xad8372a0b2aa3224,
+        0x3f5a69240f79217e,
+        0x6bc2bc151e8b1862,
+        0x0faaf65511d8d37b,
+    ]),
+    Fr::to_mont_form([
+        0x8925298820f91d4b,
+        0x37d20c225c97d849,
+        0x78149a12879fb187,
+        0x27851a082d4b9667,
+    ]),
+    Fr::to_mont_form([
+        0xbca44d0eec300b0a,
+        0xb6ab4b06ace2e27d,
+        0xa2390117c4851b0f,
+        0x705635d476a97061,
+    ]),
+    Fr::to_mont_form([
+        0xd3b6aad7a050d884,
+        0x0b963a56ce865499,
+        0xf56e3e2711bd643d,
+        0x0f2fc0364edefbb4,
+    ]),
+    Fr::to_mont_form([
+        0x6473e343e00bbefa,
+        0xefc4f29b1c3fd73f,
+        0x8ad7a15c94272818,
+        0x17816732ad5a19c4,
+    ]),
+    Fr::to_mont_form([
+        0xa75a6e52039f9446,
+        0x66b72371ec938139,
+        0xa5d7debb42ae1ae5,
+        0x66706ae0abdd0b07,
+    ]),
+    Fr::to_mont_form([
+        0x751792809a648d41,
+        0x285b4f068512f35f,
+        0xb070328596d8feeb,
+        0x33cca9c803e8e5a7,
+    ]),
+    Fr::to_mont_form([
+        0x5e37fd60e30775d5,
+        0x2111d06433136637,
+        0x831dbcf251003e9f,
+        0x4cfb1dbce8eb98a2,
+    ]),
+    Fr::to_mont_form([
+        0xe1da737f1642397c,
+        0xaa31bb7361cb1a99,
+        0x6282b60643dce34d,
+        0x1dd8e0f829a4c6d5,
+    ]),
+    Fr::to_mont_form([
+        0xf28f51cf0e43254d,
+        0xec54ecf50ea1c768,
+        0x6f218216a0e1973e,
+        0x23595fa01f6500a1,
+    ]),
+    Fr::to_mont_form([
+        0x0a299c1fa7a7d797,
+        0xbed976db57bb36ca,
+        0x8f8e10f7f1cbfe7b,
+        0x3c034b1ec779a264,
+    ]),
+    Fr::to_mont_form([
+        0x1f45b6bc08994b1f,
+        0x8fd6e8c7a6d2a703,
+        0xe25344cd83a92293,
+        0x4aa72586b2541fd0,
+    ]),
+    Fr::to_mont_form([
+        0x1ec4212a1a0f190b,
+        0x93bff828e2d1ff93,
+        0xccf5c9ee47ef6274,
+        0x4fb919d017c5ce73,
+    ]),
+    Fr::to_mont_form([
+        0x3e36860e10e3d3f6,
+        0xbed239c41ed4f4ba,
+        0x81433eefcd3e8957,
+        0x0be0904b283bd93d,
+    ]),
+    Fr::to_mont_form([
+        0x9bb2511ef6eaf2a0,
+        0x2344c0f1fb230c0f,
+        0xf26bef03793971e1,
+        0x347831224159dadc,
+    ]),
+    Fr::to_mont_form([
+        0xb5b8fa651018cae4,
+        0x691578ee651885d8,
+        0x15d123785f6888b3,
+        0x5cfdd75b9b5c5768,
+    ]),
+    Fr::to_mont_form([
+        0xa2467e3f635dc995,
+        0x0322ecbb3142dbc9,
+        0x2009a7082cfe3a6e,
+        0x2aa9b2d9afb06ff0,
+    ]),
+    Fr::to_mont_form([
+        0x97aaadbbc54d5c3f,
+        0x37f229c92386c345,
+        0x9cecbadfd94fa768,
+        0x409ea9466e48edf9,
+    ]),
+    Fr::to_mont_form([
+        0x41a1923803d77383,
+        0x6ada9695d4b3a54b,
+        0x022cef864584526a,
+        0x2cfa0d0114ba8078,
+    ]),
+    Fr::to_mont_form([
+        0xe69b36f61a6413e6,
+        0xa82936c0603d47e8,
+        0xba6c6626800ebc86,
+        0x09bc8567af7f2155,
+    ]),
+    Fr::to_mont_form([
+        0xaa61196b43fe4be5,
+        0x97142efb616ac196,
+        0x9137aa46053ae24a,
+        0x44e72a999d6ec762,
+    ]),
+    Fr::to_mont_form([
+        0x36ba8773d67ce0e0,
+        0x3721c70756fd9908,
+        0x56336989d4b411fa,
+        0x25b7906de87b5d8e,
+    ]),
+    Fr::to_mont_form([
+        0xe17e8985d313f007,
+        0xc9ad4ce45f1ce6d5,
+        0x82aa0e669c6dcfd2,
+        0x6f24d2959095faba,
+    ]),
+    Fr::to_mont_form([
+        0xbfb2be1a6aeed460,
+        0x3ba1be3834c08864,
+        0x2723583992329e8b,
+        0x140fd1862bf46f91,
+    ]),
+    Fr::to_mont_form([
+        0xa2f99fbd3d878e8c,
+        0x1a5d3e37b817d483,
+        0x9644a89344e79d4a,
+        0x498d8708cb37cd23,
+    ]),
+    Fr::to_mont_form([
+        0x65f73dacd0175255,
+        0xc0f1d15eee348b9b,
+        0xc33206cde96435e5,
+        0x24bb73dcd45363e9,
+    ]),
+    Fr::to_mont_form([
+        0x664b7efd134bce7d,
+        0x76b56d0ba6b525a1,
+        0x4f3e41559032d71a,
+        0x1f65457811b830b1,
+    ]),
+    Fr::to_mont_form([
+        0x1468d804e1fe632c,
+        0x04daa368808f02a5,
+        0x79a37fe6b9231f32,
+        0x2110ac4f0865fd91,
+    ]),
+    Fr::to_mont_form([
+        0xd886eb8d21d894b9,
+        0x349a9a4943bbba95,
+        0x51c2bc9f3a950e3d,
+        0x4055eb8b775f3b06,
+    ]),
+    Fr::to_mont_form([
+        0xa9bfe826a044e25c,
+        0xa89826f50a767c40,
+        0xa6c2c533d7085cc2,
+        0x5d21d2b0ef7cd067,
+    ]),
+    Fr::to_mont_form([
+        0xd52eaa1f89887db8,
+        0x754a4e5829907505,
+        0x6c6dc7d1f1fdcedf,
+        0x2fa76983480e81cb,
+    ]),
+    Fr::to_mont_form([
+        0xd8fa67f969013b97,
+        0xc897311bcf9f4b79,
+        0x62bb5ed219a7b496,
+        0x274c8298c61ddd2b,
+    ]),
+    Fr::to_mont_form([
+        0x31e7f1770a2f5396,
+        0x543b164d69c5df8d,
+        0x2a3ce41abfd1e557,
+        0x5aaa35c413e21785,
+    ]),
+    Fr::to_mont_form([
+        0x5d766e77e80bea1d,
+        0xf6a2197eca6a4313,
+        0x25552f95b5156a9d,
+        0x4be70bc09261922c,
+    ]),
+    Fr::to_mont_form([
+        0x97c8c9f06265755d,
+        0xdc37d12e78974bef,
+        0xfb3f4033484f3b1c,
+        0x0139c0b32c5ea0cc,
+    ]),
+    Fr::to_mont_form([
+        0xac8c9dcb122cb7d5,
+        0x7494b2e6e9fade6f,
+        0x86528cd9d6a751a3,
+        0x51a1a46e0ece407e,
+    ]),
+    Fr::to_mont_form([
+        0x2a9ef8a96ec951bd,
+        0xcc7d21ecf9afcaca,
+        0xa52a209044bf9447,
+        0x2127af6592cafb54,
+    ]),
+    Fr::to_mont_form([
+        0xc0de552197e4641a,
+        0x43be398598cf13f9,
+        0x03ff213f1937a54f,
+        0x57ef8eb5e5337002,
+    ]),
+    Fr::to_mont_form([
+        0x0f0fa920662dc2d8,
+        0x2a819bb4b95b7743,
+        0x52556ff61b548dca,
+        0x632fe48dda81a10f,
+    ]),
+    Fr::to_mont_form([
+        0x53f48140c5e17c20,
+        0xc57cd589936c5054,
+        0x5264984ce03e2026,
+        0x542292fb92489ef3,
+    ]),
+    Fr::to_mont_form([
+        0x1f0020f2ce751332,
+        0x2d81a1ee5e63e767,
+        0xdfad67445d629f23,
+        0x2b275c11e20e95e8,
+    ]),
+    Fr::to_mont_form([
+        0x108e60cdd5e5df34,
+        0x80d6f6a9b2913820,
+        0x4f3be6ae4a754ac4,
+        0x211e95619aad0bd2,
+    ]),
+    Fr::to_mont_form([
+        0xc4952ed06cc6a8b2,
+        0x157201cf6a4dd128,
+        0x2b12990f7e932f31,
+        0x4eea34556af6aebb,
+    ]),
+    Fr::to_mont_form([
+        0x1b7bd688026ccbcf,
+        0x330f7f47c113fe6d,
+        0x5b55ff3f9b1b3b7d,
+        0x30bde6b358bc6c7a,
+    ]),
+    Fr::to_mont_form([
+        0x1294b87896478cd2,
+        0xabbe7e8b03a4b51c,
+        0x86c3cc7590d3df22,
+        0x067870072e0fb91c,
+    ]),
+    Fr::to_mont_form([
+        0xe84bd5e518505347,
+        0x660802e21bd2c7d3,
+        0x9ec709f979769f43,
+        0x4428ffc97323230c,
+    ]),
+    Fr::to_mont_form([
+        0x2641cdf95dbe08c1,
+        0xde979f3ae5132c70,
+        0x48159ba80f250e81,
+        0x1a2cb8e2120c5d18,
+    ]),
+    Fr::to_mont_form([
+        0xe93f388944d7627d,
+        0x2f0162e54cebfae3,
+        0xe66bbf71f10c8ebc,
+        0x3d7daa48ac5bf4fb,
+    ]),
+    Fr::to_mont_form([
+        0x0a1d5c1222ccd6df,
+        0xbfd45081694fc122,
+        0x264a4c635f72347d,
+        0x03d2dbadd546d64f,
+    ]),
+    Fr::to_mont_form([
+        0x58a0e779717e5df5,
+        0xd9eceb3c6beb7762,
+        0x66695c8991658350,
+        0x65add14a678d5028,
+    ]),
+    Fr::to_mont_form([
+        0xe7704e293143d3c7,
+        0xc2e596daebf53323,
+        0x291580efe42c1234,
+        0x3e3cf7c2eff708e6,
+    ]),
+    Fr::to_mont_form([
+        0x5f6c689d5638c4c7,
+        0x944345d48b3df605,
+        0x52460c5e37f4c657,
+        0x18473009c2373aa9,
+    ]),
+    Fr::to_mont_form([
+        0x75ccbce53e5f863b,
+        0xe70cb6490b935ab7,
+        0x1d5b4157698c1246,
+        0x39ec76faec1c38de,
+    ]),
+    Fr::to_mont_form([
+        0x432c1dfb7f9abcc0,
+        0xb7bf6c26143e853a,
+        0x42053ac1cdb361ed,
+        0x6b4bfb6d74fdd480,
+    ]),
+    Fr::to_mont_form([
+        0x7c35b5fed456ecb1,
+        0x6d05c7711946c11f,
+        0x13da2755abd10936,
+        0x0093b4f13863fd1a,
+    ]),
+    Fr::to_mont_form([
+        0xc5503a34126d0053,
+        0x0592c35eb541bf57,
+        0x8d63d2245c3c1524,
+        0x36cca4cf6ea8bee6,
+    ]),
+    Fr::to_mont_form([
+        0x4e460a562fddd410,
+        0x3962de85da16b85a,
+        0xfcabc7e2716dcdf0,
+        0x328f06b48f0a88e1,
+    ]),
+    Fr::to_mont_form([
+        0x5228a4aa171a2286,
+        0x1cf15a0ea7ce36cd,
+        0x55f933cb2619a6f5,
+        0x136fb5a9a0a8352b,
+    ]),
+    Fr::to_mont_form([
+        0xb03d79fbdfe3b674,
+        0x6917e6398e7f3a9b,
+        0x5b0ac6094cf00801,
+        0x5f58a91a722c6a3f,
+    ]),
+    Fr::to_mont_form([
+        0x8f5c73e9c1c58db7,
+        0xe592f4e614c1ee0e,
+        0x43c9abecc67e36ea,
+        0x1b331c5981c36d21,
+    ]),
+    Fr::to_mont_form([
+        0xc040ae97c5a83717,
+        0x859001af7bbb6a9e,
+        0xa3390e81afe6c4ac,
+        0x67a5af5666d44e15,
+    ]),
+    Fr::to_mont_form([
+        0xa27bcbbdb87f9324,
+        0x3acc98fd838d4ecb,
+        0x255c56408d40cfcc,
+        0x2ab8a743a254fb95,
+    ]),
+    Fr::to_mont_form([
+        0x763752c87ad88f37,
+        0x077f85ced0f79bd0,
+        0x32bb871dde2ca125,
+        0x0e5cbbf48c274a98,
+    ]),
+    Fr::to_mont_form([
+        0xc13a8c232c39b2a2,
+        0x201f79c4bb286956,
+        0x2fc4971f04ad87d9,
+        0x5ea2543abc7f9b38,
+    ]),
+    Fr::to_mont_form([
+        0x87e5eda2ab53bc33,
+        0x616bd1ee11b29b27,
+        0xedbbd10caf4b836c,
+        0x212e2e51b2bc327b,
+    ]),
+    Fr::to_mont_form([
+        0x8089a4a77933e2d3,
+        0x71167133ab2a7fbd,
+        0xabd503acabbd4178,
+        0x67c2fc5508272d98,
+    ]),
+    Fr::to_mont_form([
+        0x3949906f1abd1f29,
+        0xa76667da4f164995,
+        0x089f519f7d14cdb0,
+        0x01705b58e234656d,
+    ]),
+    Fr::to_mont_form([
+        0x57114d99feb008e2,
+        0x555c16175ce941e0,
+        0x23996cefeb116189,
+        0x2c53fdef1f89b71f,
+    ]),
+    Fr::to_mont_form([
+        0xb45a4e9d84e4f603,
+        0xf494d134c02ac89c,
+        0x647df19763ededed,
+        0x37fda2539778f01a,
+    ]),
+    Fr::to_mont_form([
+        0x075b19bbf9553f4c,
+        0x599e224c88e18514,
+        0x16f602b65d9bd801,
+        0x2ba93bfa7ff954d3,
+    ]),
+    Fr::to_mont_form([
+        0xca0bb3fc5306b4c2,
+        0xd9ea1f926b6f4525,
+        0x8d80c949688dc7de,
+        0x2607bde97e015c06,
+    ]),
+    Fr::to_mont_form([
+        0x2b194861c323fa88,
+        0x638501a30901c3ec,
+        0xe8dfb47bccf18611,
+        0x257931a1414fae69,
+    ]),
+    Fr::to_mont_form([
+        0x7b1aa513faeb7af8,
+        0xaa6a854c1e54391e,
+        0xaaa91b7db7a2065c,
+        0x4c27d2953aa74af9,
+    ]),
+    Fr::to_mont_form([
+        0x962549f8456763bf,
+        0x689a1e14c883751b,
+        0xc8671226bf047e9e,
+        0x0771483eda4cfc57,
+    ]),
+    Fr::to_mont_form([
+        0x141272e1ca7e4bab,
+        0x929ebeaeb275792e,
+        0xbdb64afed61268ad,
+        0x3449138391eae873,
+    ]),
+    Fr::to_mont_form([
+        0x12d843cbca51a2f3,
+        0x66dd4f43ce090a5a,
+        0x95528834f7387bb6,
+        0x64587cb3af36f910,
+    ]),
+    Fr::to_mont_form([
+        0x4db72aad684d7190,
+        0x5dfad5dddef47cb2,
+        0x3571b5ffae4beae0,
+        0x26a81d288983188d,
+    ]),
+    Fr::to_mont_form([
+        0xf74b6f35f28e3b4d,
+        0x1b7bc684d55858ab,
+        0xba37ee10115f457e,
+        0x1aa3afc1f8c731a0,
+    ]),
+    Fr::to_mont_form([
+        0xe271421f7e776139,
+        0x4393f9e19453dcf1,
+        0x90d87aab11c22b92,
+        0x02a6cb8fad43677d,
+    ]),
+    Fr::to_mont_form([
+        0xd47b8f69e832f846,
+        0x4d12a6013b963502,
+        0x8d461e912f178393,
+        0x1d08e7370d05cd45,
+    ]),
+    Fr::to_mont_form([
+        0x46467c07fb7d5a20,
+        0xb2f8f3bfb708a9a9,
+        0x41c49d2c7a522402,
+        0x213a8f8d15ca7a54,
+    ]),
+    Fr::to_mont_form([
+        0x4250312d3f3518cc,
+        0x7c0efe303700bbd3,
+        0x2acce97449eb3501,
+        0x4af2d48a57fecee7,
+    ]),
+    Fr::to_mont_form([
+        0x004e84562c422be6,
+        0x1a21849ec89efbf8,
+        0x0eb365c6ce8b1575,
+        0x59ca5e895d587677,
+    ]),
+    Fr::to_mont_form([
+        0x26998f87b9b0409e,
+        0x2bffa34f303a7981,
+        0x0036aae8f2f636e3,
+        0x670a40e517496889,
+    ]),
+    Fr::to_mont_form([
+        0xa90818b0fb1b4ac5,
+        0x3daa3f5cb4848f7c,
+        0xa6ff98901c0f815b,
+        0x124f73ff214c005e,
+    ]),
+    Fr::to_mont_form([
+        0xe3f7b7e247de17c4,
+        0xbe0a3fb21e55b0da,
+        0x34e064b60b45a831,
+        0x358c375617dac1d0,
+    ]),
+    Fr::to_mont_form([
+        0x565676ac8a8c0135,
+        0x8f304e5a59346846,
+        0x77b088f27fb36fda,
+        0x0c002733a1546986,
+    ]),
+    Fr::to_mont_form([
+        0x319f0c92a4a47837,
+        0x8619640eada780b1,
+        0xf1713f00bf535211,
+        0x2b11d8fa8ccd4128,
+    ]),
+    Fr::to_mont_form([
+        0xf6b017f3631516ef,
+        0xd9763abda41eacc8,
+        0xcb9a98f07464fc3c,
+        0x058502be728c5c4b,
+    ]),
+    Fr::to_mont_form([
+        0x4ff4ad36d5ff5242,
+        0x2a004ac5eabede47,
+        0x3ba7b6b2dcd5029e,
+        0x4b0b728c86bbb276,
+    ]),
+    Fr::to_mont_form([
+        0x6f2d8c38466eb75e,
+        0x84b44741fcfa137a,
+        0xad374ad625d34846,
+        0x3b04a5f7f0be97df,
+    ]),
+    Fr::to_mont_form([
+        0x148dad17691e59e3,
+        0x218d3ad34daa5861,
+        0x378dea7052b2f3cf,
+        0x12a53b4d54b75419,
+    ]),
+    Fr::to_mont_form([
+        0xd76e0e8f533f0363,
+        0x5337515f24f18153,
+        0xbeda5aa3ed42620c,
+        0x5c6dc11e1ae603f4,
+    ]),
+    Fr::to_mont_form([
+        0xafa662b178852c77,
+        0x6f4a6874cb481ed9,
+        0x3b23b8c3c786e529,
+        0x1142ce3e47e42d9d,
+    ]),
+    Fr::to_mont_form([
+        0xda05b50767584dba,
+        0x92200abb6bc26715,
+        0x758ff596890a0890,
+        0x2d039d76b455bc8b,
+    ]),
+    Fr::to_mont_form([
+        0xffbd3cce01ee0b3a,
+        0xbe8e31ed367dc6b4,
+        0xdb50c8b2f7747783,
+        0x4c496db89236a71e,
+    ]),
+    Fr::to_mont_form([
+        0xa43e586a31b3235c,
+        0xed0f9018b979e8a8,
+        0xc852971cd6bbe7eb,
+        0x24b6aae12dad881d,
+    ]),
+    Fr::to_mont_form([
+        0xfa1f0db7058064dc,
+        0x37565b790dc7672e,
+        0xb18394634e99948c,
+        0x19d6e6c827d19f62,
+    ]),
+    Fr::to_mont_form([
+        0x87f9d742a6317eb6,
+        0x028362683b0bf745,
+        0x0ebd3c52b0408565,
+        0x450e10a1a54eab3a,
+    ]),
+    Fr::to_mont_form([
+        0x7f9e9aa14a5d740e,
+        0xffa12fe8c8337e17,
+        0xc158788a8a21d076,
+        0x2e2658bb22656b9f,
+    ]),
+    Fr::to_mont_form([
+        0x11871441f61c0c26,
+        0x54be994cfccd0cc4,
+        0xd7e08bb232e60e4b,
+        0x3fb197ca5204e90c,
+    ]),
+    Fr::to_mont_form([
+        0xb88c1e68c7a3be9c,
+        0x1bf82472d38ad5ca,
+        0xf55b4f844b791c7c,
+        0x38bb9ebcdbeec38f,
+    ]),
+    Fr::to_mont_form([
+        0xe6267c936c22b277,
+        0x24b68064eaf9b507,
+        0xd869000337519a9f,
+        0x38ab498681a1c82d,
+    ]),
+    Fr::to_mont_form([
+        0x375160fe27aae176,
+        0x43c2b2a27c862df5,
+        0x8da7ccd6691482bf,
+        0x52b5703823a931e4,
+    ]),
+    Fr::to_mont_form([
+        0xeac6ecf55f5197c5,
+        0x011a80c131f90601,
+        0xebc0772ae28c03a3,
+        0x323dab5e665aa86e,
+    ]),
+    Fr::to_mont_form([
+        0x9c91b015bec4a9ff,
+        0xbafb9cc30aa61cfb,
+        0xeab8ab1e5544c9dc,
+        0x29b8e50a967ee840,
+    ]),
+    Fr::to_mont_form([
+        0x36ddff85f6aaeed6,
+        0x36fdd760e4574dfa,
+        0x014592663b219151,
+        0x0188df8149041c39,
+    ]),
+    Fr::to_mont_form([
+        0xd8e8eb2944f5db80,
+        0xc7b17dfe92332a6f,
+        0xc26f78e357a0a766,
+        0x1d905c3ead141296,
+    ]),
+    Fr::to_mont_form([
+        0x53b83fc76f05e56b,
+        0x683bedf1eabd4ede,
+        0x99fd6fa26bfb34f2,
+        0x6f52442e6b8a0742,
+    ]),
+    Fr::to_mont_form([
+        0x20dcc8784ee49bb0,
+        0xacc5c0902cee33ed,
+        0xad94d6350c595e3e,
+        0x6feae48c8a495dfb,
+    ]),
+    Fr::to_mont_form([
+        0xf08f70821db94ff4,
+        0xe39900fa3f563173,
+        0xcf77d5c15f797bdd,
+        0x4637a746c66108e3,
+    ]),
+    Fr::to_mont_form([
+        0x6a2551da05138a31,
+        0x009c067753528d89,
+        0xf5f0c0b1ed94aeaa,
+        0x3b18488c73efa27e,
+    ]),
+    Fr::to_mont_form([
+        0xccb3ae406ca0c47f,
+        0xefb6d739099844b8,
+        0x5f8eeb383a25eeed,
+        0x16cf50168e683e38,
+    ]),
+    Fr::to_mont_form([
+        0x84aa2ed64b1cc8ad,
+        0x36937aa554b3ea9f,
+        0xdadde4fa021effb2,
+        0x34f93ff5b79fa9dd,
+    ]),
+    Fr::to_mont_form([
+        0x354ccac5e02a19d3,
+        0x45ae1c112dc45f79,
+        0x494f3f3bbb8a3b40,
+        0x097145db51c1b3cf,
+    ]),
+    Fr::to_mont_form([
+        0xc5a1ebfe83386dd6,
+        0x03b1d842ca242975,
+        0x903beff5c342b2ff,
+        0x1202ef01d6017fa7,
+    ]),
+    Fr::to_mont_form([
+        0x15c0ec1c803d72f6,
+        0x4ecca491ad6472c0,
+        0x7a58a748d0729740,
+        0x56dc3ab4d3708019,
+    ]),
+    Fr::to_mont_form([
+        0x039df088704e65ac,
+        0x29a60a0f9774bcd1,
+        0xb4e2a21626bc375c,
+        0x441307897735edb1,
+    ]),
+    Fr::to_mont_form([
+        0x1fab3e180356062c,
+        0x3f52377c73869914,
+        0x9ccd3e0bd9216172,
+        0x6859825edeaa61dd,
+    ]),
+    Fr::to_mont_form([
+        0xdd5f5b736686d31d,
+        0x617560b916388554,
+        0x12d44214fd2bd424,
+        0x0e7ad8d62e003ce7,
+    ]),
+    Fr::to_mont_form([
+        0x02a724ab07d818a8,
+        0xb69694f7e27f1b9d,
+        0x8ebdf003074a9c6c,
+        0x690d7914641169e7,
+    ]),
+    Fr::to_mont_form([
+        0x1708cd57708ebc18,
+        0x96c80bca507813f6,
+        0xad66e3afb4a9dd66,
+        0x4ddc7cf6a2d2af64,
+    ]),
+    Fr::to_mont_form([
+        0x6d5a1a3347f21578,
+        0x4cb7856577691863,
+        0x09ba31d045f0f176,
+        0x15da895813727dc3,
+    ]),
+    Fr::to_mont_form([
+        0x9916989f6bfb8b34,
+        0x5f008f305707f370,
+        0xd92dcd93d80f4eef,
+        0x54b76d02103b8a34,
+    ]),
+    Fr::to_mont_form([
+        0xae9c1c2195d7e7d0,
+        0x52fd73a3ad74b7bb,
+        0xec20cd17211b45f9,
+        0x289f600805e6cfb8,
+    ]),
+    Fr::to_mont_form([
+        0xc3e46aec80deee73,
+        0xb599f166f0326813,
+        0x41addee86af902e4,
+        0x171fff0b2380075b,
+    ]),
+    Fr::to_mont_form([
+        0x46449d17665b307c,
+        0x41d73c248ceb9a6f,
+        0xfff5fbd964d44139,
+        0x6721df7135be34e9,
+    ]),
+    Fr::to_mont_form([
+        0x3d1b02073c868610,
+        0x83be53b4359d5817,
+        0x6264e723e7ad5237,
+        0x4ef25c6bb9efb3dd,
+    ]),
+    Fr::to_mont_form([
+        0xed2197c51c9a41e9,
+        0x0383b507b85911da,
+        0x8784b839bfa8a681,
+        0x57935aecf86d2aba,
+    ]),
+    Fr::to_mont_form([
+        0x668f4ed8de400cb3,
+        0xb6f0b68e82e7837e,
+        0x0af35a52bb96a16e,
+        0x437eb1d0de1c7de8,
+    ]),
+    Fr::to_mont_form([
+        0x3bac363e431df65d,
+        0x691c7af85d9bf0f6,
+        0x8e77f936e22feef1,
+        0x60d40be55592abd2,
+    ]),
+    Fr::to_mont_form([
+        0x312e0df012538931,
+        0x1bc1ca088f493d1b,
+        0xb6eac1bdf1c301ad,
+        0x3b9e9bf0f10cbaa8,
+    ]),
+    Fr::to_mont_form([
+        0x3154bc2b88d6e974,
+        0xbdce4b731479d489,
+        0xf963ffad4eaf79fd,
+        0x680228df059638b0,
+    ]),
+    Fr::to_mont_form([
+        0x5e13c894efb3d59d,
+        0x2964dc2e879ae1e6,
+        0x547df5869b61828b,
+        0x14b088ac9d7b29ab,
+    ]),
+    Fr::to_mont_form([
+        0xd58035eeb349ba1b,
+        0x5e6c38b8e06ddc9d,
+        0x7469b9557e2f5078,
+        0x3ff490ff8392ab04,
+    ]),
+    Fr::to_mont_form([
+        0x5281304a75e6e21c,
+        0x2bf3088b2010c72d,
+        0xc20db60936213efb,
+        0x0a228bdbcdea52f4,
+    ]),
+    Fr::to_mont_form([
+        0x9469823cfacd1618,
+        0x359032bda9591f9a,
+        0x4b6fc83256a8f6bc,
+        0x2e179c6f359dfc2f,
+    ]),
+    Fr::to_mont_form([
+        0xc1ad565220efd41f,
+        0x6151f5a67799510c,
+        0xc1f00d04b696ea7d,
+        0x4a5270ba824b4820,
+    ]),
+    Fr::to_mont_form([
+        0x6571095196c1c863,
+        0x29e600e2da770bfc,
+        0xcb8bfec9221e8de6,
+        0x54c2b8a36d66ad27,
+    ]),
+    Fr::to_mont_form([
+        0x75f7d984d5852fc2,
+        0x363b422b7e6ee39c,
+        0xe706773c5f7b93cc,
+        0x4fdbab13b046e1d3,
+    ]),
+    Fr::to_mont_form([
+        0x3d7c0b9daa4ec492,
+        0xab293fb5e1ce76c6,
+        0x179774adcc382bd5,
+        0x6ab96c3d43a5657a,
+    ]),
+    Fr::to_mont_form([
+        0x1abad58bf4ad3e46,
+        0xb113847305999ff5,
+        0x628eaa19a0c23245,
+        0x6dd3fc5298d02c6f,
+    ]),
+    Fr::to_mont_form([
+        0x2d42f543cb16ce71,
+        0xd5e0de680cfd3e5d,
+        0x2058799778b53405,
+        0x48f4730665167e9a,
+    ]),
+    Fr::to_mont_form([
+        0x6abb7bb020608a11,
+        0xc9f164364c2c64b6,
+        0xdf1f78870c31d1cf,
+        0x7344d04f342e001c,
+    ]),
+    Fr::to_mont_form([
+        0xb770c749be890dd6,
+        0xc6492994a908712b,
+        0x61326141e1d72e9f,
+        0x28eb93a1674ad4dd,
+    ]),
+    Fr::to_mont_form([
+        0x9cfc47d2522e400c,
+        0x0222b305e4c893ef,
+        0x3e6fbb2882ef1c7a,
+        0x32b6bd7c91426ceb,
+    ]),
+    Fr::to_mont_form([
+        0x3ac0145f8705c4c7,
+        0xec2d7435a718f058,
+        0xb21ef81ad4f86f73,
+        0x55a8b14a5b59e046,
+    ]),
+    Fr::to_mont_form([
+        0x0b6420b2fd7cf2d6,
+        0xc38e10726a0475f1,
+        0x0778f5680c243be9,
+        0x2ff54e598e81dff7,
+    ]),
+    Fr::to_mont_form([
+        0xe06ac4540aeae0cf,
+        0xbba546eb1aa1a219,
+        0x47ff8c3abaa674a9,
+        0x3faedc216a80caa6,
+    ]),
+    Fr::to_mont_form([
+        0xaf7b22f8384b265d,
+        0xc3328307ebfe2fee,
+        0x9e08c2e8a7eb1945,
+        0x2e4f7934a296cf81,
+    ]),
+    Fr::to_mont_form([
+        0xf94cb13651b47dd3,
+        0x43f502df87339c9c,
+        0xcf3b435c2f7f264d,
+        0x0080b8d61a3a61e0,
+    ]),
+    Fr::to_mont_form([
+        0x4a321669ab5ac72c,
+        0x8416003b9e03b34f,
+        0x66e63acdfd7c64d6,
+        0x3a409ddb3ea5df7f,
+    ]),
+    Fr::to_mont_form([
+        0x0d4f496c0dbf740b,
+        0xa565350dc66bc383,
+        0x67b1321669c8ceb2,
+        0x48c5c810c7e60170,
+    ]),
+];
+
+pub(crate) const MDS_MATRIX: [[Fr; WIDTH]; WIDTH] = [
+    [
+        Fr::to_mont_form([
+            0xaaaaaaaa00000001,
+            0xe27e6d5755543d54,
+            0xccd13ab0066be558,
+            0x4d491a377113a8da,
+        ]),
+        Fr::to_mont_form([
+            0x3fffffff40000001,
+            0xfece3b023ffec4ff,
+            0x266b620607396203,
+            0x56f23d7e5f361df6,
+        ]),
+        Fr::to_mont_form([
+            0xcccccccc33333334,
+            0x323e959b66656a65,
+            0x51ef819e6c2de803,
+            0x458e97984c2b4b2b,
+        ]),
+    ],
+    [
+        Fr::to_mont_form([
+            0x3fffffff40000001,
+            0xfece3b023ffec4ff,
+            0x266b620607396203,
+            0x56f23d7e5f361df6,
+        ]),
+        Fr::to_mont_form([
+            0xcccccccc33333334,
+            0x323e959b66656a65,
+            0x51ef819e6c2de803,
+            0x458e97984c2b4b2b,
+        ]),
+        Fr::to_mont_form([
+            0xd555555480000001,
+            0x1b1e08ad2aa94ca9,
+            0x8005895c0806deaf,
+            0x609b60c54d589311,
+        ]),
+    ],
+    [
+        Fr::to_mont_form([
+            0xcccccccc33333334,
+            0x323e959b66656a65,
+            0x51ef819e6c2de803,
+            0x458e97984c2b4b2b,
+        ]),
+        Fr::to_mont_form([
+            0xd555555480000001,
+            0x1b1e08ad2aa94ca9,
+            0x8005895c0806deaf,
+            0x609b60c54d589311,
+        ]),
+        Fr::to_mont_form([
+            0xdb6db6db24924925,
+            0xaa362edc49241a48,
+            0x57c7624b7077624a,
+            0x211f5460e7519182,
+        ]),
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use blake2b_simd::Params;
+    use zero_bls12_381::Fr;
+    use zkstd::common::FftField;
+
+    use super::{MDS_MATRIX, ROUND_CONSTANTS};
+    use crate::WIDTH;
+
+    #[test]
+    fn round_constants_derivation() {
+        ROUND_CONSTANTS
+            .iter()
+            .enumerate()
+            .for_each(|(i, constant)| {
+                let mut message = b"zero-poseidon".to_vec();
+                message.extend_from_slice(&(i as u32).to_le_bytes());
+
+                let digest = Params::new().hash_length(64).hash(&message);
+                let bytes = <[u8; 64]>::try_from(digest.as_bytes()).unwrap();
+
+                assert_eq!(*constant, Fr::from_bytes_wide(&bytes));
+            })
+    }
+
+    #[test]
+    fn mds_matrix_derivation() {
+        MDS_MATRIX.iter().enumerate().for_each(|(i, row)| {
+            row.iter().enumerate().for_each(|(j, entry)| {
+                let denominator = Fr::from((i + WIDTH + j) as u64);
+
+                assert_eq!(*entry * denominator, Fr::one());
+                assert_ne!(*entry, Fr::zero());
+            })
+        })
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Poseidon gadgets matching the native [`permutation`](crate::permutation)
+//! and [`hash`](crate::hash)
+//!
+//! The round constants are folded into the gates: an S-box takes three gates
+//! and the MDS matrix, which combines the three elements of the state, takes
+//! one gate per element, so a full round appends twelve gates and a partial
+//! round six.
+
+use zero_bls12_381::Fr;
+use zero_r1cs::prelude::{Composer, Constraint, Witness};
+use zkstd::common::{Pairing, PrimeField};
+
+use crate::constants::{MDS_MATRIX, ROUND_CONSTANTS};
+use crate::permutation::is_full_round;
+use crate::{RATE, WIDTH};
+
+/// Constrain the Poseidon permutation of `state`
+pub fn permutation_gadget<P, C>(composer: &mut C, state: &mut [Witness; WIDTH])
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    ROUND_CONSTANTS
+        .chunks_exact(WIDTH)
+        .enumerate()
+        .for_each(|(round, constants)| {
+            // the constants of the elements skipping the S-box are added by
+            // the mix
+            let mut offsets = [Fr::zero(); WIDTH];
+
+            state
+                .iter_mut()
+                .zip(constants)
+                .zip(offsets.iter_mut())
+                .enumerate()
+                .for_each(|(i, ((element, constant), offset))| {
+                    if i == 0 || is_full_round(round) {
+                        *element = quintic_gadget(composer, *element, *constant);
+                    } else {
+                        *offset = *constant;
+                    }
+                });
+
+            *state = mix_gadget(composer, state, &offsets);
+        })
+}
+
+/// Constrain the Poseidon hash of `message` and return it
+pub fn hash_gadget<P, C>(composer: &mut C, message: &[Witness]) -> Witness
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    let length = composer.append_constant(Fr::from(message.len() as u64));
+    let mut state = [length, C::ZERO, C::ZERO];
+
+    message.chunks(RATE).for_each(|block| {
+        state[1..]
+            .iter_mut()
+            .zip(block)
+            .for_each(|(element, input)| {
+                // the rate is zero until the first permutation
+                *element = if *element == C::ZERO {
+                    *input
+                } else {
+                    add_gadget(composer, *element, *input)
+                }
+            });
+
+        permutation_gadget(composer, &mut state);
+    });
+
+    // the empty message is still permuted once
+    if message.is_empty() {
+        permutation_gadget(composer, &mut state);
+    }
+
+    state[1]
+}
+
+// (x + c)^5
+fn quintic_gadget<P, C>(composer: &mut C, x: Witness, c: Fr) -> Witness
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    let shifted = composer.value(x).map(|x| x + c);
+    let square = shifted.map(|shifted| shifted.square());
+    let quartic = square.map(|square| square.square());
+
+    // (x + c)^2 = x * x + 2c * x + c^2
+    let square_w = composer.append_optional_witness(square);
+    let constraint = Constraint::new()
+        .mult(1)
+        .left(c.double())
+        .constant(c.square())
+        .output(-Fr::one())
+        .a(x)
+        .b(x)
+        .o(square_w);
+    composer.append_gate(constraint);
+
+    // (x + c)^4
+    let quartic_w = composer.append_optional_witness(quartic);
+    let constraint = Constraint::new()
+        .mult(1)
+        .output(-Fr::one())
+        .a(square_w)
+        .b(square_w)
+        .o(quartic_w);
+    composer.append_gate(constraint);
+
+    // (x + c)^5 = (x + c)^4 * x + c * (x + c)^4
+    let quintic = quartic
+        .zip(shifted)
+        .map(|(quartic, shifted)| quartic * shifted);
+    let quintic_w = composer.append_optional_witness(quintic);
+    let constraint = Constraint::new()
+        .mult(1)
+        .left(c)
+        .output(-Fr::one())
+        .a(quartic_w)
+        .b(x)
+        .o(quintic_w);
+    composer.append_gate(constraint);
+
+    quintic_w
+}
+
+// M * (state + offsets), one gate per row of the width 3 matrix
+fn mix_gadget<P, C>(
+    composer: &mut C,
+    state: &[Witness; WIDTH],
+    offsets: &[Fr; WIDTH],
+) -> [Witness; WIDTH]
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    let [a, b, d] = *state;
+
+    MDS_MATRIX.map(|row| {
+        let constant = row
+            .iter()
+            .zip(offsets.iter())
+            .fold(Fr::zero(), |acc, (m, offset)| acc + *m * *offset);
+        let value = composer
+            .value(a)
+            .zip(composer.value(b))
+            .zip(composer.value(d))
+            .map(|((a, b), d)| row[0] * a + row[1] * b + row[2] * d + constant);
+
+        let mixed = composer.append_optional_witness(value);
+        let constraint = Constraint::new()
+            .left(row[0])
+            .right(row[1])
+            .fourth(row[2])
+            .constant(constant)
+            .output(-Fr::one())
+            .a(a)
+            .b(b)
+            .d(d)
+            .o(mixed);
+        composer.append_gate(constraint);
+
+        mixed
+    })
+}
+
+fn add_gadget<P, C>(composer: &mut C, a: Witness, b: Witness) -> Witness
+where
+    P: Pairing<ScalarField = Fr>,
+    C: Composer<P>,
+{
+    let sum = composer.value(a).zip(composer.value(b)).map(|(a, b)| a + b);
+    let sum = composer.append_optional_witness(sum);
+    let constraint = Constraint::new()
+        .left(1)
+        .right(1)
+        .output(-Fr::one())
+        .a(a)
+        .b(b)
+        .o(sum);
+    composer.append_gate(constraint);
+
+    sum
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(not(feature = "std"), no_std)]
+#![doc = include_str!("../README.md")]
+
+mod constants;
+mod gadget;
+mod permutation;
+mod sponge;
+
+pub use gadget::{hash_gadget, permutation_gadget};
+pub use permutation::permutation;
+pub use sponge::hash;
+
+/// Width of the permutation state, the capacity element followed by the rate
+pub const WIDTH: usize = 3;
+
+/// Elements absorbed per permutation
+pub const RATE: usize = WIDTH - 1;
+
+/// Full rounds count, half of them surrounding the partial rounds on each side
+pub const FULL_ROUNDS: usize = 8;
+
+/// Partial rounds count
+pub const PARTIAL_ROUNDS: usize = 57;
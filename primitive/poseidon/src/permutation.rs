@@ -0,0 +1,72 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use zero_bls12_381::Fr;
+use zkstd::common::PrimeField;
+
+use crate::constants::{MDS_MATRIX, ROUND_CONSTANTS};
+use crate::{FULL_ROUNDS, PARTIAL_ROUNDS, WIDTH};
+
+/// Poseidon permutation of the state
+///
+/// Every round adds its constants to the state, applies the `x^5` S-box to
+/// every element on the full rounds and to the first one on the partial
+/// rounds, then multiplies the state by the MDS matrix.
+pub fn permutation(state: &mut [Fr; WIDTH]) {
+    ROUND_CONSTANTS
+        .chunks_exact(WIDTH)
+        .enumerate()
+        .for_each(|(round, constants)| {
+            state
+                .iter_mut()
+                .zip(constants)
+                .for_each(|(element, constant)| *element += *constant);
+
+            if is_full_round(round) {
+                state
+                    .iter_mut()
+                    .for_each(|element| *element = quintic(*element));
+            } else {
+                state[0] = quintic(state[0]);
+            }
+
+            *state = mix(state);
+        })
+}
+
+pub(crate) fn is_full_round(round: usize) -> bool {
+    let half = FULL_ROUNDS / 2;
+
+    round < half || round >= half + PARTIAL_ROUNDS
+}
+
+fn quintic(x: Fr) -> Fr {
+    x.square().square() * x
+}
+
+fn mix(state: &[Fr; WIDTH]) -> [Fr; WIDTH] {
+    let mut mixed = [Fr::zero(); WIDTH];
+
+    mixed
+        .iter_mut()
+        .zip(MDS_MATRIX.iter())
+        .for_each(|(mixed, row)| {
+            *mixed = row
+                .iter()
+                .zip(state.iter())
+                .fold(Fr::zero(), |acc, (m, s)| acc + *m * *s)
+        });
+
+    mixed
+}
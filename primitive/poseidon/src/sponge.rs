@@ -0,0 +1,71 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use zero_bls12_381::Fr;
+
+use crate::permutation::permutation;
+use crate::{RATE, WIDTH};
+
+/// Hash a message with the Poseidon sponge
+///
+/// The capacity element is initialized with the message length, so messages
+/// differing only by trailing zeros don't collide. The message is absorbed
+/// `RATE` elements at a time, the last block being padded with zeros, and the
+/// first rate element is squeezed after the last permutation.
+pub fn hash(message: &[Fr]) -> Fr {
+    let mut state = [Fr::zero(); WIDTH];
+    state[0] = Fr::from(message.len() as u64);
+
+    message.chunks(RATE).for_each(|block| {
+        state[1..]
+            .iter_mut()
+            .zip(block)
+            .for_each(|(element, input)| *element += *input);
+
+        permutation(&mut state);
+    });
+
+    // the empty message is still permuted once
+    if message.is_empty() {
+        permutation(&mut state);
+    }
+
+    state[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+    use zero_bls12_381::Fr;
+    use zkstd::common::Group;
+
+    use super::hash;
+
+    #[test]
+    fn hash_is_deterministic() {
+        let message: Vec<_> = (0..5).map(|_| Fr::random(OsRng)).collect();
+
+        assert_eq!(hash(&message), hash(&message));
+        assert_ne!(hash(&message), hash(&message[..4]));
+    }
+
+    #[test]
+    fn hash_length_separation() {
+        let one = Fr::from(1);
+
+        assert_ne!(hash(&[]), hash(&[Fr::zero()]));
+        assert_ne!(hash(&[one]), hash(&[one, Fr::zero()]));
+        assert_ne!(hash(&[one, one]), hash(&[one, one, Fr::zero()]));
+    }
+}
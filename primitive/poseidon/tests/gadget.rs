@@ -0,0 +1,105 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_poseidon::{hash, hash_gadget, permutation, permutation_gadget, WIDTH};
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+#[derive(Debug)]
+struct HashCircuit<const N: usize> {
+    message: [BlsScalar; N],
+    digest: BlsScalar,
+}
+
+impl<const N: usize> HashCircuit<N> {
+    fn new(message: [BlsScalar; N]) -> Self {
+        Self {
+            message,
+            digest: hash(&message),
+        }
+    }
+}
+
+impl<const N: usize> Default for HashCircuit<N> {
+    fn default() -> Self {
+        Self {
+            message: [BlsScalar::zero(); N],
+            digest: BlsScalar::zero(),
+        }
+    }
+}
+
+impl<const N: usize> Circuit<TatePairing> for HashCircuit<N> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let message = self.message.map(|m| composer.append_witness(m));
+
+        let digest = hash_gadget(composer, &message);
+
+        composer.assert_equal_constant(digest, BlsScalar::zero(), Some(-self.digest));
+
+        Ok(())
+    }
+}
+
+fn random_message<const N: usize>() -> [BlsScalar; N] {
+    [(); N].map(|_| BlsScalar::random(OsRng))
+}
+
+#[test]
+fn permutation_gadget_matches_native() {
+    let mut state = random_message::<WIDTH>();
+    let mut builder = Builder::<TatePairing>::initialized(1 << 10);
+    let mut witnesses = state.map(|s| builder.append_witness(s));
+
+    permutation(&mut state);
+    permutation_gadget(&mut builder, &mut witnesses);
+
+    assert_eq!(witnesses.map(|w| builder[w]), state);
+    builder
+        .check_satisfied()
+        .expect("the permutation is satisfied");
+}
+
+#[test]
+fn hash_gadget_matches_native() {
+    (0..6).for_each(|len| {
+        let message: Vec<_> = (0..len).map(|_| BlsScalar::random(OsRng)).collect();
+        let mut builder = Builder::<TatePairing>::initialized(1 << 12);
+        let witnesses: Vec<_> = message.iter().map(|m| builder.append_witness(*m)).collect();
+
+        let digest = hash_gadget(&mut builder, &witnesses);
+
+        assert_eq!(builder[digest], hash(&message), "{len} elements message");
+        builder.check_satisfied().expect("the hash is satisfied");
+    })
+}
+
+#[test]
+fn hash_gadget_proof() {
+    let mut pp = KeyPair::<TatePairing>::setup(10, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<HashCircuit<2>, TatePairing>(&mut pp, b"poseidon")
+        .expect("failed to compile circuit");
+
+    let circuit = HashCircuit::new(random_message());
+    let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+
+    assert_eq!(public_inputs, vec![-circuit.digest]);
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // a digest of another message
+    let circuit = HashCircuit {
+        digest: hash(&random_message::<2>()),
+        ..HashCircuit::new(random_message())
+    };
+    let result = prover
+        .prove(&mut OsRng, &circuit)
+        .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+    assert!(result.is_err());
+}
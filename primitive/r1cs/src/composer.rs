@@ -6,22 +6,39 @@
 
 use core::ops::Index;
 
+use zero_jubjub::compute_windowed_naf;
 use zkstd::{
-    behave::{Curve, Ring},
-    common::Pairing,
+    behave::{
+        Curve, CurveExtended, CurveGroup, FftField, Group, PrimeField, Ring, SigUtils,
+        TwistedEdwardsAffine,
+    },
+    common::{vec, Pairing, Vec},
 };
 
-use crate::constraint_system::{Constraint, Witness, WitnessPoint};
+use crate::constraint_system::{
+    Constraint, CustomGate, CustomSelector, LookupTable, WiredWitness, Witness, WitnessPoint,
+};
+use crate::error::Error;
 
 pub(crate) mod builder;
 pub(crate) mod circuit;
 pub(crate) mod compiler;
+#[cfg(feature = "std")]
+pub(crate) mod export;
 pub(crate) mod polynomial;
+pub(crate) mod prover;
+pub(crate) mod public_inputs;
+pub(crate) mod report;
+pub(crate) mod verifier;
 
 pub use builder::Builder;
 pub use circuit::Circuit;
 pub use compiler::Compiler;
 pub use polynomial::Polynomial;
+pub use prover::Prover;
+pub use public_inputs::{PublicInput, PublicInputLayout, PublicInputValues, PublicInputs};
+pub use report::{CircuitReport, GateDiagnostics};
+pub use verifier::Verifier;
 
 pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField> {
     /// Zero representation inside the constraint system.
@@ -47,12 +64,38 @@ pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField>
     /// Constraints count
     fn constraints(&self) -> usize;
 
+    /// Whether the composer only records the shape of the circuit, with
+    /// witnesses carrying no value
+    fn is_setup(&self) -> bool;
+
     /// Allocate a witness value into the composer and return its index.
     fn append_witness_internal(&mut self, witness: P::ScalarField) -> Witness;
 
     /// Append a new width-4 poly gate/constraint.
     fn append_custom_gate_internal(&mut self, constraint: Constraint<P>);
 
+    /// Append the rows of `table` to the lookup table of the circuit.
+    ///
+    /// The table is part of the circuit description, so it must be appended
+    /// identically while compiling and proving.
+    fn append_lookup_table(&mut self, table: &LookupTable<P>);
+
+    /// Register a custom gate and return its selector.
+    ///
+    /// Registering a gate under the name of a registered one returns the
+    /// selector of the latter, so a gadget can register its gate each time
+    /// it is used.
+    ///
+    /// ## Panics
+    /// If a gate with the same name but another identity is registered.
+    fn register_gate(&mut self, gate: CustomGate<P>) -> CustomSelector;
+
+    /// Declare the public input of the last public input gates.
+    ///
+    /// The declared inputs make up the [`PublicInputLayout`] of the circuit,
+    /// which the typed [`PublicInputs`] are checked against when verifying.
+    fn declare_public_input(&mut self, input: PublicInput);
+
     /// Allocate a witness value into the composer and return its index.
     fn append_witness<W: Into<P::ScalarField>>(&mut self, witness: W) -> Witness {
         let witness = witness.into();
@@ -65,6 +108,30 @@ pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField>
         witness
     }
 
+    /// Allocate a witness whose value is only known when proving and return
+    /// its index
+    ///
+    /// The value is computed from the ones of other witnesses read with
+    /// [`Self::value`], so it is `None` while setting the circuit up.
+    ///
+    /// ## Panics
+    /// If `witness` is `None` outside of the setup mode.
+    fn append_optional_witness<W: Into<P::ScalarField>>(&mut self, witness: Option<W>) -> Witness {
+        let witness = match (witness, self.is_setup()) {
+            (Some(witness), _) => witness.into(),
+            (None, true) => P::ScalarField::zero(),
+            (None, false) => panic!("the witnesses are assigned when proving"),
+        };
+
+        self.append_witness(witness)
+    }
+
+    /// Value assigned to `witness`, or `None` if it was appended in the setup
+    /// mode
+    ///
+    /// The [`Self::ZERO`] and [`Self::ONE`] constants always have a value.
+    fn value(&self, witness: Witness) -> Option<P::ScalarField>;
+
     /// Appends a point in affine form as [`WitnessPoint`]
     fn append_point<AP: Into<P::JubjubAffine>>(&mut self, affine: AP) -> WitnessPoint {
         let affine = affine.into();
@@ -86,6 +153,28 @@ pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField>
         witness
     }
 
+    /// Allocate a witness value constrained to be equal to a public input
+    /// and return its index
+    fn append_public<W: Into<P::ScalarField>>(&mut self, public: W) -> Witness {
+        let public = public.into();
+        let witness = self.append_witness(public);
+
+        self.assert_equal_constant(witness, P::ScalarField::zero(), Some(-public));
+
+        witness
+    }
+
+    /// Appends a point in affine form as [`WitnessPoint`] constrained to be
+    /// equal to the public input `(x, y)`
+    fn append_public_point<AP: Into<P::JubjubAffine>>(&mut self, affine: AP) -> WitnessPoint {
+        let affine = affine.into();
+        let point = self.append_point(affine);
+
+        self.assert_equal_public_point(point, affine);
+
+        point
+    }
+
     /// Append a new width-4 poly gate/constraint.
     ///
     /// The constraint added will enforce the following:
@@ -96,6 +185,17 @@ pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField>
         self.append_custom_gate_internal(constraint)
     }
 
+    /// Append a gate activating the custom gate of `selector`.
+    ///
+    /// The identity of the custom gate is evaluated with the wires of
+    /// `constraint` and the ones of the next gate, while its selectors other
+    /// than the public input are ignored.
+    fn append_custom_gate(&mut self, selector: CustomSelector, constraint: Constraint<P>) {
+        let constraint = Constraint::custom(&constraint, selector);
+
+        self.append_custom_gate_internal(constraint)
+    }
+
     /// Asserts `a == b` by appending a gate
     fn assert_equal(&mut self, a: Witness, b: Witness) {
         let constraint = Constraint::new()
@@ -127,6 +227,64 @@ pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField>
         self.append_gate(constraint);
     }
 
+    /// Decomposes `witness` into `N` bits, least significant first, by
+    /// appending `2 · N + 1` gates.
+    ///
+    /// Every bit is constrained by [`Composer::component_boolean`] and the
+    /// bits are constrained to pack into `witness`, so the gates can't be
+    /// satisfied if `witness` doesn't fit in `N` bits. From 255 bits on, a
+    /// small value also has the decomposition of itself plus the modulus.
+    ///
+    /// ## Panics
+    /// If `N` is greater than 256.
+    fn component_decomposition<const N: usize>(&mut self, witness: Witness) -> [Witness; N] {
+        assert!(N <= 256, "the decomposition must be within the field");
+
+        let mut decomposition = [Self::ZERO; N];
+
+        let bits = self.value(witness).map(|value| value.to_bits());
+        decomposition.iter_mut().enumerate().for_each(|(i, w)| {
+            let bit = bits
+                .as_ref()
+                .map(|bits| P::ScalarField::from(bits[255 - i] as u64));
+            *w = self.append_optional_witness(bit);
+
+            self.component_boolean(*w);
+        });
+
+        let packed = self.component_pack(&decomposition);
+
+        self.assert_equal(packed, witness);
+
+        decomposition
+    }
+
+    /// Packs `bits`, least significant first, into a witness by appending a
+    /// gate per bit.
+    ///
+    /// `bits` are expected to be constrained by [`Composer::component_boolean`]
+    fn component_pack(&mut self, bits: &[Witness]) -> Witness {
+        bits.iter().enumerate().fold(Self::ZERO, |acc, (i, bit)| {
+            let sum = self
+                .value(*bit)
+                .zip(self.value(acc))
+                .map(|(bit, acc)| P::ScalarField::pow_of_2(i as u64) * bit + acc);
+            let sum = self.append_optional_witness(sum);
+
+            let constraint = Constraint::new()
+                .left(P::ScalarField::pow_of_2(i as u64))
+                .right(1)
+                .output(-P::ScalarField::one())
+                .a(*bit)
+                .b(acc)
+                .o(sum);
+
+            self.append_gate(constraint);
+
+            sum
+        })
+    }
+
     /// Constrain `a` to be equal to `constant + pi`.
     ///
     /// `constant` will be defined as part of the public circuit description.
@@ -145,6 +303,762 @@ pub trait Composer<P: Pairing>: Sized + Index<Witness, Output = P::ScalarField>
         self.append_gate(constraint);
     }
 
+    /// Returns `1` if `a == 0` and `0` otherwise by appending 2 gates.
+    fn component_is_zero(&mut self, a: Witness) -> Witness {
+        let value = self.value(a);
+
+        // `a · inv` is one unless `a` is zero
+        let inv = value.map(|a| a.invert().unwrap_or_else(P::ScalarField::zero));
+        let inv = self.append_optional_witness(inv);
+        let is_zero = value.map(|a| match a.is_zero() {
+            true => P::ScalarField::one(),
+            false => P::ScalarField::zero(),
+        });
+        let is_zero = self.append_optional_witness(is_zero);
+
+        // a · inv + is_zero - 1 = 0
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(1)
+            .constant(-P::ScalarField::one())
+            .a(a)
+            .b(inv)
+            .o(is_zero);
+
+        self.append_gate(constraint);
+
+        // a · is_zero = 0
+        let constraint = Constraint::new().mult(1).a(a).b(is_zero);
+
+        self.append_gate(constraint);
+
+        is_zero
+    }
+
+    /// Returns `1` if `a == b` and `0` otherwise by appending 3 gates.
+    fn component_is_equal(&mut self, a: Witness, b: Witness) -> Witness {
+        let diff = self.value(a).zip(self.value(b)).map(|(a, b)| a - b);
+        let diff = self.append_optional_witness(diff);
+
+        let constraint = Constraint::new()
+            .left(1)
+            .right(-P::ScalarField::one())
+            .output(-P::ScalarField::one())
+            .a(a)
+            .b(b)
+            .o(diff);
+
+        self.append_gate(constraint);
+
+        self.component_is_zero(diff)
+    }
+
+    /// Evaluate `a⁻¹` by appending a gate.
+    ///
+    /// The gate can't be satisfied if `a` is zero.
+    fn component_inverse(&mut self, a: Witness) -> Witness {
+        let inv = self
+            .value(a)
+            .map(|a| a.invert().unwrap_or_else(P::ScalarField::zero));
+        let inv = self.append_optional_witness(inv);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .constant(-P::ScalarField::one())
+            .a(a)
+            .b(inv);
+
+        self.append_gate(constraint);
+
+        inv
+    }
+
+    /// Evaluate `a / b` by appending 2 gates.
+    ///
+    /// The gates can't be satisfied if `b` is zero.
+    fn component_div(&mut self, a: Witness, b: Witness) -> Witness {
+        let inv = self.component_inverse(b);
+
+        let quotient = self.value(a).zip(self.value(inv)).map(|(a, inv)| a * inv);
+        let quotient = self.append_optional_witness(quotient);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(-P::ScalarField::one())
+            .a(a)
+            .b(inv)
+            .o(quotient);
+
+        self.append_gate(constraint);
+
+        quotient
+    }
+
+    /// Returns `1` if `a < b` and `0` otherwise.
+    ///
+    /// `a` and `b` are expected to be constrained to `num_bits` by
+    /// [`Composer::component_range`]. `a - b + 2^num_bits` is split into its
+    /// bit `num_bits` and a remainder constrained to `num_bits`, the bit
+    /// being zero exactly when `a < b`.
+    ///
+    /// ## Panics
+    /// If `num_bits` is odd or greater than 252.
+    fn component_less_than(&mut self, a: Witness, b: Witness, num_bits: usize) -> Witness {
+        assert!(
+            num_bits % 2 == 0 && num_bits <= 252,
+            "the range must be an even number of bits of at most 252"
+        );
+
+        let offset = P::ScalarField::pow_of_2(num_bits as u64);
+        let diff = self
+            .value(a)
+            .zip(self.value(b))
+            .map(|(a, b)| a - b + offset);
+
+        let geq = diff.map(|diff| P::ScalarField::from(diff.to_bits()[255 - num_bits] as u64));
+        let remainder = diff.zip(geq).map(|(diff, geq)| diff - geq * offset);
+
+        let geq = self.append_optional_witness(geq);
+        let remainder = self.append_optional_witness(remainder);
+
+        self.component_boolean(geq);
+        self.component_range(remainder, num_bits);
+
+        // a - b + 2^num_bits - geq · 2^num_bits - remainder = 0
+        let constraint = Constraint::new()
+            .left(1)
+            .right(-P::ScalarField::one())
+            .output(-offset)
+            .fourth(-P::ScalarField::one())
+            .constant(offset)
+            .a(a)
+            .b(b)
+            .o(geq)
+            .d(remainder);
+
+        self.append_gate(constraint);
+
+        let less = self.value(geq).map(|geq| P::ScalarField::one() - geq);
+        let less = self.append_optional_witness(less);
+
+        // geq + less - 1 = 0
+        let constraint = Constraint::new()
+            .left(1)
+            .right(1)
+            .constant(-P::ScalarField::one())
+            .a(geq)
+            .b(less);
+
+        self.append_gate(constraint);
+
+        less
+    }
+
+    /// Adds a range-constraint gate that checks and constrains a [`Witness`]
+    /// to be encoded in at most `num_bits`, which means that it will be within
+    /// the range `[0, 2^num_bits[`.
+    ///
+    /// The witness is decomposed into base 4 digits (quads) and every gate
+    /// holds 4 accumulators, so each gate constrains 8 bits.
+    ///
+    /// This function adds `ceil(num_bits / 8) + 2` gates, or a single gate
+    /// when `num_bits` is zero.
+    ///
+    /// ## Panics
+    /// If `num_bits` is odd or greater than 256.
+    fn component_range(&mut self, witness: Witness, num_bits: usize) {
+        assert!(
+            num_bits % 2 == 0 && num_bits <= 256,
+            "the range must be an even number of bits within the field"
+        );
+
+        // an empty range only contains zero
+        if num_bits == 0 {
+            self.assert_equal_constant(witness, P::ScalarField::zero(), None);
+            return;
+        }
+
+        // convert witness to little endian bit representation
+        let bits = self.value(witness).map(|value| {
+            let mut bits = value.to_bits();
+            bits.reverse();
+            bits
+        });
+
+        // considering this is a width-4 program, one gate will contain 4
+        // accumulators. each accumulator proves that a single quad is a
+        // base-4 digit. accumulators are bijective to quads, and these
+        // are 2-bits each. given that, one gate accumulates 8 bits.
+        let mut num_gates = num_bits >> 3;
+
+        // given each gate accumulates 8 bits, its count must be padded
+        if num_bits % 8 != 0 {
+            num_gates += 1;
+        }
+
+        // a gate holds 4 quads
+        let num_quads = num_gates * 4;
+
+        // the wires are left-padded with the difference between the quads
+        // count and the bits argument
+        let pad = 1 + (((num_quads << 1) - num_bits) >> 1);
+
+        // last gate is reserved to hold the final accumulator, read by the
+        // previous gate as its next row
+        let used_gates = num_gates + 1;
+
+        let mut constraints = vec![Constraint::new(); used_gates];
+
+        // We collect the set of accumulators to return back to the user
+        // and keep a running count of the current accumulator
+        let mut accumulator = bits.as_ref().map(|_| P::ScalarField::zero());
+        let mut last_accumulator = Self::ZERO;
+        let four = P::ScalarField::from(4);
+
+        for i in pad..=num_quads {
+            // convert each pair of bits to quads
+            let bit_index = (num_quads - i) << 1;
+            let quad = bits.as_ref().map(|bits| {
+                let q_0 = bits[bit_index] as u64;
+                let q_1 = bits[bit_index + 1] as u64;
+                q_0 + (2 * q_1)
+            });
+
+            accumulator = accumulator
+                .zip(quad)
+                .map(|(accumulator, quad)| four * accumulator + P::ScalarField::from(quad));
+
+            last_accumulator = self.append_optional_witness(accumulator);
+
+            let idx = i / 4;
+            let wire = match i % 4 {
+                0 => WiredWitness::D,
+                1 => WiredWitness::O,
+                2 => WiredWitness::B,
+                3 => WiredWitness::A,
+                _ => unreachable!(),
+            };
+
+            constraints[idx].set_witness(wire, last_accumulator);
+        }
+
+        // the last gate only holds the final accumulator and doesn't activate
+        // the range selector
+        let last = constraints.pop().expect("at least two gates are used");
+
+        constraints
+            .iter()
+            .for_each(|c| self.append_custom_gate_internal(Constraint::range(c)));
+        self.append_custom_gate_internal(last);
+
+        // the final accumulator is the witness itself
+        self.assert_equal(last_accumulator, witness);
+    }
+
+    /// Adds a logical AND gate that performs the bitwise AND between two
+    /// values for the specified first `num_bits` and returns the result.
+    ///
+    /// Both `a` and `b` are constrained to be encoded in `num_bits`.
+    ///
+    /// ## Panics
+    /// If `num_bits` is odd or greater than 256.
+    fn component_and(&mut self, a: Witness, b: Witness, num_bits: usize) -> Witness {
+        self.append_logic_component(a, b, num_bits, false)
+    }
+
+    /// Adds a logical XOR gate that performs the bitwise XOR between two
+    /// values for the specified first `num_bits` and returns the result.
+    ///
+    /// Both `a` and `b` are constrained to be encoded in `num_bits`.
+    ///
+    /// ## Panics
+    /// If `num_bits` is odd or greater than 256.
+    fn component_xor(&mut self, a: Witness, b: Witness, num_bits: usize) -> Witness {
+        self.append_logic_component(a, b, num_bits, true)
+    }
+
+    /// Constrain `(a, b, c)` to be a row of the lookup table with a single
+    /// gate.
+    ///
+    /// The rows are appended with [`Self::append_lookup_table`], so any
+    /// operation over small operands fitting in a table, such as a bitwise
+    /// operation or an S-box, costs one gate per evaluation.
+    fn component_lookup(&mut self, a: Witness, b: Witness, c: Witness) {
+        let constraint = Constraint::new().a(a).b(b).o(c);
+        let constraint = Constraint::lookup(&constraint);
+
+        self.append_custom_gate_internal(constraint)
+    }
+
+    /// Performs a bitwise AND or XOR between the first `num_bits` of `a` and
+    /// `b`, depending on `is_component_xor`.
+    ///
+    /// The operands are decomposed into base 4 digits (quads) from the most
+    /// significant one, and every gate accumulates one quad of each operand
+    /// and of the result. This function adds `num_bits / 2 + 3` gates.
+    ///
+    /// ## Panics
+    /// If `num_bits` is odd or greater than 256.
+    fn append_logic_component(
+        &mut self,
+        a: Witness,
+        b: Witness,
+        num_bits: usize,
+        is_component_xor: bool,
+    ) -> Witness {
+        assert!(
+            num_bits % 2 == 0 && num_bits <= 256,
+            "the logic operands must be an even number of bits within the field"
+        );
+
+        let num_quads = num_bits >> 1;
+        let four = P::ScalarField::from(4);
+
+        // convert the operands to little endian bit representation
+        let bits = self.value(a).zip(self.value(b)).map(|(a, b)| {
+            let mut a_bits = a.to_bits();
+            let mut b_bits = b.to_bits();
+            a_bits.reverse();
+            b_bits.reverse();
+            (a_bits, b_bits)
+        });
+
+        let zero = bits.as_ref().map(|_| P::ScalarField::zero());
+        let (mut left_acc, mut right_acc, mut out_acc) = (zero, zero, zero);
+
+        // +-----+-----+-----+-----+
+        // |  A  |  B  |  O  |  D  |
+        // +-----+-----+-----+-----+
+        // | 0   | 0   | w1  | 0   |
+        // | a1  | b1  | w2  | d1  |
+        // | a2  | b2  | w3  | d2  |
+        // |  :  |  :  |  :  |  :  |
+        // | an  | bn  | 0   | dn  |
+        // +-----+-----+-----+-----+
+        //
+        // `an`, `bn` and `dn` are the accumulators of the operands and the
+        // result, and every `wi` is the product of the quads read from the
+        // next row, which keeps the degree of the quotient polynomial low
+        let mut constraint = if is_component_xor {
+            Constraint::logic_xor(&Constraint::new())
+        } else {
+            Constraint::logic(&Constraint::new())
+        };
+
+        for i in (0..num_quads).rev() {
+            let bit_index = i << 1;
+            let quads = bits.as_ref().map(|(a_bits, b_bits)| {
+                let left_quad = a_bits[bit_index] as u64 + 2 * a_bits[bit_index + 1] as u64;
+                let right_quad = b_bits[bit_index] as u64 + 2 * b_bits[bit_index + 1] as u64;
+                let out_quad = if is_component_xor {
+                    left_quad ^ right_quad
+                } else {
+                    left_quad & right_quad
+                };
+
+                (left_quad, right_quad, out_quad)
+            });
+
+            let prod_quad = quads.map(|(left, right, _)| P::ScalarField::from(left * right));
+            let prod_quad = self.append_optional_witness(prod_quad);
+            constraint = constraint.o(prod_quad);
+
+            self.append_custom_gate_internal(constraint.clone());
+
+            let accumulate = |acc: Option<P::ScalarField>, quad: Option<u64>| {
+                acc.zip(quad)
+                    .map(|(acc, quad)| four * acc + P::ScalarField::from(quad))
+            };
+            left_acc = accumulate(left_acc, quads.map(|(left, _, _)| left));
+            right_acc = accumulate(right_acc, quads.map(|(_, right, _)| right));
+            out_acc = accumulate(out_acc, quads.map(|(_, _, out)| out));
+
+            let wit_a = self.append_optional_witness(left_acc);
+            let wit_b = self.append_optional_witness(right_acc);
+            let wit_d = self.append_optional_witness(out_acc);
+
+            constraint = constraint.a(wit_a).b(wit_b).d(wit_d);
+        }
+
+        // the last gate only holds the final accumulators and doesn't
+        // activate the logic selector
+        let acc_a = constraint.witness(WiredWitness::A);
+        let acc_b = constraint.witness(WiredWitness::B);
+        let acc_d = constraint.witness(WiredWitness::D);
+
+        let constraint = Constraint::new().a(acc_a).b(acc_b).d(acc_d);
+        self.append_custom_gate_internal(constraint);
+
+        // the final accumulators are the operands themselves
+        self.assert_equal(acc_a, a);
+        self.assert_equal(acc_b, b);
+
+        acc_d
+    }
+
+    /// Asserts `a == b` for two points by appending two gates
+    fn assert_equal_point(&mut self, a: WitnessPoint, b: WitnessPoint) {
+        self.assert_equal(*a.x(), *b.x());
+        self.assert_equal(*a.y(), *b.y());
+    }
+
+    /// Asserts `point == public`.
+    ///
+    /// Will add `public` affine coordinates `(x,y)` as public inputs
+    fn assert_equal_public_point<A: Into<P::JubjubAffine>>(
+        &mut self,
+        point: WitnessPoint,
+        public: A,
+    ) {
+        let public = public.into();
+
+        self.assert_equal_constant(
+            *point.x(),
+            P::ScalarField::zero(),
+            Some(-P::ScalarField::from(public.get_x())),
+        );
+
+        self.assert_equal_constant(
+            *point.y(),
+            P::ScalarField::zero(),
+            Some(-P::ScalarField::from(public.get_y())),
+        );
+    }
+
+    /// Returns `value` if `bit == 1` and `0` otherwise.
+    ///
+    /// `bit` is expected to be constrained by [`Composer::component_boolean`]
+    fn component_select_zero(&mut self, bit: Witness, value: Witness) -> Witness {
+        let product = self
+            .value(bit)
+            .zip(self.value(value))
+            .map(|(bit, value)| bit * value);
+        let product = self.append_optional_witness(product);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(-P::ScalarField::one())
+            .a(bit)
+            .b(value)
+            .o(product);
+
+        self.append_gate(constraint);
+
+        product
+    }
+
+    /// Returns `value` if `bit == 1` and `1` otherwise.
+    ///
+    /// `bit` is expected to be constrained by [`Composer::component_boolean`]
+    fn component_select_one(&mut self, bit: Witness, value: Witness) -> Witness {
+        let f_x = self
+            .value(bit)
+            .zip(self.value(value))
+            .map(|(b, v)| P::ScalarField::one() - b + (b * v));
+        let f_x = self.append_optional_witness(f_x);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .left(-P::ScalarField::one())
+            .output(-P::ScalarField::one())
+            .constant(1)
+            .a(bit)
+            .b(value)
+            .o(f_x);
+
+        self.append_gate(constraint);
+
+        f_x
+    }
+
+    /// Returns `point` if `bit == 1` and the identity otherwise.
+    ///
+    /// `bit` is expected to be constrained by [`Composer::component_boolean`]
+    fn component_select_identity(&mut self, bit: Witness, point: WitnessPoint) -> WitnessPoint {
+        let x = self.component_select_zero(bit, *point.x());
+        let y = self.component_select_one(bit, *point.y());
+
+        WitnessPoint::new(x, y)
+    }
+
+    /// Returns `a` if `bit == 1` and `b` otherwise by appending 2 gates.
+    ///
+    /// `bit` is expected to be constrained by [`Composer::component_boolean`]
+    fn component_select(&mut self, bit: Witness, a: Witness, b: Witness) -> Witness {
+        let bit_a = self.component_select_zero(bit, a);
+
+        let selected = self
+            .value(bit_a)
+            .zip(self.value(b))
+            .zip(self.value(bit))
+            .map(|((bit_a, b), bit)| bit_a + b - bit * b);
+        let selected = self.append_optional_witness(selected);
+
+        // bit · a + b - bit · b - selected = 0
+        let constraint = Constraint::new()
+            .mult(-P::ScalarField::one())
+            .right(1)
+            .output(-P::ScalarField::one())
+            .fourth(1)
+            .a(bit)
+            .b(b)
+            .o(selected)
+            .d(bit_a);
+
+        self.append_gate(constraint);
+
+        selected
+    }
+
+    /// Returns `a` if `bit == 1` and `b` otherwise by appending 4 gates.
+    ///
+    /// `bit` is expected to be constrained by [`Composer::component_boolean`]
+    fn component_select_point(
+        &mut self,
+        bit: Witness,
+        a: WitnessPoint,
+        b: WitnessPoint,
+    ) -> WitnessPoint {
+        let x = self.component_select(bit, *a.x(), *b.x());
+        let y = self.component_select(bit, *a.y(), *b.y());
+
+        WitnessPoint::new(x, y)
+    }
+
+    /// Adds two curve points by consuming 2 gates.
+    fn component_add_point(&mut self, a: WitnessPoint, b: WitnessPoint) -> WitnessPoint {
+        // In order to verify that two points were correctly added
+        // without going over a degree 4 polynomial, we will need
+        // x_1, y_1, x_2, y_2
+        // x_3, y_3, x_1 * y_2
+        let x_1 = *a.x();
+        let y_1 = *a.y();
+        let x_2 = *b.x();
+        let y_2 = *b.y();
+
+        let p1 = self.value(x_1).zip(self.value(y_1));
+        let p2 = self.value(x_2).zip(self.value(y_2));
+
+        let point = p1.zip(p2).map(|((x_1, y_1), (x_2, y_2))| {
+            let p1 = P::JubjubAffine::from_raw_unchecked(x_1.into(), y_1.into());
+            let p2 = P::JubjubAffine::from_raw_unchecked(x_2.into(), y_2.into());
+
+            (p1 + p2).to_affine()
+        });
+
+        let x_3 = point.map(|point| P::ScalarField::from(point.get_x()));
+        let y_3 = point.map(|point| P::ScalarField::from(point.get_y()));
+
+        let x1_y2 = p1.zip(p2).map(|((x_1, _), (_, y_2))| x_1 * y_2);
+
+        let x_1_y_2 = self.append_optional_witness(x1_y2);
+        let x_3 = self.append_optional_witness(x_3);
+        let y_3 = self.append_optional_witness(y_3);
+
+        // Add the rest of the prepared points into the composer
+        let constraint = Constraint::new().a(x_1).b(y_1).o(x_2).d(y_2);
+        let constraint = Constraint::group_add_variable_base(&constraint);
+
+        self.append_custom_gate_internal(constraint);
+
+        // The next row holds the result read by the curve addition gate
+        let constraint = Constraint::new().a(x_3).b(y_3).d(x_1_y_2);
+
+        self.append_custom_gate_internal(constraint);
+
+        WitnessPoint::new(x_3, y_3)
+    }
+
+    /// Negates a curve point by consuming 1 gate.
+    fn component_neg_point(&mut self, a: WitnessPoint) -> WitnessPoint {
+        let x = *a.x();
+        let neg_x = self.value(x).map(|x| -x);
+        let neg_x = self.append_optional_witness(neg_x);
+
+        let constraint = Constraint::new().left(1).right(1).a(x).b(neg_x);
+
+        self.append_gate(constraint);
+
+        WitnessPoint::new(neg_x, *a.y())
+    }
+
+    /// Evaluate `jubjub · point` as a [`WitnessPoint`]
+    ///
+    /// `jubjub` is decomposed into 252 bits, the size of a jubjub scalar,
+    /// and the product is computed with double and add
+    fn component_mul_point(&mut self, jubjub: Witness, point: WitnessPoint) -> WitnessPoint {
+        const BITS: usize = 252;
+
+        let scalar_bits = self.component_decomposition::<BITS>(jubjub);
+
+        let mut result = Self::IDENTITY;
+
+        for bit in scalar_bits.iter().rev() {
+            result = self.component_add_point(result, result);
+
+            let point_to_add = self.component_select_identity(*bit, point);
+            result = self.component_add_point(result, point_to_add);
+        }
+
+        result
+    }
+
+    /// Evaluate `jubjub · generator` as a [`WitnessPoint`]
+    ///
+    /// `generator` will be appended to the circuit description as constant.
+    /// `jubjub` is constrained to be below the jubjub order, so the windowed
+    /// NAF accumulated by the gates can only encode it as a single integer.
+    ///
+    /// Will error if `jubjub` isn't a jubjub scalar
+    fn component_mul_generator<A: Into<P::JubjubExtended>>(
+        &mut self,
+        jubjub: Witness,
+        generator: A,
+    ) -> Result<WitnessPoint, Error> {
+        let generator = generator.into();
+
+        // the accumulator is only checked against `jubjub` modulo the scalar
+        // field. with `jubjub` below the jubjub order and the accumulator
+        // below `2^253`, the only integer both can encode is `jubjub` itself
+        let order: P::ScalarField = (-P::JubjubScalar::one()).into();
+        let order = order + P::ScalarField::one();
+        let order = self.append_constant(order);
+
+        self.component_range(jubjub, 252);
+        let canonical = self.component_less_than(jubjub, order, 252);
+        self.assert_equal_constant(canonical, P::ScalarField::one(), None);
+
+        // a canonical scalar is below `2^252`, so its windowed naf fits in 253
+        // entries. we could move to base4 and halve the number of gates
+        // considering that the product of wnaf adjacent entries is zero.
+        let bits: usize = 253;
+
+        // compute 2^iG
+        let mut wnaf_point_multiples: Vec<P::JubjubAffine> = (0..bits)
+            .scan(generator, |multiple, _| {
+                let point = multiple.to_affine();
+                *multiple = multiple.double();
+
+                Some(point)
+            })
+            .collect();
+
+        wnaf_point_multiples.reverse();
+
+        // the accumulators are only computed when `jubjub` has a value. we
+        // should error instead of producing invalid proofs - otherwise this
+        // can easily become an attack vector to either shutdown prover
+        // services or create malicious statements
+        let accumulators = self
+            .value(jubjub)
+            .map(|jubjub| {
+                let scalar = P::JubjubScalar::from_bytes(jubjub.to_bytes())
+                    .ok_or(Error::BlsScalarMalformed)?;
+
+                let width = 2;
+                let wnaf_entries = compute_windowed_naf(scalar, width);
+
+                debug_assert!(wnaf_entries[bits..].iter().all(|entry| *entry == 0));
+
+                // initialize the accumulators
+                let mut scalar_acc = vec![P::ScalarField::zero()];
+                let mut point_acc = vec![P::JubjubAffine::ADDITIVE_IDENTITY];
+
+                // auxillary point to help with checks on the backend
+                let two = P::ScalarField::from(2u64);
+                let xy_alphas = wnaf_entries[..bits]
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, entry)| {
+                        let (scalar_to_add, point_to_add) = match entry {
+                            0 => (P::ScalarField::zero(), P::JubjubAffine::ADDITIVE_IDENTITY),
+                            -1 => (-P::ScalarField::one(), -wnaf_point_multiples[i]),
+                            1 => (P::ScalarField::one(), wnaf_point_multiples[i]),
+                            _ => return Err(Error::UnsupportedWNAF2k),
+                        };
+
+                        let prev_accumulator = two * scalar_acc[i];
+                        let scalar = prev_accumulator + scalar_to_add;
+                        scalar_acc.push(scalar);
+
+                        let point = point_acc[i] + point_to_add;
+                        point_acc.push(point.to_affine());
+
+                        let x_alpha = P::ScalarField::from(point_to_add.get_x());
+                        let y_alpha = P::ScalarField::from(point_to_add.get_y());
+
+                        Ok(x_alpha * y_alpha)
+                    })
+                    .collect::<Result<Vec<P::ScalarField>, Error>>()?;
+
+                Ok::<_, Error>((scalar_acc, point_acc, xy_alphas))
+            })
+            .transpose()?;
+
+        let scalar_acc = |i: usize| accumulators.as_ref().map(|(acc, _, _)| acc[i]);
+        let acc_x = |i: usize| {
+            accumulators
+                .as_ref()
+                .map(|(_, acc, _)| P::ScalarField::from(acc[i].get_x()))
+        };
+        let acc_y = |i: usize| {
+            accumulators
+                .as_ref()
+                .map(|(_, acc, _)| P::ScalarField::from(acc[i].get_y()))
+        };
+        let xy_alpha = |i: usize| accumulators.as_ref().map(|(_, _, xy)| xy[i]);
+
+        for (i, multiple) in wnaf_point_multiples.iter().enumerate() {
+            let acc_x = self.append_optional_witness(acc_x(i));
+            let acc_y = self.append_optional_witness(acc_y(i));
+            let accumulated_bit = self.append_optional_witness(scalar_acc(i));
+
+            // the point accumulator must start from identity and its scalar
+            // from zero
+            if i == 0 {
+                self.assert_equal_constant(acc_x, P::ScalarField::zero(), None);
+                self.assert_equal_constant(acc_y, P::ScalarField::one(), None);
+                self.assert_equal_constant(accumulated_bit, P::ScalarField::zero(), None);
+            }
+
+            let x_beta = P::ScalarField::from(multiple.get_x());
+            let y_beta = P::ScalarField::from(multiple.get_y());
+
+            let xy_alpha = self.append_optional_witness(xy_alpha(i));
+            let xy_beta = x_beta * y_beta;
+
+            let constraint = Constraint::group_add_fixed_base(
+                &Constraint::new()
+                    .left(x_beta)
+                    .right(y_beta)
+                    .constant(xy_beta)
+                    .a(acc_x)
+                    .b(acc_y)
+                    .o(xy_alpha)
+                    .d(accumulated_bit),
+            );
+
+            self.append_custom_gate_internal(constraint);
+        }
+
+        // last gate isn't activated for ecc
+        let acc_x = self.append_optional_witness(acc_x(bits));
+        let acc_y = self.append_optional_witness(acc_y(bits));
+
+        let last_accumulated_bit = self.append_optional_witness(scalar_acc(bits));
+
+        // the last round of the wnaf reads this gate as its next row
+        let constraint = Constraint::new().a(acc_x).b(acc_y).d(last_accumulated_bit);
+        self.append_gate(constraint);
+
+        // constrain the last element in the accumulator to be equal to the
+        // input jubjub scalar
+        self.assert_equal(last_accumulated_bit, jubjub);
+
+        Ok(WitnessPoint::new(acc_x, acc_y))
+    }
+
     /// Adds blinding factors to the witness polynomials with two dummy
     /// arithmetic constraints
     fn append_dummy_gates(&mut self) {
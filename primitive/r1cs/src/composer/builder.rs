@@ -4,15 +4,21 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use hashbrown::HashMap;
-use zkstd::common::Pairing;
+use hashbrown::{HashMap, HashSet};
+use zkstd::common::{vec, Group, Pairing, SigUtils, Vec};
 
 use crate::{
-    constraint_system::{Constraint, Selector, WiredWitness, Witness},
+    constraint_system::{
+        custom, Constraint, CustomGate, CustomSelector, LookupTable, Selector, WireData,
+        WiredWitness, Witness,
+    },
+    error::Error,
     permutation::Permutation,
+    proof_system::widget::ecc::{curve_addition, fixed_base},
+    proof_system::widget::{logic, range},
 };
 
-use super::{Composer, Polynomial};
+use super::{CircuitReport, Composer, GateDiagnostics, Polynomial, PublicInput, PublicInputLayout};
 
 /// Construct and prove circuits
 #[derive(Debug, Clone)]
@@ -23,18 +29,32 @@ pub struct Builder<P: Pairing> {
     /// Sparse representation of the public inputs
     pub(crate) public_inputs: HashMap<usize, P::ScalarField>,
 
-    /// Witness values
-    pub(crate) witnesses: Vec<P::ScalarField>,
+    /// Witness values, unassigned in setup mode
+    pub(crate) witnesses: Vec<Option<P::ScalarField>>,
 
     /// Permutation argument.
     pub(crate) perm: Permutation<P>,
+
+    /// Rows the lookup gates are constrained to
+    pub(crate) lookup_table: LookupTable<P>,
+
+    /// Custom gates indexed by their selector
+    pub(crate) custom_gates: Vec<CustomGate<P>>,
+
+    /// Declared public inputs
+    pub(crate) public_input_layout: PublicInputLayout,
+
+    /// Whether only the shape of the circuit is recorded
+    pub(crate) setup: bool,
 }
 
 impl<P: Pairing> core::ops::Index<Witness> for Builder<P> {
     type Output = P::ScalarField;
 
     fn index(&self, w: Witness) -> &Self::Output {
-        &self.witnesses[w.index()]
+        self.witnesses[w.index()]
+            .as_ref()
+            .expect("the witness has no value in setup mode")
     }
 }
 
@@ -45,20 +65,35 @@ impl<P: Pairing> Composer<P> for Builder<P> {
             public_inputs: HashMap::new(),
             witnesses: Vec::with_capacity(capacity),
             perm: Permutation::new(),
+            lookup_table: LookupTable::new(),
+            custom_gates: Vec::new(),
+            public_input_layout: PublicInputLayout::default(),
+            setup: false,
         }
     }
     fn constraints(&self) -> usize {
         self.constraints.len()
     }
 
+    fn is_setup(&self) -> bool {
+        self.setup
+    }
+
+    fn value(&self, witness: Witness) -> Option<P::ScalarField> {
+        self.witnesses[witness.index()]
+    }
+
     fn append_witness_internal(&mut self, witness: <P as Pairing>::ScalarField) -> Witness {
         let n = self.witnesses.len();
 
         // Get a new Witness from the permutation
         self.perm.new_witness();
 
-        // Bind the allocated witness
-        self.witnesses.push(witness);
+        // Bind the allocated witness, which has no value in setup mode
+        match self.setup {
+            true => self.witnesses.push(None),
+            false => self.witnesses.push(Some(witness)),
+        }
 
         Witness::new(n)
     }
@@ -83,6 +118,8 @@ impl<P: Pairing> Composer<P> for Builder<P> {
         let q_logic = *constraint.coeff(Selector::Logic);
         let q_fixed_group_add = *constraint.coeff(Selector::GroupAddFixedBase);
         let q_variable_group_add = *constraint.coeff(Selector::GroupAddVariableBase);
+        let q_lookup = *constraint.coeff(Selector::Lookup);
+        let q_custom = constraint.custom_selector();
 
         let poly = Polynomial {
             q_m,
@@ -96,6 +133,8 @@ impl<P: Pairing> Composer<P> for Builder<P> {
             q_logic,
             q_fixed_group_add,
             q_variable_group_add,
+            q_lookup,
+            q_custom,
             w_a,
             w_b,
             w_o,
@@ -105,11 +144,419 @@ impl<P: Pairing> Composer<P> for Builder<P> {
         self.constraints.push(poly);
 
         if constraint.has_public_input() {
-            let pi = *constraint.coeff(Selector::PublicInput);
+            let pi = match self.setup {
+                true => P::ScalarField::zero(),
+                false => *constraint.coeff(Selector::PublicInput),
+            };
 
             self.public_inputs.insert(n, pi);
         }
 
         self.perm.add_witnesses_to_map(w_a, w_b, w_o, w_d, n);
     }
+
+    fn append_lookup_table(&mut self, table: &LookupTable<P>) {
+        self.lookup_table.0.extend_from_slice(table.rows());
+    }
+
+    fn register_gate(&mut self, gate: CustomGate<P>) -> CustomSelector {
+        match self
+            .custom_gates
+            .iter()
+            .position(|registered| registered.name() == gate.name())
+        {
+            Some(index) => {
+                assert!(
+                    self.custom_gates[index] == gate,
+                    "the custom gate {} is already registered with another identity",
+                    gate.name()
+                );
+
+                CustomSelector(index)
+            }
+            None => {
+                self.custom_gates.push(gate);
+
+                CustomSelector(self.custom_gates.len() - 1)
+            }
+        }
+    }
+
+    fn declare_public_input(&mut self, input: PublicInput) {
+        self.public_input_layout.push(input);
+    }
+}
+
+impl<P: Pairing> Builder<P> {
+    /// Initialize a composer which only records the shape of the circuit
+    ///
+    /// The witnesses and the public inputs appended to it carry no value, so
+    /// [`Composer::value`] returns `None` and the gadgets can't invert or
+    /// branch on a dummy value. Only the [`Composer::ZERO`] and
+    /// [`Composer::ONE`] constants keep theirs. The gates are the ones of an
+    /// initialized composer, so the same circuit proved with assigned
+    /// witnesses matches the preprocessed shape.
+    pub fn setup(capacity: usize) -> Self {
+        let mut builder = Self::initialized(capacity);
+
+        builder.setup = true;
+
+        builder
+    }
+
+    /// Size of the power of two domain the circuit is proved over
+    ///
+    /// The lookup table is interpolated over the same domain as the gates.
+    pub(crate) fn domain_size(&self) -> usize {
+        self.constraints
+            .len()
+            .max(self.lookup_table.len())
+            .next_power_of_two()
+    }
+
+    /// Count the gates of each kind, the witnesses and the public inputs of
+    /// the circuit
+    pub fn report(&self) -> CircuitReport {
+        let zero = P::ScalarField::zero();
+        let count = |selector: &dyn Fn(&Polynomial<P>) -> bool| {
+            self.constraints
+                .iter()
+                .filter(|gate| selector(gate))
+                .count()
+        };
+
+        CircuitReport {
+            gates: self.constraints.len(),
+            arithmetic_gates: count(&|gate| gate.q_arith != zero),
+            range_gates: count(&|gate| gate.q_range != zero),
+            logic_gates: count(&|gate| gate.q_logic != zero),
+            group_add_gates: count(&|gate| {
+                gate.q_fixed_group_add != zero || gate.q_variable_group_add != zero
+            }),
+            lookup_gates: count(&|gate| gate.q_lookup != zero),
+            custom_gates: count(&|gate| gate.q_custom.is_some()),
+            witnesses: self.witnesses.len(),
+            public_inputs: self.public_inputs.len(),
+            domain_size: self.domain_size(),
+        }
+    }
+
+    /// Indexes of the gates holding a public input, in ascending order
+    pub(crate) fn public_input_indexes(&self) -> Vec<usize> {
+        let mut public_input_indexes: Vec<_> = self.public_inputs.keys().copied().collect();
+
+        public_input_indexes.as_mut_slice().sort();
+
+        public_input_indexes
+    }
+
+    /// Public input values ordered by the index of the gate they belong to
+    pub(crate) fn public_inputs(&self) -> Vec<P::ScalarField> {
+        self.public_input_indexes()
+            .iter()
+            .filter_map(|idx| self.public_inputs.get(idx).copied())
+            .collect()
+    }
+
+    /// Expand the sparse public inputs into a vector of size `n`
+    pub(crate) fn dense_public_inputs(
+        public_input_indexes: &[usize],
+        public_inputs: &[P::ScalarField],
+        n: usize,
+    ) -> Vec<P::ScalarField> {
+        let mut dense_public_inputs = vec![P::ScalarField::zero(); n];
+
+        public_input_indexes
+            .iter()
+            .zip(public_inputs.iter())
+            .for_each(|(idx, pi)| dense_public_inputs[*idx] = *pi);
+
+        dense_public_inputs
+    }
+
+    /// Evaluate every gate and copy constraint of the circuit with the
+    /// witness values
+    ///
+    /// The copy constraints are evaluated like the permutation argument of
+    /// the prover, each wire holding the value of the wire its sigma
+    /// permutation maps it to. A gate whose wires were rewired without the
+    /// permutation breaks them.
+    ///
+    /// Returns [`Error::UnsatisfiedGate`] with the first gate which doesn't
+    /// hold, so a wrong witness can be located before proving the circuit.
+    ///
+    /// ## Panics
+    /// If the builder is in setup mode, whose witnesses have no value.
+    pub fn check_satisfied(&self) -> Result<(), Error> {
+        let zero = P::ScalarField::zero();
+
+        // the identities of the custom gates divided by their separation
+        // challenge are polynomials of degree at most 4 in its square, so
+        // they vanish for 5 distinct challenges iff every term vanishes
+        let challenges: Vec<_> = (1..=5).map(P::ScalarField::from).collect();
+
+        let table: HashSet<_> = self
+            .lookup_table
+            .rows()
+            .iter()
+            .map(|row| row.map(|s| s.to_bytes()))
+            .collect();
+
+        for (index, gate) in self.constraints.iter().enumerate() {
+            let (a, b, o, d) = self.wire_values(gate);
+            let (a_next, b_next, d_next) = self
+                .constraints
+                .get(index + 1)
+                .map(|next| {
+                    let (a, b, _, d) = self.wire_values(next);
+                    (a, b, d)
+                })
+                .unwrap_or((zero, zero, zero));
+            let pi = self.public_inputs.get(&index).copied().unwrap_or(zero);
+
+            let arithmetic = (gate.q_m * a * b
+                + gate.q_l * a
+                + gate.q_r * b
+                + gate.q_o * o
+                + gate.q_d * d
+                + gate.q_c)
+                * gate.q_arith
+                + pi;
+
+            let wires = (&a, &b, &o, &d);
+            let custom = challenges.iter().any(|sep| {
+                let range = range::compute_identity::<P>(sep, wires, &d_next) * gate.q_range;
+                let logic = logic::compute_identity::<P>(
+                    sep,
+                    wires,
+                    (&a_next, &b_next, &d_next),
+                    &gate.q_c,
+                ) * gate.q_logic;
+                let fixed_base = fixed_base::compute_identity::<P>(
+                    sep,
+                    wires,
+                    (&a_next, &b_next, &d_next),
+                    (&gate.q_l, &gate.q_r, &gate.q_c),
+                ) * gate.q_fixed_group_add;
+                let variable_base =
+                    curve_addition::compute_identity::<P>(sep, wires, (&a_next, &b_next, &d_next))
+                        * gate.q_variable_group_add;
+
+                [range, logic, fixed_base, variable_base]
+                    .iter()
+                    .any(|identity| *identity != zero)
+            });
+
+            // the wires of a lookup gate must be a row of the table
+            let lookup = gate.q_lookup != zero && !table.contains(&[a, b, o].map(|w| w.to_bytes()));
+
+            // the identity of a registered gate must vanish
+            let registered = gate.q_custom.map_or(false, |selector| {
+                let terms = &self.custom_gates[selector.0].terms;
+
+                custom::evaluate::<P>(terms, &[a, b, o, d, a_next, b_next, d_next]) != zero
+            });
+
+            if arithmetic != zero || custom || lookup || registered {
+                return Err(Error::UnsatisfiedGate { index });
+            }
+        }
+
+        // every wire must hold the value of the next wire of its copy cycle
+        let sigmas = self.perm.compute_sigma_permutations(self.constraints.len());
+        let wired = |wire: &WireData| match *wire {
+            WireData::Left(i) => self[self.constraints[i].w_a],
+            WireData::Right(i) => self[self.constraints[i].w_b],
+            WireData::Output(i) => self[self.constraints[i].w_o],
+            WireData::Fourth(i) => self[self.constraints[i].w_d],
+        };
+
+        for (index, gate) in self.constraints.iter().enumerate() {
+            let (a, b, o, d) = self.wire_values(gate);
+
+            if [a, b, o, d]
+                .iter()
+                .zip(sigmas.iter())
+                .any(|(value, sigma)| *value != wired(&sigma[index]))
+            {
+                return Err(Error::UnsatisfiedGate { index });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Selectors, custom selector and wire values of the gate `index`, or
+    /// `None` if the circuit has fewer gates
+    ///
+    /// ## Panics
+    /// If the builder is in setup mode, whose witnesses have no value.
+    pub fn gate_diagnostics(&self, index: usize) -> Option<GateDiagnostics<P>> {
+        let gate = self.constraints.get(index)?;
+        let (a, b, o, d) = self.wire_values(gate);
+
+        Some(GateDiagnostics {
+            index,
+            selectors: [
+                gate.q_m,
+                gate.q_l,
+                gate.q_r,
+                gate.q_o,
+                gate.q_d,
+                gate.q_c,
+                gate.q_arith,
+                gate.q_range,
+                gate.q_logic,
+                gate.q_fixed_group_add,
+                gate.q_variable_group_add,
+                gate.q_lookup,
+            ],
+            custom_selector: gate.q_custom,
+            wires: [a, b, o, d],
+        })
+    }
+
+    fn wire_values(
+        &self,
+        gate: &Polynomial<P>,
+    ) -> (
+        P::ScalarField,
+        P::ScalarField,
+        P::ScalarField,
+        P::ScalarField,
+    ) {
+        (
+            self[gate.w_a],
+            self[gate.w_b],
+            self[gate.w_o],
+            self[gate.w_d],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zero_bls12_381::Fr as BlsScalar;
+    use zero_jubjub::{JubjubAffine, JubjubExtended};
+    use zero_pairing::TatePairing;
+    use zkstd::common::CurveGroup;
+
+    use super::Builder;
+    use crate::composer::{Composer, Polynomial};
+    use crate::constraint_system::Constraint;
+    use crate::error::Error;
+
+    fn builder() -> Builder<TatePairing> {
+        let mut builder = Builder::initialized(1 << 10);
+
+        let a = builder.append_witness(BlsScalar::from(7));
+        let b = builder.append_witness(BlsScalar::from(11));
+        let c = builder.append_witness(BlsScalar::from(77));
+
+        // a * b = c
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(-BlsScalar::one())
+            .a(a)
+            .b(b)
+            .o(c);
+        builder.append_gate(constraint);
+
+        builder.component_range(c, 8);
+        builder.component_xor(a, b, 8);
+
+        let scalar = builder.append_witness(BlsScalar::from(5));
+        let point = JubjubAffine::from(JubjubExtended::ADDITIVE_GENERATOR * BlsScalar::from(5));
+        let product = builder
+            .component_mul_generator(scalar, JubjubExtended::ADDITIVE_GENERATOR)
+            .expect("the scalar is in the jubjub scalar field");
+        builder.assert_equal_public_point(product, point);
+
+        builder
+    }
+
+    fn failing_gate(builder: &Builder<TatePairing>) -> usize {
+        match builder.check_satisfied() {
+            Err(Error::UnsatisfiedGate { index }) => index,
+            result => panic!("unexpected result {result:?}"),
+        }
+    }
+
+    #[test]
+    fn check_satisfied_circuit() {
+        builder()
+            .check_satisfied()
+            .expect("the circuit is satisfied");
+    }
+
+    #[test]
+    fn check_unsatisfied_arithmetic_gate() {
+        let mut builder = builder();
+        let gate = 6;
+
+        // break the product of the gate following the dummy ones
+        let c = builder.constraints[gate].w_o;
+        builder.witnesses[c.index()] = Some(BlsScalar::from(78));
+
+        assert_eq!(failing_gate(&builder), gate);
+
+        let diagnostics = builder.gate_diagnostics(gate).expect("the gate exists");
+        assert_eq!(diagnostics.selectors[0], BlsScalar::one());
+        assert_eq!(diagnostics.custom_selector, None);
+        assert_eq!(diagnostics.wires[2], BlsScalar::from(78));
+    }
+
+    // rewire the fourth wire of the gate following the first one selected by
+    // `selector`, which every custom identity reads, shifting it out of the
+    // base 4 digits
+    fn tamper_custom_gate(
+        selector: impl Fn(&Polynomial<TatePairing>) -> BlsScalar,
+    ) -> (Builder<TatePairing>, usize) {
+        let mut builder = builder();
+        let gate = builder
+            .constraints
+            .iter()
+            .position(|gate| selector(gate) != BlsScalar::zero())
+            .expect("the gate is in the circuit");
+
+        let d = builder[builder.constraints[gate + 1].w_d] + BlsScalar::from(4);
+        let d = builder.append_witness(d);
+        builder.constraints[gate + 1].w_d = d;
+
+        (builder, gate)
+    }
+
+    #[test]
+    fn check_unsatisfied_custom_gates() {
+        let selectors: [fn(&Polynomial<TatePairing>) -> BlsScalar; 3] = [
+            |gate| gate.q_range,
+            |gate| gate.q_logic,
+            |gate| gate.q_fixed_group_add,
+        ];
+
+        selectors.into_iter().for_each(|selector| {
+            let (builder, gate) = tamper_custom_gate(selector);
+
+            assert_eq!(failing_gate(&builder), gate);
+        });
+    }
+
+    #[test]
+    fn check_broken_copy_constraint() {
+        let mut builder = builder();
+
+        // x = 3 followed by a gate reading x without any selector
+        let x = builder.append_witness(BlsScalar::from(3));
+        let gate = builder.constraints.len();
+        builder.append_gate(Constraint::new().left(1).constant(-BlsScalar::from(3)).a(x));
+        builder.append_gate(Constraint::new().a(x));
+        builder.check_satisfied().expect("the circuit is satisfied");
+
+        // rewire the second gate to another value without the permutation,
+        // which still satisfies the gate itself
+        let y = builder.append_witness(BlsScalar::from(4));
+        builder.constraints[gate + 1].w_a = y;
+
+        assert_eq!(failing_gate(&builder), gate);
+    }
 }
@@ -15,6 +15,10 @@ use super::Composer;
 /// Circuit implementation that can be proved by a Composer
 ///
 /// The default implementation will be used to generate the proving arguments.
+/// It is run by a setup composer, which records the gates but not the values
+/// of the witnesses, so the values computed in the circuit must be read with
+/// [`Composer::value`] when an operation like an inversion isn't defined for
+/// the dummy ones.
 pub trait Circuit<P: Pairing>: Default + Debug {
     /// Circuit definition
     fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
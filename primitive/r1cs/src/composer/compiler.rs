@@ -4,22 +4,25 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use merlin::Transcript as MerlinTranscript;
 use zero_kzg::KeyPair;
 use zkstd::common::Pairing;
 
-use super::{Builder, Circuit, Composer};
+use super::{Builder, Circuit, CircuitReport, Composer, Prover, Verifier};
 use crate::error::Error;
+use crate::transcript::Transcript;
 
 /// Generate the arguments to prove and verify a circuit
 pub struct Compiler;
 
-type CompilerResult<P> = Result<Builder<P>, Error>;
+type CompilerResult<C, P, T = MerlinTranscript> =
+    Result<(Prover<C, P, T>, Verifier<C, P, T>), Error>;
 
 impl Compiler {
     /// Create a new arguments set from a given circuit instance
     ///
     /// Use the default implementation of the circuit
-    pub fn compile<C, P>(keypair: &mut KeyPair<P>, label: &[u8]) -> CompilerResult<P>
+    pub fn compile<C, P>(keypair: &mut KeyPair<P>, label: &[u8]) -> CompilerResult<C, P>
     where
         C: Circuit<P>,
         P: Pairing,
@@ -30,20 +33,122 @@ impl Compiler {
     /// Create a new arguments set from a given circuit instance
     ///
     /// Use the provided circuit instead of the default implementation
+    ///
+    /// The prover holds a copy of `keypair` trimmed to the domain size of
+    /// the circuit, so the same keypair can compile circuits of any size up
+    /// to its degree.
     pub fn compile_with_circuit<C, P>(
         keypair: &mut KeyPair<P>,
-        _label: &[u8],
+        label: &[u8],
+        circuit: &C,
+    ) -> CompilerResult<C, P>
+    where
+        C: Circuit<P>,
+        P: Pairing,
+    {
+        Self::compile_with_transcript::<C, P, MerlinTranscript>(keypair, label, circuit)
+    }
+
+    /// Create a new arguments set from a given circuit instance, deriving
+    /// the challenges of its proofs with the transcript `T`
+    ///
+    /// The transcript is bound to `label` and to the hash of the verifier
+    /// data, so the proofs of a circuit don't verify for another one.
+    pub fn compile_with_transcript<C, P, T>(
+        keypair: &mut KeyPair<P>,
+        label: &[u8],
         circuit: &C,
-    ) -> CompilerResult<P>
+    ) -> CompilerResult<C, P, T>
     where
         C: Circuit<P>,
         P: Pairing,
+        T: Transcript<P>,
     {
-        let max_size = (keypair.commit_key().len() - 1) >> 1;
-        let mut builder = Builder::initialized(max_size);
+        let builder = Self::setup(circuit)?;
+        let size = builder.domain_size();
+
+        if size > keypair.max_degree() {
+            return Err(Error::PolynomialDegreeTooLarge);
+        }
+
+        let keypair = match size + KeyPair::<P>::ADDED_BLINDING_DEGREE <= keypair.max_degree() {
+            true => keypair.trim(size),
+            false => keypair.clone(),
+        };
+
+        Self::preprocess(label, &keypair, &builder)
+    }
+
+    /// Report the size of a circuit without a keypair, to choose the degree
+    /// to set it up with
+    ///
+    /// Use the default implementation of the circuit
+    pub fn report<C, P>() -> Result<CircuitReport, Error>
+    where
+        C: Circuit<P>,
+        P: Pairing,
+    {
+        Self::report_with_circuit::<C, P>(&Default::default())
+    }
+
+    /// Report the size of a circuit without a keypair, to choose the degree
+    /// to set it up with
+    ///
+    /// Use the provided circuit instead of the default implementation
+    pub fn report_with_circuit<C, P>(circuit: &C) -> Result<CircuitReport, Error>
+    where
+        C: Circuit<P>,
+        P: Pairing,
+    {
+        Self::setup(circuit).map(|builder| builder.report())
+    }
+
+    /// Append the gates of the `circuit` to an initialized builder, to
+    /// inspect or export the constraint system it compiles to
+    ///
+    /// The witnesses are assigned the values of the `circuit`, like when
+    /// proving it.
+    pub fn build<C, P>(circuit: &C) -> Result<Builder<P>, Error>
+    where
+        C: Circuit<P>,
+        P: Pairing,
+    {
+        let mut builder = Builder::initialized(0);
+
+        circuit.circuit(&mut builder)?;
+
+        Ok(builder)
+    }
+
+    /// Record the shape of the `circuit` in a setup builder, ignoring the
+    /// values of its witnesses
+    fn setup<C, P>(circuit: &C) -> Result<Builder<P>, Error>
+    where
+        C: Circuit<P>,
+        P: Pairing,
+    {
+        let mut builder = Builder::setup(0);
 
         circuit.circuit(&mut builder)?;
 
         Ok(builder)
     }
+
+    /// Preprocess the gates of the `builder` and commit to them so the
+    /// resulting verifier doesn't need the circuit
+    fn preprocess<C, P, T>(
+        label: &[u8],
+        keypair: &KeyPair<P>,
+        builder: &Builder<P>,
+    ) -> CompilerResult<C, P, T>
+    where
+        C: Circuit<P>,
+        P: Pairing,
+        T: Transcript<P>,
+    {
+        let prover = Prover::new(label, keypair, builder)?;
+        let verifier = Verifier::new(label, prover.verifier_data().clone(), keypair.opening_key());
+
+        Ok((prover, verifier))
+    }
 }
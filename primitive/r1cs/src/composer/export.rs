@@ -0,0 +1,381 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON export of the constraint system of a [`Builder`]
+//!
+//! The file lists every gate with its selectors and wire indexes, the number
+//! of witnesses, the copy constraint cycle of each witness, the gates
+//! holding a public input, the declared public inputs, the lookup table and
+//! the registered custom gates. Scalars are `0x` prefixed big endian hex
+//! strings, so they read like the constants of the circuit.
+//!
+//! Only the shape of the circuit is exported. The witness and public input
+//! values of the instance it was built with are left out, so the file can
+//! be shared without the secrets of a prover and doesn't change from an
+//! instance to another.
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use zkstd::common::{Box, Group, Pairing, Ring, SigUtils, Vec};
+
+use crate::constraint_system::custom::Term;
+use crate::constraint_system::{CustomGate, CustomSelector, LookupTable, WireData, Witness};
+use crate::error::Error;
+use crate::permutation::Permutation;
+
+use super::{Builder, Polynomial, PublicInput, PublicInputLayout};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct CircuitJson {
+    gates: Vec<GateJson>,
+    witnesses: usize,
+    copy_constraints: Vec<CycleJson>,
+    public_inputs: Vec<usize>,
+    public_input_layout: Vec<PublicInputDeclJson>,
+    lookup_table: Vec<[String; 3]>,
+    custom_gates: Vec<CustomGateJson>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct GateJson {
+    q_m: String,
+    q_l: String,
+    q_r: String,
+    q_o: String,
+    q_c: String,
+    q_d: String,
+    q_arith: String,
+    q_range: String,
+    q_logic: String,
+    q_fixed_group_add: String,
+    q_variable_group_add: String,
+    q_lookup: String,
+    q_custom: Option<usize>,
+    w_a: usize,
+    w_b: usize,
+    w_o: usize,
+    w_d: usize,
+}
+
+/// Wires sharing the value of a witness
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct CycleJson {
+    witness: usize,
+    wires: Vec<WireJson>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum WireJson {
+    Left(usize),
+    Right(usize),
+    Output(usize),
+    Fourth(usize),
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum PublicInputDeclJson {
+    Scalar(String),
+    Point(String),
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct CustomGateJson {
+    name: String,
+    terms: Vec<TermJson>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct TermJson {
+    coeff: String,
+    powers: [u8; 7],
+}
+
+impl From<WireData> for WireJson {
+    fn from(wire: WireData) -> Self {
+        match wire {
+            WireData::Left(gate) => Self::Left(gate),
+            WireData::Right(gate) => Self::Right(gate),
+            WireData::Output(gate) => Self::Output(gate),
+            WireData::Fourth(gate) => Self::Fourth(gate),
+        }
+    }
+}
+
+fn scalar_to_hex<P: Pairing>(scalar: &P::ScalarField) -> String {
+    let hex: String = scalar
+        .to_bytes()
+        .iter()
+        .rev()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    format!("0x{hex}")
+}
+
+fn scalar_from_hex<P: Pairing>(hex: &str) -> Result<P::ScalarField, Error> {
+    let hex = hex.strip_prefix("0x").ok_or(Error::InvalidCircuitJson)?;
+
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(Error::InvalidCircuitJson);
+    }
+
+    let mut bytes = [0; 32];
+    for (byte, digits) in bytes.iter_mut().rev().zip(hex.as_bytes().chunks(2)) {
+        let digits = core::str::from_utf8(digits).map_err(|_| Error::InvalidCircuitJson)?;
+        *byte = u8::from_str_radix(digits, 16).map_err(|_| Error::InvalidCircuitJson)?;
+    }
+
+    P::ScalarField::from_bytes(bytes).ok_or(Error::InvalidCircuitJson)
+}
+
+/// Names of the custom gates are `&'static str`, so the imported ones are
+/// leaked for the rest of the program
+fn leak(name: String) -> &'static str {
+    Box::leak(name.into_boxed_str())
+}
+
+/// Copy constraint cycles of every witness, ordered by witness index
+fn cycles<P: Pairing>(perm: &Permutation<P>) -> Vec<CycleJson> {
+    let mut cycles: Vec<_> = perm
+        .witness_map
+        .iter()
+        .map(|(witness, wires)| CycleJson {
+            witness: witness.index(),
+            wires: wires.iter().copied().map(WireJson::from).collect(),
+        })
+        .collect();
+
+    cycles.sort_by_key(|cycle| cycle.witness);
+
+    cycles
+}
+
+impl<P: Pairing> Builder<P> {
+    /// Export the constraint system to pretty printed JSON, to be reviewed
+    /// or diffed without the circuit
+    pub fn to_json(&self) -> String {
+        let scalar = scalar_to_hex::<P>;
+
+        let gates = self
+            .constraints
+            .iter()
+            .map(|gate| GateJson {
+                q_m: scalar(&gate.q_m),
+                q_l: scalar(&gate.q_l),
+                q_r: scalar(&gate.q_r),
+                q_o: scalar(&gate.q_o),
+                q_c: scalar(&gate.q_c),
+                q_d: scalar(&gate.q_d),
+                q_arith: scalar(&gate.q_arith),
+                q_range: scalar(&gate.q_range),
+                q_logic: scalar(&gate.q_logic),
+                q_fixed_group_add: scalar(&gate.q_fixed_group_add),
+                q_variable_group_add: scalar(&gate.q_variable_group_add),
+                q_lookup: scalar(&gate.q_lookup),
+                q_custom: gate.q_custom.map(|selector| selector.0),
+                w_a: gate.w_a.index(),
+                w_b: gate.w_b.index(),
+                w_o: gate.w_o.index(),
+                w_d: gate.w_d.index(),
+            })
+            .collect();
+
+        let public_input_layout = self
+            .public_input_layout
+            .inputs()
+            .iter()
+            .map(|input| match input {
+                PublicInput::Scalar(name) => PublicInputDeclJson::Scalar(name.clone().into()),
+                PublicInput::Point(name) => PublicInputDeclJson::Point(name.clone().into()),
+            })
+            .collect();
+
+        let custom_gates = self
+            .custom_gates
+            .iter()
+            .map(|gate| CustomGateJson {
+                name: gate.name().into(),
+                terms: gate
+                    .terms
+                    .iter()
+                    .map(|term| TermJson {
+                        coeff: scalar(&term.coeff),
+                        powers: term.powers,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let circuit = CircuitJson {
+            gates,
+            witnesses: self.witnesses.len(),
+            copy_constraints: cycles(&self.perm),
+            public_inputs: self.public_input_indexes(),
+            public_input_layout,
+            lookup_table: self
+                .lookup_table
+                .rows()
+                .iter()
+                .map(|row| row.map(|s| scalar(&s)))
+                .collect(),
+            custom_gates,
+        };
+
+        serde_json::to_string_pretty(&circuit).expect("the circuit is serializable")
+    }
+
+    /// Rebuild the builder exported by [`Builder::to_json`]
+    ///
+    /// The file holds no values, so the builder is in setup mode like the
+    /// one of [`Builder::setup`]. It can be preprocessed but not proved.
+    ///
+    /// The names of the custom gates are `&'static str`, so the imported ones
+    /// are leaked and never freed. Import a circuit once rather than on every
+    /// use.
+    ///
+    /// Returns [`Error::InvalidCircuitJson`] if the file is malformed, refers
+    /// to missing witnesses or custom gates, or if its copy constraints
+    /// differ from the ones of its gates.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let circuit: CircuitJson =
+            serde_json::from_str(json).map_err(|_| Error::InvalidCircuitJson)?;
+        let scalar = scalar_from_hex::<P>;
+
+        // only the constants appended by `Builder::initialized` have a value
+        let witnesses: Vec<_> = (0..circuit.witnesses)
+            .map(|index| match index {
+                0 => Some(P::ScalarField::zero()),
+                1 => Some(P::ScalarField::one()),
+                _ => None,
+            })
+            .collect();
+
+        let custom_gates = circuit
+            .custom_gates
+            .iter()
+            .map(|gate| {
+                let terms = gate
+                    .terms
+                    .iter()
+                    .map(|term| {
+                        let degree: usize = term.powers.iter().map(|e| *e as usize).sum();
+                        match degree <= CustomGate::<P>::MAX_DEGREE {
+                            true => Ok(Term {
+                                coeff: scalar(&term.coeff)?,
+                                powers: term.powers,
+                            }),
+                            false => Err(Error::InvalidCircuitJson),
+                        }
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                let mut custom_gate = CustomGate::new(leak(gate.name.clone()));
+                custom_gate.terms = terms;
+
+                Ok(custom_gate)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let witness = |index: usize| match index < witnesses.len() {
+            true => Ok(Witness::new(index)),
+            false => Err(Error::InvalidCircuitJson),
+        };
+
+        let constraints = circuit
+            .gates
+            .iter()
+            .map(|gate| {
+                let q_custom = match gate.q_custom {
+                    Some(index) if index >= custom_gates.len() => {
+                        return Err(Error::InvalidCircuitJson)
+                    }
+                    q_custom => q_custom.map(CustomSelector),
+                };
+
+                Ok(Polynomial {
+                    q_m: scalar(&gate.q_m)?,
+                    q_l: scalar(&gate.q_l)?,
+                    q_r: scalar(&gate.q_r)?,
+                    q_o: scalar(&gate.q_o)?,
+                    q_c: scalar(&gate.q_c)?,
+                    q_d: scalar(&gate.q_d)?,
+                    q_arith: scalar(&gate.q_arith)?,
+                    q_range: scalar(&gate.q_range)?,
+                    q_logic: scalar(&gate.q_logic)?,
+                    q_fixed_group_add: scalar(&gate.q_fixed_group_add)?,
+                    q_variable_group_add: scalar(&gate.q_variable_group_add)?,
+                    q_lookup: scalar(&gate.q_lookup)?,
+                    q_custom,
+                    w_a: witness(gate.w_a)?,
+                    w_b: witness(gate.w_b)?,
+                    w_o: witness(gate.w_o)?,
+                    w_d: witness(gate.w_d)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut perm = Permutation::new();
+        witnesses.iter().for_each(|_| {
+            perm.new_witness();
+        });
+        constraints.iter().enumerate().for_each(|(index, gate)| {
+            perm.add_witnesses_to_map(gate.w_a, gate.w_b, gate.w_o, gate.w_d, index)
+        });
+
+        if cycles(&perm) != circuit.copy_constraints {
+            return Err(Error::InvalidCircuitJson);
+        }
+
+        let mut public_inputs = HashMap::new();
+        for gate in circuit.public_inputs.iter() {
+            if *gate >= constraints.len() {
+                return Err(Error::InvalidCircuitJson);
+            }
+            public_inputs.insert(*gate, P::ScalarField::zero());
+        }
+
+        let mut public_input_layout = PublicInputLayout::default();
+        circuit
+            .public_input_layout
+            .into_iter()
+            .for_each(|input| match input {
+                PublicInputDeclJson::Scalar(name) => {
+                    public_input_layout.push(PublicInput::Scalar(name.into()))
+                }
+                PublicInputDeclJson::Point(name) => {
+                    public_input_layout.push(PublicInput::Point(name.into()))
+                }
+            });
+
+        let lookup_table = circuit
+            .lookup_table
+            .iter()
+            .map(|[a, b, c]| Ok([scalar(a)?, scalar(b)?, scalar(c)?]))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Self {
+            constraints,
+            public_inputs,
+            witnesses,
+            perm,
+            lookup_table: LookupTable::from(lookup_table),
+            custom_gates,
+            public_input_layout,
+            setup: true,
+        })
+    }
+}
@@ -6,7 +6,7 @@
 
 use zkstd::common::Pairing;
 
-use crate::constraint_system::Witness;
+use crate::constraint_system::{CustomSelector, Witness};
 
 /// Represents a polynomial in coefficient form with its associated wire data
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,6 +34,10 @@ pub struct Polynomial<P: Pairing> {
     pub(crate) q_fixed_group_add: P::ScalarField,
     /// Variable base group addition selector
     pub(crate) q_variable_group_add: P::ScalarField,
+    /// Lookup selector
+    pub(crate) q_lookup: P::ScalarField,
+    /// Selector of the activated custom gate
+    pub(crate) q_custom: Option<CustomSelector>,
 
     /// Left wire witness.
     pub(crate) w_a: Witness,
@@ -0,0 +1,569 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use core::marker::PhantomData;
+
+use merlin::Transcript as MerlinTranscript;
+use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use zero_kzg::{Fft, KeyPair, Polynomial};
+use zkstd::common::{vec, Group, Pairing, Vec};
+
+use super::{Builder, Circuit, Composer, PublicInputLayout};
+use crate::error::Error;
+use crate::permutation::Permutation;
+use crate::proof_system::preprocess::{ProverKey, VerifierData};
+use crate::proof_system::proof::Proof;
+use crate::proof_system::widget::lookup;
+use crate::proof_system::{linearization_poly, quotient_poly};
+use crate::transcript::{circuit_transcript, Transcript};
+
+/// Turbo Prover with processed keys
+#[derive(Clone)]
+pub struct Prover<C, P, T = MerlinTranscript>
+where
+    C: Circuit<P>,
+    P: Pairing,
+    T: Transcript<P>,
+{
+    keypair: KeyPair<P>,
+    prover_key: ProverKey<P>,
+    verifier_data: VerifierData<P>,
+    transcript: T,
+    size: usize,
+    constraints: usize,
+    circuit: PhantomData<C>,
+}
+
+impl<C, P, T> Prover<C, P, T>
+where
+    C: Circuit<P>,
+    P: Pairing,
+    T: Transcript<P>,
+{
+    /// Preprocess the gates of a compiled `builder` so it can be proved with
+    /// the commit key of `keypair`
+    ///
+    /// The transcript of the proofs is bound to `label` and to the verifier
+    /// data of the circuit.
+    pub fn new(label: &[u8], keypair: &KeyPair<P>, builder: &Builder<P>) -> Result<Self, Error> {
+        let constraints = builder.constraints();
+        let size = builder.domain_size();
+
+        if size > keypair.max_degree() {
+            return Err(Error::PolynomialDegreeTooLarge);
+        }
+
+        let prover_key = ProverKey::new(builder, size);
+        let verifier_data = prover_key.verifier_data(
+            keypair,
+            builder.public_input_indexes(),
+            builder.public_input_layout.clone(),
+        )?;
+        let transcript = circuit_transcript(label, &verifier_data);
+
+        Ok(Self {
+            keypair: keypair.clone(),
+            prover_key,
+            verifier_data,
+            transcript,
+            size,
+            constraints,
+            circuit: PhantomData,
+        })
+    }
+
+    /// Preprocessed circuit data to verify the proofs with
+    pub fn verifier_data(&self) -> &VerifierData<P> {
+        &self.verifier_data
+    }
+
+    /// Public inputs declared by the circuit
+    pub fn public_input_layout(&self) -> &PublicInputLayout {
+        self.verifier_data.public_input_layout()
+    }
+
+    /// interpolates a witness vector
+    fn interpolate(
+        witnesses: &[P::ScalarField],
+        fft: &Fft<P::ScalarField>,
+    ) -> Polynomial<P::ScalarField> {
+        let mut w_vec_inverse = Polynomial::new(witnesses.to_vec());
+        fft.idft(&mut w_vec_inverse);
+
+        w_vec_inverse
+    }
+
+    /// adds blinding scalars to an interpolated witness vector
+    ///
+    /// appends:
+    ///
+    /// if hiding degree = 1: (b2*X^(n+1) + b1*X^n - b2*X - b1) + witnesses
+    /// if hiding degree = 2: (b3*X^(n+2) + b2*X^(n+1) + b1*X^n - b3*X^2 - b2*X
+    fn blind_poly<R>(
+        rng: &mut R,
+        mut w_vec_inverse: Polynomial<P::ScalarField>,
+        hiding_degree: usize,
+    ) -> Polynomial<P::ScalarField>
+    where
+        R: RngCore,
+    {
+        for i in 0..hiding_degree + 1 {
+            let blinding_scalar = P::ScalarField::random(&mut *rng);
+
+            w_vec_inverse[i] -= blinding_scalar;
+            w_vec_inverse.0.push(blinding_scalar);
+        }
+
+        Polynomial::from_coefficients_vec(w_vec_inverse.0)
+    }
+
+    /// Prove the circuit
+    pub fn prove<R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+    ) -> Result<(Proof<P>, Vec<P::ScalarField>), Error>
+    where
+        R: RngCore,
+    {
+        let mut prover = Builder::initialized(self.constraints);
+
+        circuit.circuit(&mut prover)?;
+
+        if prover.constraints() > self.size {
+            return Err(Error::MismatchedPolyLen);
+        }
+
+        let n = self.size;
+        let k = n.trailing_zeros() as usize;
+        let fft = Fft::<P::ScalarField>::new(k);
+
+        let mut transcript = self.transcript.clone();
+
+        let public_input_indexes = prover.public_input_indexes();
+        let public_inputs = prover.public_inputs();
+        public_inputs
+            .iter()
+            .for_each(|pi| transcript.append_scalar(b"pi", pi));
+
+        // round 1
+        // convert wires to padded scalars
+        let mut a_w_scalar = vec![P::ScalarField::zero(); n];
+        let mut b_w_scalar = vec![P::ScalarField::zero(); n];
+        let mut o_w_scalar = vec![P::ScalarField::zero(); n];
+        let mut d_w_scalar = vec![P::ScalarField::zero(); n];
+
+        prover.constraints.iter().enumerate().for_each(|(i, c)| {
+            a_w_scalar[i] = prover[c.w_a];
+            b_w_scalar[i] = prover[c.w_b];
+            o_w_scalar[i] = prover[c.w_o];
+            d_w_scalar[i] = prover[c.w_d];
+        });
+
+        // the wires are interpolated in parallel with the `parallel` feature
+        // and blinded in order
+        let wires = [&a_w_scalar, &b_w_scalar, &o_w_scalar, &d_w_scalar];
+        let [a_w_poly, b_w_poly, o_w_poly, d_w_poly]: [Polynomial<P::ScalarField>; 4] =
+            cfg_iter!(wires)
+                .map(|wire| Self::interpolate(wire, &fft))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|poly| Self::blind_poly(rng, poly, 1))
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("a polynomial per wire");
+
+        // commit to wire polynomials
+        // ([a(x)]_1, [b(x)]_1, [c(x)]_1, [d(x)]_1)
+        let a_w_poly_commit = self.keypair.commit(&a_w_poly)?;
+        let b_w_poly_commit = self.keypair.commit(&b_w_poly)?;
+        let o_w_poly_commit = self.keypair.commit(&o_w_poly)?;
+        let d_w_poly_commit = self.keypair.commit(&d_w_poly)?;
+
+        // Add wire polynomial commitments to transcript
+        transcript.append_commitment(b"a_w", &a_w_poly_commit);
+        transcript.append_commitment(b"b_w", &b_w_poly_commit);
+        transcript.append_commitment(b"o_w", &o_w_poly_commit);
+        transcript.append_commitment(b"d_w", &d_w_poly_commit);
+
+        // lookup round
+        // compress the table and the queries of the lookup gates
+        let zeta = transcript.challenge_scalar(b"zeta");
+        transcript.append_scalar(b"zeta", &zeta);
+
+        let table = &self.prover_key.lookup;
+        let t_scalar = table.compress_rows(&zeta);
+        let (table_poly, table_eval_8n) = table.compress_table(&zeta);
+
+        // the gates other than the lookup ones query the padding row
+        let mut f_scalar = vec![t_scalar[n - 1]; n];
+        prover
+            .constraints
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.q_lookup != P::ScalarField::zero())
+            .for_each(|(i, _)| {
+                f_scalar[i] =
+                    lookup::compress::<P>(&zeta, (&a_w_scalar[i], &b_w_scalar[i], &o_w_scalar[i]))
+            });
+
+        let f_poly = Self::blind_poly(rng, Self::interpolate(&f_scalar, &fft), 1);
+        let f_poly_commit = self.keypair.commit(&f_poly)?;
+        transcript.append_commitment(b"f", &f_poly_commit);
+
+        // sort the queries by the table
+        let [h_1_scalar, h_2_scalar] = lookup::compute_sorted_halves::<P>(&f_scalar, &t_scalar)?;
+
+        let h_1_poly = Self::blind_poly(rng, Self::interpolate(&h_1_scalar, &fft), 2);
+        let h_2_poly = Self::blind_poly(rng, Self::interpolate(&h_2_scalar, &fft), 1);
+
+        let h_1_poly_commit = self.keypair.commit(&h_1_poly)?;
+        let h_2_poly_commit = self.keypair.commit(&h_2_poly)?;
+
+        transcript.append_commitment(b"h_1", &h_1_poly_commit);
+        transcript.append_commitment(b"h_2", &h_2_poly_commit);
+
+        // round 2
+        // permutation challenges
+        let beta = transcript.challenge_scalar(b"beta");
+        transcript.append_scalar(b"beta", &beta);
+
+        let gamma = transcript.challenge_scalar(b"gamma");
+        transcript.append_scalar(b"gamma", &gamma);
+
+        // lookup challenges
+        let delta = transcript.challenge_scalar(b"delta");
+        transcript.append_scalar(b"delta", &delta);
+
+        let epsilon = transcript.challenge_scalar(b"epsilon");
+        transcript.append_scalar(b"epsilon", &epsilon);
+
+        let sigma = &self.prover_key.permutation;
+        let z_scalar = Permutation::<P>::compute_permutation_vec(
+            &fft,
+            [&a_w_scalar, &b_w_scalar, &o_w_scalar, &d_w_scalar],
+            &beta,
+            &gamma,
+            [
+                &sigma.s_sigma_1.0,
+                &sigma.s_sigma_2.0,
+                &sigma.s_sigma_3.0,
+                &sigma.s_sigma_4.0,
+            ],
+        );
+        let z_poly = Self::blind_poly(rng, Self::interpolate(&z_scalar, &fft), 2);
+
+        let p_scalar = lookup::compute_grand_product_vec::<P>(
+            (&delta, &epsilon),
+            &f_scalar,
+            &t_scalar,
+            (&h_1_scalar, &h_2_scalar),
+        );
+        let p_poly = Self::blind_poly(rng, Self::interpolate(&p_scalar, &fft), 2);
+
+        // commit to permutation polynomials
+        let z_poly_commit = self.keypair.commit(&z_poly)?;
+        let p_poly_commit = self.keypair.commit(&p_poly)?;
+
+        // Add permutation polynomial commitments to transcript
+        transcript.append_commitment(b"z", &z_poly_commit);
+        transcript.append_commitment(b"p", &p_poly_commit);
+
+        // round 3
+        // compute quotient challenge alpha
+        let alpha = transcript.challenge_scalar(b"alpha");
+        transcript.append_scalar(b"alpha", &alpha);
+
+        // compute the separation challenges of the custom gates
+        let range_sep_challenge = transcript.challenge_scalar(b"range_sep_challenge");
+        transcript.append_scalar(b"range_sep_challenge", &range_sep_challenge);
+
+        let logic_sep_challenge = transcript.challenge_scalar(b"logic_sep_challenge");
+        transcript.append_scalar(b"logic_sep_challenge", &logic_sep_challenge);
+
+        let fixed_base_sep_challenge = transcript.challenge_scalar(b"fixed_base_sep_challenge");
+        transcript.append_scalar(b"fixed_base_sep_challenge", &fixed_base_sep_challenge);
+
+        let var_base_sep_challenge = transcript.challenge_scalar(b"var_base_sep_challenge");
+        transcript.append_scalar(b"var_base_sep_challenge", &var_base_sep_challenge);
+
+        let lookup_sep_challenge = transcript.challenge_scalar(b"lookup_sep_challenge");
+        transcript.append_scalar(b"lookup_sep_challenge", &lookup_sep_challenge);
+
+        let custom_sep_challenge = transcript.challenge_scalar(b"custom_sep_challenge");
+        transcript.append_scalar(b"custom_sep_challenge", &custom_sep_challenge);
+
+        // compute public inputs polynomial
+        let mut pi_poly = Polynomial::new(Builder::<P>::dense_public_inputs(
+            &public_input_indexes,
+            &public_inputs,
+            n,
+        ));
+        fft.idft(&mut pi_poly);
+
+        // compute quotient polynomial
+        let t_poly = quotient_poly::compute::<P>(
+            &fft,
+            &self.prover_key,
+            [&z_poly, &p_poly],
+            [&a_w_poly, &b_w_poly, &o_w_poly, &d_w_poly],
+            [&f_poly, &h_1_poly, &h_2_poly],
+            &table_eval_8n,
+            &pi_poly,
+            (&alpha, &beta, &gamma),
+            (&zeta, &delta, &epsilon, &lookup_sep_challenge),
+            (
+                &range_sep_challenge,
+                &logic_sep_challenge,
+                &fixed_base_sep_challenge,
+                &var_base_sep_challenge,
+            ),
+            &custom_sep_challenge,
+        );
+
+        // split quotient polynomial into 4 degree `n` polynomials
+        let t_low_poly = Polynomial::from_coefficients_vec(t_poly[0..n].to_vec());
+        let t_mid_poly = Polynomial::from_coefficients_vec(t_poly[n..2 * n].to_vec());
+        let t_high_poly = Polynomial::from_coefficients_vec(t_poly[2 * n..3 * n].to_vec());
+        let t_4_poly = Polynomial::from_coefficients_vec(t_poly[3 * n..].to_vec());
+
+        // commit to split quotient polynomial
+        let t_low_commit = self.keypair.commit(&t_low_poly)?;
+        let t_mid_commit = self.keypair.commit(&t_mid_poly)?;
+        let t_high_commit = self.keypair.commit(&t_high_poly)?;
+        let t_4_commit = self.keypair.commit(&t_4_poly)?;
+
+        // add quotient polynomial commitments to transcript
+        transcript.append_commitment(b"t_low", &t_low_commit);
+        transcript.append_commitment(b"t_mid", &t_mid_commit);
+        transcript.append_commitment(b"t_high", &t_high_commit);
+        transcript.append_commitment(b"t_4", &t_4_commit);
+
+        // round 4
+        // compute evaluation challenge 'z'
+        let z_challenge = transcript.challenge_scalar(b"z_challenge");
+        transcript.append_scalar(b"z_challenge", &z_challenge);
+
+        // compute linearization polynomial and the evaluations sent along
+        // the proof
+        let (r_poly, evaluations) = linearization_poly::compute::<P>(
+            &fft.generator(),
+            &self.prover_key,
+            (&alpha, &beta, &gamma, &z_challenge),
+            (&zeta, &delta, &epsilon, &lookup_sep_challenge),
+            (
+                &range_sep_challenge,
+                &logic_sep_challenge,
+                &fixed_base_sep_challenge,
+                &var_base_sep_challenge,
+            ),
+            &custom_sep_challenge,
+            [&a_w_poly, &b_w_poly, &o_w_poly, &d_w_poly],
+            [&z_poly, &p_poly],
+            [&f_poly, &h_1_poly, &h_2_poly, &table_poly],
+            [&t_low_poly, &t_mid_poly, &t_high_poly, &t_4_poly],
+        );
+
+        // add evaluations to transcript.
+        transcript.append_scalar(b"a_eval", &evaluations.a_eval);
+        transcript.append_scalar(b"b_eval", &evaluations.b_eval);
+        transcript.append_scalar(b"o_eval", &evaluations.o_eval);
+        transcript.append_scalar(b"d_eval", &evaluations.d_eval);
+        transcript.append_scalar(b"a_next_eval", &evaluations.a_next_eval);
+        transcript.append_scalar(b"b_next_eval", &evaluations.b_next_eval);
+        transcript.append_scalar(b"d_next_eval", &evaluations.d_next_eval);
+        transcript.append_scalar(b"q_arith_eval", &evaluations.q_arith_eval);
+        transcript.append_scalar(b"q_c_eval", &evaluations.q_c_eval);
+        transcript.append_scalar(b"q_l_eval", &evaluations.q_l_eval);
+        transcript.append_scalar(b"q_r_eval", &evaluations.q_r_eval);
+        transcript.append_scalar(b"s_sigma_1_eval", &evaluations.s_sigma_1_eval);
+        transcript.append_scalar(b"s_sigma_2_eval", &evaluations.s_sigma_2_eval);
+        transcript.append_scalar(b"s_sigma_3_eval", &evaluations.s_sigma_3_eval);
+        transcript.append_scalar(b"perm_eval", &evaluations.perm_eval);
+        transcript.append_scalar(b"f_eval", &evaluations.f_eval);
+        transcript.append_scalar(b"table_eval", &evaluations.table_eval);
+        transcript.append_scalar(b"table_next_eval", &evaluations.table_next_eval);
+        transcript.append_scalar(b"h_1_eval", &evaluations.h_1_eval);
+        transcript.append_scalar(b"h_1_next_eval", &evaluations.h_1_next_eval);
+        transcript.append_scalar(b"h_2_eval", &evaluations.h_2_eval);
+        transcript.append_scalar(b"lookup_perm_eval", &evaluations.lookup_perm_eval);
+
+        // round 5
+        // compute the opening proof polynomial
+        let v_challenge = transcript.challenge_scalar(b"v_challenge");
+        transcript.append_scalar(b"v_challenge", &v_challenge);
+
+        let aggregate_witness = self.keypair.compute_aggregate_witness(
+            &[
+                r_poly,
+                a_w_poly.clone(),
+                b_w_poly.clone(),
+                o_w_poly,
+                d_w_poly.clone(),
+                self.prover_key.permutation.s_sigma_1.0.clone(),
+                self.prover_key.permutation.s_sigma_2.0.clone(),
+                self.prover_key.permutation.s_sigma_3.0.clone(),
+                self.prover_key.arithmetic.q_arith.0.clone(),
+                self.prover_key.arithmetic.q_c.0.clone(),
+                self.prover_key.arithmetic.q_l.0.clone(),
+                self.prover_key.arithmetic.q_r.0.clone(),
+                f_poly,
+                h_1_poly.clone(),
+                h_2_poly,
+                table_poly.clone(),
+            ],
+            &z_challenge,
+            &v_challenge,
+        );
+        let w_z_chall_comm = self.keypair.commit(&aggregate_witness)?;
+
+        // compute the shifted opening proof polynomial
+        let v_w_challenge = transcript.challenge_scalar(b"v_w_challenge");
+        transcript.append_scalar(b"v_w_challenge", &v_w_challenge);
+
+        let shifted_aggregate_witness = self.keypair.compute_aggregate_witness(
+            &[
+                z_poly, a_w_poly, b_w_poly, d_w_poly, p_poly, h_1_poly, table_poly,
+            ],
+            &(z_challenge * fft.generator()),
+            &v_w_challenge,
+        );
+        let w_z_chall_w_comm = self.keypair.commit(&shifted_aggregate_witness)?;
+
+        let proof = Proof {
+            a_comm: a_w_poly_commit,
+            b_comm: b_w_poly_commit,
+            o_comm: o_w_poly_commit,
+            d_comm: d_w_poly_commit,
+
+            f_comm: f_poly_commit,
+            h_1_comm: h_1_poly_commit,
+            h_2_comm: h_2_poly_commit,
+
+            z_comm: z_poly_commit,
+            p_comm: p_poly_commit,
+
+            t_low_comm: t_low_commit,
+            t_mid_comm: t_mid_commit,
+            t_high_comm: t_high_commit,
+            t_4_comm: t_4_commit,
+
+            w_z_chall_comm,
+            w_z_chall_w_comm,
+
+            evaluations,
+        };
+
+        Ok((proof, public_inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+    use zero_bls12_381::Fr as BlsScalar;
+    use zero_kzg::KeyPair;
+    use zero_pairing::TatePairing;
+    use zkstd::common::Group;
+
+    use super::Prover;
+    use crate::composer::{Builder, Circuit, Composer, Verifier};
+    use crate::constraint_system::Constraint;
+    use crate::error::Error;
+
+    #[derive(Debug)]
+    struct DummyCircuit {
+        a: BlsScalar,
+        b: BlsScalar,
+        c: BlsScalar,
+    }
+
+    impl Default for DummyCircuit {
+        fn default() -> Self {
+            Self {
+                a: BlsScalar::from(7),
+                b: BlsScalar::from(11),
+                c: BlsScalar::from(77),
+            }
+        }
+    }
+
+    impl Circuit<TatePairing> for DummyCircuit {
+        fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+        where
+            C: Composer<TatePairing>,
+        {
+            let a = composer.append_witness(self.a);
+            let b = composer.append_witness(self.b);
+            let c = composer.append_witness(self.c);
+
+            let constraint = Constraint::new()
+                .mult(1)
+                .output(-BlsScalar::one())
+                .a(a)
+                .b(b)
+                .o(c);
+            composer.append_gate(constraint);
+
+            composer.assert_equal_constant(c, 0, Some(-self.c));
+
+            Ok(())
+        }
+    }
+
+    fn prover(
+        k: u64,
+    ) -> (
+        Prover<DummyCircuit, TatePairing>,
+        Verifier<DummyCircuit, TatePairing>,
+    ) {
+        let keypair = KeyPair::<TatePairing>::setup(k, BlsScalar::random(OsRng));
+        let mut builder = Builder::initialized(1 << 5);
+        DummyCircuit::default().circuit(&mut builder).unwrap();
+
+        let prover = Prover::new(b"prover", &keypair, &builder).unwrap();
+        let verifier = Verifier::new(
+            b"prover",
+            prover.verifier_data().clone(),
+            keypair.opening_key(),
+        );
+
+        (prover, verifier)
+    }
+
+    #[test]
+    fn prove_satisfied_circuit() {
+        let (prover, verifier) = prover(5);
+        let circuit = DummyCircuit {
+            a: BlsScalar::from(3),
+            b: BlsScalar::from(5),
+            c: BlsScalar::from(15),
+        };
+
+        let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).unwrap();
+
+        assert_eq!(public_inputs, vec![-BlsScalar::from(15)]);
+        assert!(verifier.verify(&proof, &public_inputs).is_ok());
+    }
+
+    #[test]
+    fn prove_unsatisfied_circuit() {
+        // the quotient of an unsatisfied circuit isn't divisible by the
+        // vanishing polynomial, so the key must commit to its whole degree
+        let (prover, verifier) = prover(8);
+        let circuit = DummyCircuit {
+            a: BlsScalar::from(3),
+            b: BlsScalar::from(5),
+            c: BlsScalar::from(16),
+        };
+
+        let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).unwrap();
+
+        assert!(verifier.verify(&proof, &public_inputs).is_err());
+    }
+}
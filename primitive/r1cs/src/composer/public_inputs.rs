@@ -0,0 +1,169 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::alloc::borrow::Cow;
+use zkstd::behave::Curve;
+use zkstd::common::{Pairing, Vec};
+
+use crate::error::Error;
+
+/// Public input declared by a circuit with
+/// [`Composer::declare_public_input`](super::Composer::declare_public_input)
+///
+/// The names declared by the circuits are borrowed, while the ones decoded
+/// from the [`VerifierData`](crate::prelude::VerifierData) are owned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicInput {
+    /// Scalar taking a single public input
+    Scalar(Cow<'static, str>),
+    /// Point taking the public inputs of its `x` and `y` coordinates
+    Point(Cow<'static, str>),
+}
+
+impl PublicInput {
+    /// Public scalar `name`
+    pub const fn scalar(name: &'static str) -> Self {
+        Self::Scalar(Cow::Borrowed(name))
+    }
+
+    /// Public point `name`
+    pub const fn point(name: &'static str) -> Self {
+        Self::Point(Cow::Borrowed(name))
+    }
+
+    /// Name the input is declared with
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Scalar(name) | Self::Point(name) => name,
+        }
+    }
+
+    /// Number of scalars the input takes in the public inputs
+    pub fn scalars(&self) -> usize {
+        match self {
+            Self::Scalar(_) => 1,
+            Self::Point(_) => 2,
+        }
+    }
+}
+
+/// Public inputs of a circuit in the order they are declared
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublicInputLayout(Vec<PublicInput>);
+
+impl PublicInputLayout {
+    /// Declared public inputs
+    pub fn inputs(&self) -> &[PublicInput] {
+        &self.0
+    }
+
+    /// Number of scalars taken by the declared public inputs
+    pub fn scalars(&self) -> usize {
+        self.0.iter().map(PublicInput::scalars).sum()
+    }
+
+    pub(crate) fn push(&mut self, input: PublicInput) {
+        self.0.push(input)
+    }
+
+    /// Check `provided` declares the same inputs in the same order
+    ///
+    /// Returns [`Error::PublicInputLayoutMismatch`] with the index of the
+    /// first input which differs otherwise.
+    pub(crate) fn check(&self, provided: &Self) -> Result<(), Error> {
+        let index = self
+            .0
+            .iter()
+            .zip(provided.0.iter())
+            .position(|(expected, provided)| expected != provided)
+            .unwrap_or_else(|| self.0.len().min(provided.0.len()));
+
+        if index == self.0.len() && index == provided.0.len() {
+            Ok(())
+        } else {
+            Err(Error::PublicInputLayoutMismatch { index })
+        }
+    }
+}
+
+/// Public input values along with the layout they are appended in
+///
+/// The values are negated like the ones the composer constrains its
+/// public input gates with, so they can be passed to the verifier as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputValues<P: Pairing> {
+    layout: PublicInputLayout,
+    scalars: Vec<P::ScalarField>,
+}
+
+impl<P: Pairing> Default for PublicInputValues<P> {
+    fn default() -> Self {
+        Self {
+            layout: PublicInputLayout::default(),
+            scalars: Vec::new(),
+        }
+    }
+}
+
+impl<P: Pairing> PublicInputValues<P> {
+    /// Append the public scalar `name`
+    pub fn scalar<S: Into<P::ScalarField>>(&mut self, name: &'static str, scalar: S) -> &mut Self {
+        self.layout.push(PublicInput::scalar(name));
+        self.scalars.push(-scalar.into());
+
+        self
+    }
+
+    /// Append the public point `name`
+    pub fn point<A: Into<P::JubjubAffine>>(&mut self, name: &'static str, point: A) -> &mut Self {
+        let point = point.into();
+
+        self.layout.push(PublicInput::point(name));
+        self.scalars.push(-P::ScalarField::from(point.get_x()));
+        self.scalars.push(-P::ScalarField::from(point.get_y()));
+
+        self
+    }
+
+    /// Layout the values are appended in
+    pub fn layout(&self) -> &PublicInputLayout {
+        &self.layout
+    }
+
+    /// Public inputs to verify a proof with
+    pub fn scalars(&self) -> &[P::ScalarField] {
+        &self.scalars
+    }
+}
+
+/// Public inputs of a circuit serialized from a typed struct
+///
+/// The prover and the verifier both serialize the struct, and
+/// [`Verifier::verify_public_inputs`](super::Verifier::verify_public_inputs)
+/// rejects the values if their layout differs from the one the circuit
+/// declared while compiling.
+pub trait PublicInputs<P: Pairing> {
+    /// Append the public inputs in the order the circuit declares them
+    fn append(&self, values: &mut PublicInputValues<P>);
+
+    /// Serialize the public inputs
+    fn values(&self) -> PublicInputValues<P> {
+        let mut values = PublicInputValues::default();
+
+        self.append(&mut values);
+
+        values
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use zkstd::common::Pairing;
+
+use crate::constraint_system::CustomSelector;
+
+/// Size of a circuit compiled by a [`Builder`](super::Builder)
+///
+/// The domain size is the degree the [`KeyPair`](zero_kzg::KeyPair) must
+/// be set up with to prove the circuit, so it can be read from the report
+/// of [`Compiler::report`](super::Compiler::report) before the setup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CircuitReport {
+    /// Total number of gates
+    pub gates: usize,
+    /// Gates activating the arithmetic selector
+    pub arithmetic_gates: usize,
+    /// Gates activating the range selector
+    pub range_gates: usize,
+    /// Gates activating the logic selector
+    pub logic_gates: usize,
+    /// Gates activating either of the group addition selectors
+    pub group_add_gates: usize,
+    /// Gates activating the lookup selector
+    pub lookup_gates: usize,
+    /// Gates activating a custom gate
+    pub custom_gates: usize,
+    /// Number of allocated witnesses
+    pub witnesses: usize,
+    /// Number of gates holding a public input
+    pub public_inputs: usize,
+    /// Power of two domain the gates and the lookup table are interpolated
+    /// over
+    pub domain_size: usize,
+}
+
+impl fmt::Display for CircuitReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "gates: {}", self.gates)?;
+        writeln!(f, "  arithmetic: {}", self.arithmetic_gates)?;
+        writeln!(f, "  range: {}", self.range_gates)?;
+        writeln!(f, "  logic: {}", self.logic_gates)?;
+        writeln!(f, "  group add: {}", self.group_add_gates)?;
+        writeln!(f, "  lookup: {}", self.lookup_gates)?;
+        writeln!(f, "  custom: {}", self.custom_gates)?;
+        writeln!(f, "witnesses: {}", self.witnesses)?;
+        writeln!(f, "public inputs: {}", self.public_inputs)?;
+        write!(f, "domain size: {}", self.domain_size)
+    }
+}
+
+/// Selectors and wire values of a gate of a [`Builder`](super::Builder)
+///
+/// Read with [`Builder::gate_diagnostics`](super::Builder::gate_diagnostics)
+/// for the gate of an [`Error::UnsatisfiedGate`](crate::prelude::Error).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GateDiagnostics<P: Pairing> {
+    /// Index of the gate
+    pub index: usize,
+    /// Selectors in the `[q_m, q_l, q_r, q_o, q_d, q_c, q_arith, q_range,
+    /// q_logic, q_fixed_group_add, q_variable_group_add, q_lookup]` order
+    pub selectors: [P::ScalarField; 12],
+    /// Selector of the registered custom gate the gate activates
+    pub custom_selector: Option<CustomSelector>,
+    /// Values of the `[a, b, o, d]` wires
+    pub wires: [P::ScalarField; 4],
+}
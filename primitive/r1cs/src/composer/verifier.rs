@@ -0,0 +1,152 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use core::marker::PhantomData;
+
+use merlin::Transcript as MerlinTranscript;
+use zero_kzg::Commitment;
+use zkstd::common::{CurveGroup, Pairing, Ring, Vec};
+
+use super::{Circuit, PublicInputLayout, PublicInputs};
+use crate::error::Error;
+use crate::proof_system::proof::pairing_check;
+use crate::proof_system::{Proof, VerifierData};
+use crate::transcript::{circuit_transcript, Transcript};
+
+/// Verify proofs of a given circuit
+#[derive(Clone)]
+pub struct Verifier<C, P, T = MerlinTranscript>
+where
+    C: Circuit<P>,
+    P: Pairing,
+    T: Transcript<P>,
+{
+    verifier_data: VerifierData<P>,
+    opening_key: P::G2Affine,
+    transcript: T,
+    circuit: PhantomData<C>,
+}
+
+impl<C, P, T> Verifier<C, P, T>
+where
+    C: Circuit<P>,
+    P: Pairing,
+    T: Transcript<P>,
+{
+    /// Create a verifier from the preprocessed circuit data and the opening
+    /// key of the keypair the circuit was compiled with `label`
+    ///
+    /// The public input layout the typed inputs are checked against is the
+    /// one encoded in `verifier_data`.
+    pub fn new(label: &[u8], verifier_data: VerifierData<P>, opening_key: P::G2Affine) -> Self {
+        let transcript = circuit_transcript(label, &verifier_data);
+
+        Self {
+            verifier_data,
+            opening_key,
+            transcript,
+            circuit: PhantomData,
+        }
+    }
+
+    /// Preprocessed circuit data
+    pub fn verifier_data(&self) -> &VerifierData<P> {
+        &self.verifier_data
+    }
+
+    /// Public inputs declared by the circuit
+    pub fn public_input_layout(&self) -> &PublicInputLayout {
+        self.verifier_data.public_input_layout()
+    }
+
+    /// Verify a generated proof
+    pub fn verify(&self, proof: &Proof<P>, public_inputs: &[P::ScalarField]) -> Result<(), Error> {
+        let (left, right) = self.opening(proof, public_inputs)?;
+
+        pairing_check::<P>(left, right, &self.opening_key)
+    }
+
+    /// Verify many generated proofs with a single pairing check
+    ///
+    /// The openings of the proofs are folded with the powers of a challenge
+    /// bound to all of them, so the batch is rejected as a whole if any of
+    /// its proofs is invalid.
+    ///
+    /// Returns [`Error::EmptyBatch`] if `proofs` is empty.
+    pub fn batch_verify(&self, proofs: &[(Proof<P>, Vec<P::ScalarField>)]) -> Result<(), Error> {
+        if proofs.is_empty() {
+            return Err(Error::EmptyBatch);
+        }
+
+        let openings = proofs
+            .iter()
+            .map(|(proof, public_inputs)| self.opening(proof, public_inputs))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut transcript = self.transcript.clone();
+        openings.iter().for_each(|(left, right)| {
+            transcript.append_commitment(b"left", &Commitment::<P>::new(*left));
+            transcript.append_commitment(b"right", &Commitment::<P>::new(*right));
+        });
+        let batch_challenge = transcript.challenge_scalar(b"batch_challenge");
+
+        let (left, right, _) = openings.iter().fold(
+            (
+                P::G1Projective::ADDITIVE_IDENTITY,
+                P::G1Projective::ADDITIVE_IDENTITY,
+                P::ScalarField::one(),
+            ),
+            |(acc_left, acc_right, power), (left, right)| {
+                (
+                    acc_left + *left * power,
+                    acc_right + *right * power,
+                    power * batch_challenge,
+                )
+            },
+        );
+
+        pairing_check::<P>(left, right, &self.opening_key)
+    }
+
+    /// Verify a generated proof with typed public inputs
+    ///
+    /// Returns [`Error::PublicInputLayoutMismatch`] if the inputs aren't
+    /// serialized in the order the circuit declares them.
+    pub fn verify_public_inputs<I>(&self, proof: &Proof<P>, public_inputs: &I) -> Result<(), Error>
+    where
+        I: PublicInputs<P>,
+    {
+        let values = public_inputs.values();
+
+        self.public_input_layout().check(values.layout())?;
+
+        self.verify(proof, values.scalars())
+    }
+
+    // Check the public inputs and fold the openings of `proof`
+    fn opening(
+        &self,
+        proof: &Proof<P>,
+        public_inputs: &[P::ScalarField],
+    ) -> Result<(P::G1Projective, P::G1Projective), Error> {
+        let expected = self.verifier_data.public_input_indexes.len();
+
+        if public_inputs.len() != expected {
+            return Err(Error::InconsistentPublicInputsLen {
+                expected,
+                provided: public_inputs.len(),
+            });
+        }
+
+        let mut transcript = self.transcript.clone();
+
+        public_inputs
+            .iter()
+            .for_each(|pi| transcript.append_scalar(b"pi", pi));
+
+        Ok(proof.opening(&self.verifier_data, &mut transcript, public_inputs))
+    }
+}
@@ -10,12 +10,16 @@
 //! build, preprocess circuits.
 
 pub(crate) mod constraint;
+pub(crate) mod custom;
 pub(crate) mod ecc;
+pub(crate) mod lookup;
 pub(crate) mod witness;
 
 pub(crate) use constraint::{Selector, WiredWitness};
 pub(crate) use witness::WireData;
 
 pub use constraint::Constraint;
+pub use custom::{CustomGate, CustomSelector, Wire};
 pub use ecc::WitnessPoint;
+pub use lookup::LookupTable;
 pub use witness::Witness;
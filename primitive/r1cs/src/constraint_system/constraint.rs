@@ -1,6 +1,6 @@
 use crate::composer::builder::Builder;
 use crate::composer::Composer;
-use crate::constraint_system::Witness;
+use crate::constraint_system::{CustomSelector, Witness};
 use zkstd::behave::{Group, Ring};
 use zkstd::common::Pairing;
 
@@ -32,6 +32,8 @@ pub(crate) enum Selector {
     GroupAddFixedBase = 0x0a,
     /// Curve addition with variable base coefficient (internal use)
     GroupAddVariableBase = 0x0b,
+    /// Lookup coefficient (internal use)
+    Lookup = 0x0c,
 }
 
 /// Wire used to address a witness inside of a [`Constraint`]
@@ -74,6 +76,9 @@ pub struct Constraint<P: Pairing> {
     //
     // Related issue: https://github.com/dusk-network/plonk/issues/607
     has_public_input: bool,
+
+    /// Custom gate activated by the constraint
+    custom_selector: Option<CustomSelector>,
 }
 
 impl<P: Pairing> Default for Constraint<P> {
@@ -95,6 +100,7 @@ impl<P: Pairing> Constraint<P> {
             coefficients: [P::ScalarField::zero(); 13],
             witnesses: [Builder::<P>::ZERO; 4],
             has_public_input: false,
+            custom_selector: None,
         }
     }
 
@@ -204,46 +210,46 @@ impl<P: Pairing> Constraint<P> {
         self.has_public_input
     }
 
+    pub(crate) const fn custom_selector(&self) -> Option<CustomSelector> {
+        self.custom_selector
+    }
+
     pub(crate) fn arithmetic(s: &Self) -> Self {
         Self::from_external(s).set(Selector::Arithmetic, 1)
     }
 
-    #[allow(dead_code)]
-    // TODO to be used when `ComposerBackend` replaces internal selectors
-    // with this struct
     pub(crate) fn range(s: &Self) -> Self {
         Self::from_external(s).set(Selector::Range, 1)
     }
 
-    #[allow(dead_code)]
-    // TODO to be used when `ComposerBackend` replaces internal selectors
-    // with this struct
     pub(crate) fn logic(s: &Self) -> Self {
         Self::from_external(s)
             .set(Selector::Constant, 1)
             .set(Selector::Logic, 1)
     }
 
-    #[allow(dead_code)]
-    // TODO to be used when `ComposerBackend` replaces internal selectors
-    // with this struct
     pub(crate) fn logic_xor(s: &Self) -> Self {
         Self::from_external(s)
             .set(Selector::Constant, -P::ScalarField::one())
             .set(Selector::Logic, -P::ScalarField::one())
     }
 
-    #[allow(dead_code)]
-    // TODO to be used when `ComposerBackend` replaces internal selectors
-    // with this struct
     pub(crate) fn group_add_fixed_base(s: &Self) -> Self {
         Self::from_external(s).set(Selector::GroupAddFixedBase, 1)
     }
 
-    #[allow(dead_code)]
-    // TODO to be used when `ComposerBackend` replaces internal selectors
-    // with this struct
     pub(crate) fn group_add_variable_base(s: &Self) -> Self {
         Self::from_external(s).set(Selector::GroupAddVariableBase, 1)
     }
+
+    pub(crate) fn lookup(s: &Self) -> Self {
+        Self::from_external(s).set(Selector::Lookup, 1)
+    }
+
+    pub(crate) fn custom(s: &Self, selector: CustomSelector) -> Self {
+        let mut s = Self::from_external(s);
+        s.custom_selector = Some(selector);
+
+        s
+    }
 }
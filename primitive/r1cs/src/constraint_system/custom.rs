@@ -0,0 +1,141 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::{Decode, Encode};
+use zkstd::common::{Group, Pairing, Vec};
+
+/// Wire value read by the identity of a [`CustomGate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wire {
+    /// `a` wire of the gate
+    A = 0x00,
+    /// `b` wire of the gate
+    B = 0x01,
+    /// `o` wire of the gate
+    O = 0x02,
+    /// `d` wire of the gate
+    D = 0x03,
+    /// `a` wire of the next gate
+    NextA = 0x04,
+    /// `b` wire of the next gate
+    NextB = 0x05,
+    /// `d` wire of the next gate
+    NextD = 0x06,
+}
+
+/// Monomial `coeff * a^e_0 * b^e_1 * ...` of a custom gate identity, with
+/// the exponents indexed by [`Wire`]
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub(crate) struct Term<P: Pairing> {
+    pub(crate) coeff: P::ScalarField,
+    pub(crate) powers: [u8; 7],
+}
+
+/// Gate defined outside of the crate by a named selector and a polynomial
+/// identity over the wires of the gate and of the next one
+///
+/// The identity must vanish on every gate activating the selector, and is
+/// added to the quotient and the linearization polynomials like the built-in
+/// gates. Its degree is bounded by [`CustomGate::MAX_DEGREE`] so the
+/// quotient fits in its four chunks.
+///
+/// ```
+/// use zero_pairing::TatePairing;
+/// use zero_r1cs::prelude::*;
+///
+/// // a * b^2 - o = 0
+/// let gate = CustomGate::<TatePairing>::new("mul_square")
+///     .term(BlsScalar::one(), &[Wire::A, Wire::B, Wire::B])
+///     .term(-BlsScalar::one(), &[Wire::O]);
+///
+/// assert_eq!(gate.degree(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomGate<P: Pairing> {
+    name: &'static str,
+    pub(crate) terms: Vec<Term<P>>,
+}
+
+impl<P: Pairing> CustomGate<P> {
+    /// Maximum total degree of the identity in the wires
+    pub const MAX_DEGREE: usize = 4;
+
+    /// Create a gate named `name` with an identity equal to zero
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            terms: Vec::new(),
+        }
+    }
+
+    /// Add `coeff` times the product of `wires` to the identity
+    ///
+    /// ## Panics
+    /// If the degree of the term exceeds [`Self::MAX_DEGREE`].
+    pub fn term<T: Into<P::ScalarField>>(mut self, coeff: T, wires: &[Wire]) -> Self {
+        assert!(
+            wires.len() <= Self::MAX_DEGREE,
+            "the degree of a custom gate term is at most {}",
+            Self::MAX_DEGREE
+        );
+
+        let mut powers = [0; 7];
+        wires.iter().for_each(|wire| powers[*wire as usize] += 1);
+
+        self.terms.push(Term {
+            coeff: coeff.into(),
+            powers,
+        });
+
+        self
+    }
+
+    /// Name the selector of the gate is registered with
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Total degree of the identity in the wires
+    pub fn degree(&self) -> usize {
+        self.terms
+            .iter()
+            .map(|term| term.powers.iter().map(|e| *e as usize).sum())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Evaluate the identity made of `terms` with the wire values indexed by
+/// [`Wire`]
+pub(crate) fn evaluate<P: Pairing>(
+    terms: &[Term<P>],
+    wires: &[P::ScalarField; 7],
+) -> P::ScalarField {
+    terms
+        .iter()
+        .map(|term| {
+            term.powers
+                .iter()
+                .zip(wires.iter())
+                .fold(term.coeff, |acc, (e, w)| {
+                    (0..*e).fold(acc, |acc, _| acc * w)
+                })
+        })
+        .fold(P::ScalarField::zero(), |acc, t| acc + t)
+}
+
+/// Selector of a custom gate registered into a composer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomSelector(pub(crate) usize);
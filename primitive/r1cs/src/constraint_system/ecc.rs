@@ -14,7 +14,6 @@ pub struct WitnessPoint {
 }
 
 impl WitnessPoint {
-    #[allow(dead_code)]
     pub(crate) const fn new(x: Witness, y: Witness) -> Self {
         Self { x, y }
     }
@@ -0,0 +1,94 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zkstd::common::{Pairing, Vec};
+
+/// Rows of three scalars a lookup gate can be constrained to
+///
+/// Every table appended to a composer is merged into a single one, so the
+/// rows of different operations must be distinguishable, for instance with a
+/// tag in one of their columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LookupTable<P: Pairing>(pub(crate) Vec<[P::ScalarField; 3]>);
+
+impl<P: Pairing> Default for LookupTable<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Pairing> From<Vec<[P::ScalarField; 3]>> for LookupTable<P> {
+    fn from(rows: Vec<[P::ScalarField; 3]>) -> Self {
+        Self(rows)
+    }
+}
+
+impl<P: Pairing> LookupTable<P> {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Append the row `(a, b, c)`
+    pub fn insert<T: Into<P::ScalarField>>(&mut self, a: T, b: T, c: T) {
+        self.0.push([a.into(), b.into(), c.into()]);
+    }
+
+    /// Rows count
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the table has no row
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Rows of the table
+    pub fn rows(&self) -> &[[P::ScalarField; 3]] {
+        &self.0
+    }
+
+    /// Table of `(a, b, a ^ b)` for every `a` and `b` of `bits` bits
+    ///
+    /// ## Panics
+    /// If `bits` is greater than 8.
+    pub fn xor(bits: usize) -> Self {
+        Self::binary(bits, |a, b| a ^ b)
+    }
+
+    /// Table of `(a, b, a & b)` for every `a` and `b` of `bits` bits
+    ///
+    /// ## Panics
+    /// If `bits` is greater than 8.
+    pub fn and(bits: usize) -> Self {
+        Self::binary(bits, |a, b| a & b)
+    }
+
+    fn binary(bits: usize, op: impl Fn(u64, u64) -> u64) -> Self {
+        assert!(bits <= 8, "the table would exceed 2^16 rows");
+
+        let size = 1u64 << bits;
+        let mut table = Self(Vec::with_capacity((size * size) as usize));
+
+        for a in 0..size {
+            for b in 0..size {
+                table.insert(a, b, op(a, b));
+            }
+        }
+
+        table
+    }
+}
@@ -25,6 +25,8 @@ pub enum Error {
     // Prover/Verifier errors
     /// This error occurs when a proof verification fails.
     ProofVerificationError,
+    /// This error occurs when a batch to verify holds no proof.
+    EmptyBatch,
     /// This error occurs when the circuit is not provided with all of the
     /// required inputs.
     CircuitInputsNotFound,
@@ -65,6 +67,14 @@ pub enum Error {
     /// This error occurs when there are not enough bytes to read out of a
     /// slice during deserialization.
     NotEnoughBytes,
+    /// This error occurs when bytes are left after deserialization.
+    TrailingBytes,
+    /// This error occurs when the decoded verifier data doesn't describe a
+    /// circuit.
+    InvalidVerifierDataBytes,
+    /// This error occurs when the imported circuit JSON doesn't describe a
+    /// circuit.
+    InvalidCircuitJson,
     /// This error occurs when a malformed point is decoded from a byte array.
     PointMalformed,
     /// This error occurs when a malformed BLS scalar is decoded from a byte
@@ -72,6 +82,9 @@ pub enum Error {
     BlsScalarMalformed,
     /// WNAF2k should be in `[-1, 0, 1]`
     UnsupportedWNAF2k,
+    /// This error occurs when the wires of a lookup gate aren't a row of the
+    /// lookup table.
+    LookupQueryNotInTable,
     /// The provided public inputs doesn't match the circuit definition
     PublicInputNotFound {
         /// Expected public input wasn't found
@@ -84,6 +97,20 @@ pub enum Error {
         /// Provided value
         provided: usize,
     },
+    /// The layout of the provided public inputs doesn't match the one
+    /// declared by the circuit
+    PublicInputLayoutMismatch {
+        /// Index of the first input which differs
+        index: usize,
+    },
+    /// A gate or a copy constraint isn't satisfied by the witness values.
+    ///
+    /// The selectors and the wire values of the gate are read with
+    /// `Builder::gate_diagnostics`.
+    UnsatisfiedGate {
+        /// Index of the first failing gate
+        index: usize,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -102,6 +129,7 @@ impl core::fmt::Display for Error {
             Self::ProofVerificationError => {
                 write!(f, "proof verification failed")
             }
+            Self::EmptyBatch => write!(f, "the batch holds no proof"),
             Self::CircuitInputsNotFound => {
                 write!(f, "circuit inputs not found")
             }
@@ -136,18 +164,30 @@ impl core::fmt::Display for Error {
             }
             Self::PairingCheckFailure => write!(f, "pairing check failed"),
             Self::NotEnoughBytes => write!(f, "not enough bytes left to read"),
+            Self::TrailingBytes => write!(f, "bytes left after deserialization"),
+            Self::InvalidVerifierDataBytes => write!(f, "invalid verifier data bytes"),
+            Self::InvalidCircuitJson => write!(f, "invalid circuit json"),
             Self::PointMalformed => write!(f, "BLS point bytes malformed"),
             Self::BlsScalarMalformed => write!(f, "BLS scalar bytes malformed"),
             Self::UnsupportedWNAF2k => write!(
                 f,
                 "WNAF2k cannot hold values not contained in `[-1..1]`"
             ),
+            Self::LookupQueryNotInTable => {
+                write!(f, "the lookup query isn't a row of the lookup table")
+            }
             Self::PublicInputNotFound {
                 index
             } => write!(f, "The public input of index {} is defined in the circuit description, but wasn't declared in the prove instance", index),
             Self::InconsistentPublicInputsLen {
                 expected, provided,
             } => write!(f, "The provided public inputs set of length {} doesn't match the processed verifier: {}", provided, expected),
+            Self::PublicInputLayoutMismatch {
+                index
+            } => write!(f, "The public input of index {} doesn't match the layout declared by the circuit", index),
+            Self::UnsatisfiedGate {
+                index
+            } => write!(f, "The gate of index {} isn't satisfied by the witness values", index),
         }
     }
 }
@@ -0,0 +1,75 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Groth16 proving system over rank-1 constraint systems
+//!
+//! The setup is circuit specific: the proving and verifying keys are derived
+//! from the constraints of a [`R1csCircuit`] and random toxic waste, and the
+//! proofs consist of three group elements checked with a single multi Miller
+//! loop.
+
+mod key;
+mod proof;
+mod prover;
+mod qap;
+mod verifier;
+
+pub use key::{ProvingKey, VerifyingKey};
+pub use proof::Proof;
+pub use prover::Prover;
+pub use verifier::Verifier;
+
+use rand_core::RngCore;
+use zkstd::common::Pairing;
+
+use crate::error::Error;
+use crate::r1cs::{R1cs, R1csCircuit};
+
+/// Generate the arguments to prove and verify a circuit with Groth16
+pub struct Groth16;
+
+type Groth16Result<C, P> = Result<(Prover<C, P>, Verifier<C, P>), Error>;
+
+impl Groth16 {
+    /// Run the circuit specific setup of a given circuit
+    ///
+    /// Use the default implementation of the circuit
+    pub fn compile<C, P, R>(rng: &mut R) -> Groth16Result<C, P>
+    where
+        C: R1csCircuit<P>,
+        P: Pairing,
+        R: RngCore,
+    {
+        Self::compile_with_circuit::<C, P, R>(rng, &Default::default())
+    }
+
+    /// Run the circuit specific setup of a given circuit
+    ///
+    /// Use the provided circuit instead of the default implementation
+    pub fn compile_with_circuit<C, P, R>(rng: &mut R, circuit: &C) -> Groth16Result<C, P>
+    where
+        C: R1csCircuit<P>,
+        P: Pairing,
+        R: RngCore,
+    {
+        let mut cs = R1cs::default();
+
+        circuit.synthesize(&mut cs)?;
+
+        let (proving_key, verifying_key) = key::setup(&cs, rng);
+
+        Ok((Prover::new(proving_key), Verifier::new(verifying_key)))
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::{Decode, Encode};
+use rand_core::RngCore;
+use zkstd::common::{CurveGroup, FftField, Group, Pairing, Ring, Vec};
+
+use super::qap;
+use crate::r1cs::R1cs;
+
+/// Circuit specific parameters to generate Groth16 proofs
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub struct ProvingKey<P: Pairing> {
+    pub(crate) alpha_g1: P::G1Affine,
+    pub(crate) beta_g1: P::G1Affine,
+    pub(crate) beta_g2: P::G2Affine,
+    pub(crate) delta_g1: P::G1Affine,
+    pub(crate) delta_g2: P::G2Affine,
+    /// `A_j(tau)` of every variable
+    pub(crate) a_query: Vec<P::G1Affine>,
+    /// `B_j(tau)` of every variable
+    pub(crate) b_g1_query: Vec<P::G1Affine>,
+    /// `B_j(tau)` of every variable
+    pub(crate) b_g2_query: Vec<P::G2Affine>,
+    /// `tau^i * Z(tau) / delta` for the coefficients of `h(X)`
+    pub(crate) h_query: Vec<P::G1Affine>,
+    /// `(beta * A_j(tau) + alpha * B_j(tau) + C_j(tau)) / delta` of every
+    /// witness variable
+    pub(crate) l_query: Vec<P::G1Affine>,
+}
+
+/// Circuit specific parameters to verify Groth16 proofs
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub struct VerifyingKey<P: Pairing> {
+    pub(crate) alpha_g1: P::G1Affine,
+    pub(crate) beta_g2: P::G2Affine,
+    pub(crate) gamma_g2: P::G2Affine,
+    pub(crate) delta_g2: P::G2Affine,
+    /// `(beta * A_j(tau) + alpha * B_j(tau) + C_j(tau)) / gamma` of every
+    /// instance variable
+    pub(crate) ic: Vec<P::G1Affine>,
+}
+
+impl<P: Pairing> ProvingKey<P> {
+    /// Instance variables count, including the constant one
+    pub fn instance_len(&self) -> usize {
+        self.a_query.len() - self.l_query.len()
+    }
+
+    /// Witness variables count
+    pub fn witness_len(&self) -> usize {
+        self.l_query.len()
+    }
+}
+
+impl<P: Pairing> VerifyingKey<P> {
+    /// Public inputs count
+    pub fn public_inputs_len(&self) -> usize {
+        self.ic.len() - 1
+    }
+}
+
+/// Derive the keys of `cs` from random toxic waste which is dropped
+/// afterwards
+pub(crate) fn setup<P, R>(cs: &R1cs<P>, rng: &mut R) -> (ProvingKey<P>, VerifyingKey<P>)
+where
+    P: Pairing,
+    R: RngCore,
+{
+    let tau = P::ScalarField::random(&mut *rng);
+    let alpha = P::ScalarField::random(&mut *rng);
+    let beta = P::ScalarField::random(&mut *rng);
+    let gamma = P::ScalarField::random(&mut *rng);
+    let delta = P::ScalarField::random(&mut *rng);
+
+    let gamma_inv = gamma.invert().expect("gamma is not zero");
+    let delta_inv = delta.invert().expect("delta is not zero");
+
+    let fft = qap::domain(cs);
+    let n = fft.size();
+    let [a, b, c] = qap::evaluate_at(cs, &fft, &tau);
+
+    let g1 = |s: &P::ScalarField| P::G1Affine::from(P::G1Affine::ADDITIVE_GENERATOR * s);
+    let g2 = |s: &P::ScalarField| P::G2Affine::from(P::G2Affine::ADDITIVE_GENERATOR * s);
+
+    // beta * A_j(tau) + alpha * B_j(tau) + C_j(tau)
+    let k: Vec<_> = a
+        .iter()
+        .zip(b.iter())
+        .zip(c.iter())
+        .map(|((a, b), c)| beta * a + alpha * b + c)
+        .collect();
+    let (ic, l) = k.split_at(cs.instance_len());
+
+    let z_tau = tau.pow(n as u64) - P::ScalarField::one();
+    let h_query = (0..n - 1)
+        .scan(z_tau * delta_inv, |acc, _| {
+            let h = *acc;
+            *acc *= tau;
+            Some(g1(&h))
+        })
+        .collect();
+
+    let proving_key = ProvingKey {
+        alpha_g1: g1(&alpha),
+        beta_g1: g1(&beta),
+        beta_g2: g2(&beta),
+        delta_g1: g1(&delta),
+        delta_g2: g2(&delta),
+        a_query: a.iter().map(g1).collect(),
+        b_g1_query: b.iter().map(g1).collect(),
+        b_g2_query: b.iter().map(g2).collect(),
+        h_query,
+        l_query: l.iter().map(|l| g1(&(*l * delta_inv))).collect(),
+    };
+
+    let verifying_key = VerifyingKey {
+        alpha_g1: g1(&alpha),
+        beta_g2: g2(&beta),
+        gamma_g2: g2(&gamma),
+        delta_g2: g2(&delta),
+        ic: ic.iter().map(|ic| g1(&(*ic * gamma_inv))).collect(),
+    };
+
+    (proving_key, verifying_key)
+}
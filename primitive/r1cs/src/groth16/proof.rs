@@ -0,0 +1,57 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_pairing::msm_variable_base;
+use zkstd::common::{Group, Pairing, PairingRange};
+
+use super::key::VerifyingKey;
+use crate::error::Error;
+
+/// Groth16 proof, made of the `A`, `B` and `C` group elements
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub struct Proof<P: Pairing> {
+    pub(crate) a: P::G1Affine,
+    pub(crate) b: P::G2Affine,
+    pub(crate) c: P::G1Affine,
+}
+
+impl<P: Pairing> Proof<P> {
+    /// Check `e(A, B) = e(alpha, beta) * e(IC, gamma) * e(C, delta)` where
+    /// `IC` is the linear combination of the instance with the verifying key
+    pub(crate) fn verify(
+        &self,
+        verifying_key: &VerifyingKey<P>,
+        [beta_g2, gamma_g2, delta_g2]: &[P::G2PairngRepr; 3],
+        public_inputs: &[P::ScalarField],
+    ) -> Result<(), Error> {
+        let ic =
+            msm_variable_base::<P>(&verifying_key.ic[1..], public_inputs) + verifying_key.ic[0];
+
+        let pairing = P::multi_miller_loop(&[
+            (self.a, P::G2PairngRepr::from(self.b)),
+            (-verifying_key.alpha_g1, beta_g2.clone()),
+            (P::G1Affine::from(-ic), gamma_g2.clone()),
+            (-self.c, delta_g2.clone()),
+        ])
+        .final_exp();
+
+        if pairing == <P::PairingRange as PairingRange>::Gt::ADDITIVE_IDENTITY {
+            Ok(())
+        } else {
+            Err(Error::ProofVerificationError)
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+use zero_pairing::msm_variable_base;
+use zkstd::common::{Group, Pairing, Vec};
+
+use super::key::ProvingKey;
+use super::proof::Proof;
+use super::qap;
+use crate::error::Error;
+use crate::r1cs::{R1cs, R1csCircuit};
+
+/// Generate Groth16 proofs of a given circuit
+#[derive(Debug, Clone)]
+pub struct Prover<C, P>
+where
+    C: R1csCircuit<P>,
+    P: Pairing,
+{
+    proving_key: ProvingKey<P>,
+    circuit: PhantomData<C>,
+}
+
+impl<C, P> Prover<C, P>
+where
+    C: R1csCircuit<P>,
+    P: Pairing,
+{
+    /// Create a prover from the proving key of the circuit
+    pub fn new(proving_key: ProvingKey<P>) -> Self {
+        Self {
+            proving_key,
+            circuit: PhantomData,
+        }
+    }
+
+    /// Circuit specific proving key
+    pub fn proving_key(&self) -> &ProvingKey<P> {
+        &self.proving_key
+    }
+
+    /// Prove the circuit
+    pub fn prove<R>(
+        &self,
+        rng: &mut R,
+        circuit: &C,
+    ) -> Result<(Proof<P>, Vec<P::ScalarField>), Error>
+    where
+        R: RngCore,
+    {
+        let pk = &self.proving_key;
+        let mut cs = R1cs::default();
+
+        circuit.synthesize(&mut cs)?;
+
+        let fft = qap::domain(&cs);
+
+        // the circuit must have the shape it was set up with
+        if cs.instance_len() != pk.instance_len()
+            || cs.witness_len() != pk.witness_len()
+            || fft.size() != pk.h_query.len() + 1
+        {
+            return Err(Error::MismatchedPolyLen);
+        }
+
+        let r = P::ScalarField::random(&mut *rng);
+        let s = P::ScalarField::random(&mut *rng);
+
+        let assignment: Vec<_> = cs
+            .instance
+            .iter()
+            .chain(cs.witness.iter())
+            .copied()
+            .collect();
+
+        // A = alpha + sum(z_j * A_j(tau)) + r * delta
+        let a = msm_variable_base::<P>(&pk.a_query, &assignment) + pk.alpha_g1 + pk.delta_g1 * r;
+
+        // B = beta + sum(z_j * B_j(tau)) + s * delta
+        let b_g1 =
+            msm_variable_base::<P>(&pk.b_g1_query, &assignment) + pk.beta_g1 + pk.delta_g1 * s;
+        let b_g2 = pk.b_g2_query.iter().zip(assignment.iter()).fold(
+            P::G2Projective::from(pk.beta_g2) + pk.delta_g2 * s,
+            |acc, (b, z)| acc + *b * z,
+        );
+
+        // C = sum(w_j * L_j) + h(tau) * Z(tau) / delta + s * A + r * B - r * s * delta
+        let h = qap::compute_h(&cs, &fft);
+        let c = msm_variable_base::<P>(&pk.l_query, &cs.witness)
+            + msm_variable_base::<P>(&pk.h_query, &h)
+            + a * s
+            + b_g1 * r
+            - pk.delta_g1 * (r * s);
+
+        let proof = Proof {
+            a: a.into(),
+            b: b_g2.into(),
+            c: c.into(),
+        };
+
+        Ok((proof, cs.public_inputs()))
+    }
+}
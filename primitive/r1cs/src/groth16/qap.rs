@@ -0,0 +1,181 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reduction of a rank-1 constraint system to a quadratic arithmetic program
+//!
+//! Every constraint is assigned to a root of unity of the domain and every
+//! variable to the polynomials interpolating its columns of the `A`, `B` and
+//! `C` matrices. One extra row per instance variable is appended to `A` so
+//! the instance polynomials are linearly independent.
+
+use zero_kzg::{Fft, Polynomial};
+use zkstd::common::{FftField, Group, Pairing, Ring, Vec};
+
+use crate::r1cs::R1cs;
+
+/// Domain large enough to hold the constraints and the instance rows
+pub(crate) fn domain<P: Pairing>(cs: &R1cs<P>) -> Fft<P::ScalarField> {
+    let size = (cs.constraints() + cs.instance_len()).next_power_of_two();
+
+    Fft::new(size.trailing_zeros().max(1) as usize)
+}
+
+/// Evaluations of the `A`, `B` and `C` polynomials of every variable at
+/// `tau`, with the instance variables followed by the witness ones
+pub(crate) fn evaluate_at<P: Pairing>(
+    cs: &R1cs<P>,
+    fft: &Fft<P::ScalarField>,
+    tau: &P::ScalarField,
+) -> [Vec<P::ScalarField>; 3] {
+    let n = fft.size();
+    let constraints = cs.constraints();
+    let (instance_len, witness_len) = (cs.instance_len(), cs.witness_len());
+
+    // L_i(tau) = Z(tau) * w^i / (n * (tau - w^i))
+    let z_tau = vanishing_eval::<P>(n, tau);
+    let n_inv = P::ScalarField::from(n as u64)
+        .invert()
+        .expect("the domain size is not zero");
+    let lagrange: Vec<_> = fft
+        .elements
+        .iter()
+        .map(|w| {
+            let denominator = (*tau - w)
+                .invert()
+                .expect("tau is not in the evaluation domain");
+
+            z_tau * n_inv * w * denominator
+        })
+        .collect();
+
+    let mut a = cs.a.evaluate_columns(&lagrange, instance_len, witness_len);
+    let b = cs.b.evaluate_columns(&lagrange, instance_len, witness_len);
+    let c = cs.c.evaluate_columns(&lagrange, instance_len, witness_len);
+
+    a.iter_mut()
+        .take(instance_len)
+        .zip(&lagrange[constraints..])
+        .for_each(|(a, l)| *a += l);
+
+    [a, b, c]
+}
+
+/// Coefficients of `h(X) = (A(X) * B(X) - C(X)) / Z(X)` for the assignment
+/// of `cs`
+pub(crate) fn compute_h<P: Pairing>(
+    cs: &R1cs<P>,
+    fft: &Fft<P::ScalarField>,
+) -> Vec<P::ScalarField> {
+    let n = fft.size();
+
+    let mut a = cs.a.evaluate(&cs.instance, &cs.witness);
+    a.extend_from_slice(&cs.instance);
+    let b = cs.b.evaluate(&cs.instance, &cs.witness);
+    let c = cs.c.evaluate(&cs.instance, &cs.witness);
+
+    // evaluations over the domain to evaluations over its coset
+    let coset = |evals: Vec<P::ScalarField>| {
+        let mut poly = Polynomial::new(evals);
+        fft.idft(&mut poly);
+        fft.coset_dft(&mut poly);
+
+        poly.0
+    };
+
+    let (a, b, c) = (coset(a), coset(b), coset(c));
+
+    // the vanishing polynomial is constant over the coset
+    let z_inv = vanishing_eval::<P>(n, &P::ScalarField::MULTIPLICATIVE_GENERATOR)
+        .invert()
+        .expect("the coset is disjoint from the evaluation domain");
+
+    let mut h = Polynomial::new(
+        a.iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| (*a * b - c) * z_inv)
+            .collect(),
+    );
+    fft.coset_idft(&mut h);
+
+    // `h(X)` is of degree `n - 2`
+    h.0.truncate(n - 1);
+    h.0
+}
+
+// Z(x) = x^n - 1
+fn vanishing_eval<P: Pairing>(n: usize, x: &P::ScalarField) -> P::ScalarField {
+    x.pow(n as u64) - P::ScalarField::one()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+    use zero_bls12_381::Fr as BlsScalar;
+    use zero_kzg::Polynomial;
+    use zero_pairing::TatePairing;
+    use zkstd::behave::*;
+
+    use super::{compute_h, domain, evaluate_at, vanishing_eval};
+    use crate::r1cs::{LinearCombination, R1cs};
+
+    // x^3 + x + 5 = y
+    fn cubic(x: u64) -> R1cs<TatePairing> {
+        let mut cs = R1cs::default();
+
+        let y = cs.alloc_instance(x * x * x + x + 5);
+        let x_sq = cs.alloc_witness(x * x);
+        let x_cu = cs.alloc_witness(x * x * x);
+        let x = cs.alloc_witness(x);
+
+        cs.enforce(x, x, x_sq);
+        cs.enforce(x_sq, x, x_cu);
+        cs.enforce(
+            LinearCombination::from(x_cu) + x + (BlsScalar::from(5), R1cs::<TatePairing>::ONE),
+            R1cs::<TatePairing>::ONE,
+            y,
+        );
+
+        cs
+    }
+
+    #[test]
+    fn qap_is_divisible_by_the_vanishing_polynomial() {
+        let cs = cubic(3);
+        assert!(cs.is_sat());
+
+        let fft = domain(&cs);
+        let tau = BlsScalar::random(OsRng);
+        let [a, b, c] = evaluate_at(&cs, &fft, &tau);
+
+        let assignment: Vec<_> = cs.instance.iter().chain(cs.witness.iter()).collect();
+        let eval = |polys: &[BlsScalar]| -> BlsScalar {
+            polys.iter().zip(&assignment).map(|(p, z)| *p * *z).sum()
+        };
+
+        let h = Polynomial::new(compute_h(&cs, &fft)).evaluate(&tau);
+        let z = vanishing_eval::<TatePairing>(fft.size(), &tau);
+
+        assert_eq!(eval(&a) * eval(&b) - eval(&c), h * z);
+    }
+
+    #[test]
+    fn unsatisfied_assignment() {
+        let mut cs = cubic(3);
+        cs.witness[1] = BlsScalar::from(10);
+
+        assert!(!cs.is_sat());
+    }
+}
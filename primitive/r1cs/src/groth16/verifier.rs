@@ -0,0 +1,76 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::marker::PhantomData;
+
+use zkstd::common::Pairing;
+
+use super::key::VerifyingKey;
+use super::proof::Proof;
+use crate::error::Error;
+use crate::r1cs::R1csCircuit;
+
+/// Verify Groth16 proofs of a given circuit
+#[derive(Debug, Clone)]
+pub struct Verifier<C, P>
+where
+    C: R1csCircuit<P>,
+    P: Pairing,
+{
+    verifying_key: VerifyingKey<P>,
+    /// `beta`, `gamma` and `delta` prepared for the Miller loop
+    prepared: [P::G2PairngRepr; 3],
+    circuit: PhantomData<C>,
+}
+
+impl<C, P> Verifier<C, P>
+where
+    C: R1csCircuit<P>,
+    P: Pairing,
+{
+    /// Create a verifier from the verifying key of the circuit
+    pub fn new(verifying_key: VerifyingKey<P>) -> Self {
+        let prepared = [
+            P::G2PairngRepr::from(verifying_key.beta_g2),
+            P::G2PairngRepr::from(verifying_key.gamma_g2),
+            P::G2PairngRepr::from(verifying_key.delta_g2),
+        ];
+
+        Self {
+            verifying_key,
+            prepared,
+            circuit: PhantomData,
+        }
+    }
+
+    /// Circuit specific verifying key
+    pub fn verifying_key(&self) -> &VerifyingKey<P> {
+        &self.verifying_key
+    }
+
+    /// Verify a generated proof
+    pub fn verify(&self, proof: &Proof<P>, public_inputs: &[P::ScalarField]) -> Result<(), Error> {
+        let expected = self.verifying_key.public_inputs_len();
+
+        if public_inputs.len() != expected {
+            return Err(Error::InconsistentPublicInputsLen {
+                expected,
+                provided: public_inputs.len(),
+            });
+        }
+
+        proof.verify(&self.verifying_key, &self.prepared, public_inputs)
+    }
+}
@@ -1,6 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#[macro_use]
+mod parallel;
+
 mod composer;
 mod constraint_system;
 mod error;
+pub mod groth16;
 mod permutation;
 pub mod prelude;
+mod proof_system;
+pub mod r1cs;
+mod transcript;
@@ -0,0 +1,44 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Iterators running in parallel with the `parallel` feature and
+//! sequentially otherwise, so `no_std` builds don't depend on rayon.
+//!
+//! The callers import `rayon::prelude::*` under the feature for the adapters
+//! of the parallel iterators.
+
+/// Iterates over the references to the items of `$e`
+macro_rules! cfg_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = $e.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = $e.iter();
+
+        iter
+    }};
+}
+
+/// Iterates over the items of `$e`
+macro_rules! cfg_into_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let iter = $e.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = $e.into_iter();
+
+        iter
+    }};
+}
@@ -9,7 +9,15 @@ use crate::constraint_system::{WireData, Witness};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
 
+use zero_kzg::{Fft, Polynomial};
 use zkstd::behave::*;
+use zkstd::common::Vec;
+
+/// Constants used in the permutation argument to ensure that the wire subsets
+/// are disjoint.
+pub(crate) const K1: u64 = 7;
+pub(crate) const K2: u64 = 13;
+pub(crate) const K3: u64 = 17;
 
 /// Permutation provides the necessary state information and functions
 /// to create the permutation polynomial. In the literature, Z(X) is the
@@ -95,4 +103,367 @@ impl<P: Pairing> Permutation<P> {
         let vec_wire_data = self.witness_map.get_mut(&var.into()).unwrap();
         vec_wire_data.push(wire_data);
     }
+
+    /// Performs shift by one permutation and computes `sigma_1`, `sigma_2`,
+    /// `sigma_3` and `sigma_4` permutations from the variable maps
+    pub(crate) fn compute_sigma_permutations(&self, n: usize) -> [Vec<WireData>; 4] {
+        let sigma_1: Vec<_> = (0..n).map(WireData::Left).collect();
+        let sigma_2: Vec<_> = (0..n).map(WireData::Right).collect();
+        let sigma_3: Vec<_> = (0..n).map(WireData::Output).collect();
+        let sigma_4: Vec<_> = (0..n).map(WireData::Fourth).collect();
+
+        let mut sigmas = [sigma_1, sigma_2, sigma_3, sigma_4];
+
+        for (_, wire_data) in self.witness_map.iter() {
+            // Gets the data for each wire assosciated with this witness
+            for (wire_index, current_wire) in wire_data.iter().enumerate() {
+                // Fetch index of the next wire, if it is the last element
+                // We loop back around to the beginning
+                let next_index = match wire_index == wire_data.len() - 1 {
+                    true => 0,
+                    false => wire_index + 1,
+                };
+
+                // Fetch the next wire
+                let next_wire = &wire_data[next_index];
+
+                // Map current wire to next wire
+                match current_wire {
+                    WireData::Left(index) => sigmas[0][*index] = *next_wire,
+                    WireData::Right(index) => sigmas[1][*index] = *next_wire,
+                    WireData::Output(index) => sigmas[2][*index] = *next_wire,
+                    WireData::Fourth(index) => sigmas[3][*index] = *next_wire,
+                };
+            }
+        }
+
+        sigmas
+    }
+
+    fn compute_permutation_lagrange(
+        &self,
+        sigma_mapping: &[WireData],
+        fft: &Fft<P::ScalarField>,
+    ) -> Vec<P::ScalarField> {
+        let roots = &fft.elements;
+
+        let lagrange_poly: Vec<P::ScalarField> = sigma_mapping
+            .iter()
+            .map(|x| match x {
+                WireData::Left(index) => roots[*index],
+                WireData::Right(index) => P::ScalarField::from(K1) * roots[*index],
+                WireData::Output(index) => P::ScalarField::from(K2) * roots[*index],
+                WireData::Fourth(index) => P::ScalarField::from(K3) * roots[*index],
+            })
+            .collect();
+
+        lagrange_poly
+    }
+
+    /// Computes the sigma polynomials which are used to build the permutation
+    /// polynomial
+    pub(crate) fn compute_sigma_polynomials(
+        &self,
+        n: usize,
+        fft: &Fft<P::ScalarField>,
+    ) -> [Polynomial<P::ScalarField>; 4] {
+        // Compute sigma mappings
+        let sigmas = self.compute_sigma_permutations(n);
+
+        assert_eq!(sigmas[0].len(), n);
+        assert_eq!(sigmas[1].len(), n);
+        assert_eq!(sigmas[2].len(), n);
+        assert_eq!(sigmas[3].len(), n);
+
+        // define the sigma permutations using two non quadratic residues
+        sigmas.map(|sigma| {
+            let mut s_sigma = Polynomial::new(self.compute_permutation_lagrange(&sigma, fft));
+            fft.idft(&mut s_sigma);
+
+            Polynomial::from_coefficients_vec(s_sigma.0)
+        })
+    }
+
+    /// Computes the evaluations of the permutation polynomial `Z(X)` over
+    /// the evaluation domain.
+    ///
+    /// `Z(ω^0) = 1` and `Z(ω^{i+1})` accumulates the ratio between the
+    /// identity and the sigma permutation of the `i`th row.
+    pub(crate) fn compute_permutation_vec(
+        fft: &Fft<P::ScalarField>,
+        wires: [&[P::ScalarField]; 4],
+        beta: &P::ScalarField,
+        gamma: &P::ScalarField,
+        sigma_polys: [&Polynomial<P::ScalarField>; 4],
+    ) -> Vec<P::ScalarField> {
+        let n = fft.size();
+
+        let ks = [
+            P::ScalarField::one(),
+            P::ScalarField::from(K1),
+            P::ScalarField::from(K2),
+            P::ScalarField::from(K3),
+        ];
+
+        // Transpose the sigma polynomials into their evaluation form
+        let sigma_evals = sigma_polys.map(|sigma| {
+            let mut sigma = sigma.clone();
+            fft.dft(&mut sigma);
+
+            sigma.0
+        });
+
+        // Compute the numerator and denominator of every row
+        // (w_j + β·k_j·ω^i + γ) and (w_j + β·σ_j(ω^i) + γ)
+        let (numerators, mut denominators): (Vec<_>, Vec<_>) = fft
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(i, root)| {
+                (0..4).fold(
+                    (P::ScalarField::one(), P::ScalarField::one()),
+                    |(numerator, denominator), j| {
+                        let wire = wires[j][i];
+                        (
+                            numerator * (wire + *beta * ks[j] * *root + *gamma),
+                            denominator * (wire + *beta * sigma_evals[j][i] + *gamma),
+                        )
+                    },
+                )
+            })
+            .unzip();
+
+        batch_inversion::<P>(&mut denominators);
+
+        // Accumulate the ratios starting from one
+        let mut z = Vec::with_capacity(n);
+        let mut state = P::ScalarField::one();
+        z.push(state);
+        numerators
+            .iter()
+            .zip(denominators.iter())
+            .take(n - 1)
+            .for_each(|(numerator, denominator)| {
+                state *= *numerator * *denominator;
+                z.push(state);
+            });
+
+        z
+    }
+}
+
+/// Inverts every element of the slice in place with a single field inversion
+fn batch_inversion<P: Pairing>(v: &mut [P::ScalarField]) {
+    // Montgomery's trick: accumulate the prefix products first
+    let mut acc = P::ScalarField::one();
+    let prefix = v
+        .iter()
+        .map(|x| {
+            let prev = acc;
+            acc *= *x;
+            prev
+        })
+        .collect::<Vec<_>>();
+
+    let mut inv = acc.invert().expect("permutation denominators are non-zero");
+
+    v.iter_mut().zip(prefix).rev().for_each(|(x, prev)| {
+        let tmp = inv * *x;
+        *x = inv * prev;
+        inv = tmp;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::OsRng;
+    use zero_bls12_381::Fr as BlsScalar;
+    use zero_kzg::Fft;
+    use zero_pairing::TatePairing;
+    use zkstd::behave::*;
+    use zkstd::common::{vec, Vec};
+
+    use super::{Permutation, K1, K2, K3};
+    use crate::composer::{Builder, Composer};
+    use crate::constraint_system::{Constraint, WireData};
+
+    fn builder() -> Builder<TatePairing> {
+        let mut builder = Builder::initialized(16);
+
+        let a = builder.append_witness(BlsScalar::from(3));
+        let b = builder.append_witness(BlsScalar::from(5));
+        let c = builder.append_witness(BlsScalar::from(8));
+        let d = builder.append_witness(BlsScalar::from(15));
+
+        // a + b = c
+        let constraint = Constraint::new()
+            .left(1)
+            .right(1)
+            .output(-BlsScalar::one())
+            .a(a)
+            .b(b)
+            .o(c);
+        builder.append_gate(constraint);
+
+        // a * b = d
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(-BlsScalar::one())
+            .a(a)
+            .b(b)
+            .o(d);
+        builder.append_gate(constraint);
+
+        // d + b = c + 12
+        let constraint = Constraint::new()
+            .left(1)
+            .right(1)
+            .output(-BlsScalar::one())
+            .constant(-BlsScalar::from(12))
+            .a(d)
+            .b(b)
+            .o(c);
+        builder.append_gate(constraint);
+
+        builder
+    }
+
+    fn wires(builder: &Builder<TatePairing>, n: usize) -> [Vec<BlsScalar>; 4] {
+        let mut wires = [
+            vec![BlsScalar::zero(); n],
+            vec![BlsScalar::zero(); n],
+            vec![BlsScalar::zero(); n],
+            vec![BlsScalar::zero(); n],
+        ];
+
+        builder.constraints.iter().enumerate().for_each(|(i, c)| {
+            wires[0][i] = builder[c.w_a];
+            wires[1][i] = builder[c.w_b];
+            wires[2][i] = builder[c.w_o];
+            wires[3][i] = builder[c.w_d];
+        });
+
+        wires
+    }
+
+    // Checks `Z(ω^{i+1}) * den(ω^i) = Z(ω^i) * num(ω^i)` on every row, with
+    // the last row wrapping around to `Z(ω^0) = 1`
+    fn accumulator_closes(builder: &Builder<TatePairing>, wires: &[Vec<BlsScalar>; 4]) -> bool {
+        let n = builder.constraints.len().next_power_of_two();
+        let fft = Fft::<BlsScalar>::new(n.trailing_zeros() as usize);
+        let beta = BlsScalar::random(OsRng);
+        let gamma = BlsScalar::random(OsRng);
+
+        let sigma_polys = builder.perm.compute_sigma_polynomials(n, &fft);
+        let z = Permutation::<TatePairing>::compute_permutation_vec(
+            &fft,
+            [&wires[0], &wires[1], &wires[2], &wires[3]],
+            &beta,
+            &gamma,
+            [
+                &sigma_polys[0],
+                &sigma_polys[1],
+                &sigma_polys[2],
+                &sigma_polys[3],
+            ],
+        );
+
+        let ks = [
+            BlsScalar::one(),
+            BlsScalar::from(K1),
+            BlsScalar::from(K2),
+            BlsScalar::from(K3),
+        ];
+
+        assert_eq!(z[0], BlsScalar::one());
+
+        fft.elements.iter().enumerate().all(|(i, root)| {
+            let (numerator, denominator) =
+                (0..4).fold((BlsScalar::one(), BlsScalar::one()), |(num, den), j| {
+                    let sigma = sigma_polys[j].evaluate(root);
+                    (
+                        num * (wires[j][i] + beta * ks[j] * root + gamma),
+                        den * (wires[j][i] + beta * sigma + gamma),
+                    )
+                });
+
+            z[(i + 1) % n] * denominator == z[i] * numerator
+        })
+    }
+
+    #[test]
+    fn sigma_permutations_are_cycles() {
+        let builder = builder();
+        let n = builder.constraints.len().next_power_of_two();
+        let sigmas = builder.perm.compute_sigma_permutations(n);
+
+        let next = |wire: &WireData| match wire {
+            WireData::Left(i) => sigmas[0][*i],
+            WireData::Right(i) => sigmas[1][*i],
+            WireData::Output(i) => sigmas[2][*i],
+            WireData::Fourth(i) => sigmas[3][*i],
+        };
+
+        builder
+            .perm
+            .witness_map
+            .values()
+            .filter(|wires| !wires.is_empty())
+            .for_each(|wires| {
+                let mut wire = wires[0];
+                for expected in wires.iter().cycle().skip(1).take(wires.len()) {
+                    wire = next(&wire);
+                    assert_eq!(&wire, expected);
+                }
+            });
+    }
+
+    #[test]
+    fn sigma_polynomials_interpolate_permutations() {
+        let builder = builder();
+        let n = builder.constraints.len().next_power_of_two();
+        let fft = Fft::<BlsScalar>::new(n.trailing_zeros() as usize);
+
+        let sigmas = builder.perm.compute_sigma_permutations(n);
+        let sigma_polys = builder.perm.compute_sigma_polynomials(n, &fft);
+
+        sigmas
+            .iter()
+            .zip(sigma_polys.iter())
+            .for_each(|(sigma, poly)| {
+                sigma.iter().enumerate().for_each(|(i, wire)| {
+                    let expected = match wire {
+                        WireData::Left(j) => fft.elements[*j],
+                        WireData::Right(j) => BlsScalar::from(K1) * fft.elements[*j],
+                        WireData::Output(j) => BlsScalar::from(K2) * fft.elements[*j],
+                        WireData::Fourth(j) => BlsScalar::from(K3) * fft.elements[*j],
+                    };
+
+                    assert_eq!(poly.evaluate(&fft.elements[i]), expected);
+                })
+            });
+    }
+
+    #[test]
+    fn permutation_accumulator_closes() {
+        let builder = builder();
+        let n = builder.constraints.len().next_power_of_two();
+        let wires = wires(&builder, n);
+
+        assert!(accumulator_closes(&builder, &wires));
+    }
+
+    #[test]
+    fn permutation_accumulator_detects_broken_copy() {
+        let builder = builder();
+        let n = builder.constraints.len().next_power_of_two();
+        let mut wires = wires(&builder, n);
+
+        // the left wire of the last gate is copied from the output of the
+        // previous one
+        let last = builder.constraints.len() - 1;
+        wires[0][last] += BlsScalar::one();
+
+        assert!(!accumulator_closes(&builder, &wires));
+    }
 }
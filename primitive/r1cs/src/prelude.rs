@@ -1,5 +1,17 @@
 pub use crate::composer::circuit::Circuit;
 pub use crate::composer::compiler::Compiler;
+pub use crate::composer::Builder;
 pub use crate::composer::Composer;
+pub use crate::composer::Prover;
+pub use crate::composer::Verifier;
+pub use crate::composer::{CircuitReport, GateDiagnostics};
+pub use crate::composer::{PublicInput, PublicInputLayout, PublicInputValues, PublicInputs};
+pub use crate::constraint_system::{
+    Constraint, CustomGate, CustomSelector, LookupTable, Wire, Witness, WitnessPoint,
+};
 pub use crate::error::Error;
+pub use crate::proof_system::{Proof, VerifierData};
+pub use crate::transcript::Transcript;
 pub use zero_bls12_381::Fr as BlsScalar;
+pub use zero_jubjub::{Fp as JubjubScalar, JubjubAffine, JubjubExtended};
+pub use zero_r1cs_derive::Circuit;
@@ -0,0 +1,108 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Byte encoding shared by the proofs and the verifier data
+//!
+//! - points are 48 bytes in the compressed form of `SigUtils<48>`, which
+//!   rejects the points out of the curve or of the prime order subgroup
+//! - scalars are 32 bytes in the little endian form of `SigUtils<32>`, which
+//!   rejects the non canonical ones
+//! - lengths and sizes are little endian `u32` and `u64`
+
+use zero_kzg::Commitment;
+use zkstd::common::{Pairing, SigUtils, Vec};
+
+use crate::error::Error;
+
+/// Size of an encoded point
+pub(crate) const POINT_SIZE: usize = 48;
+/// Size of an encoded scalar
+pub(crate) const SCALAR_SIZE: usize = 32;
+
+pub(crate) fn write_commitment<P: Pairing>(bytes: &mut Vec<u8>, commitment: &Commitment<P>) {
+    bytes.extend_from_slice(&commitment.0.to_bytes());
+}
+
+pub(crate) fn write_scalar<P: Pairing>(bytes: &mut Vec<u8>, scalar: &P::ScalarField) {
+    bytes.extend_from_slice(&scalar.to_bytes());
+}
+
+pub(crate) fn write_u32(bytes: &mut Vec<u8>, value: usize) {
+    bytes.extend_from_slice(&(value as u32).to_le_bytes());
+}
+
+/// Reads the encoded values from the front of a slice
+pub(crate) struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    fn read<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        if self.0.len() < N {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let (head, tail) = self.0.split_at(N);
+        self.0 = tail;
+
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(head);
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn commitment<P: Pairing>(&mut self) -> Result<Commitment<P>, Error> {
+        P::G1Affine::from_bytes(self.read()?)
+            .map(Commitment)
+            .ok_or(Error::PointMalformed)
+    }
+
+    pub(crate) fn scalar<P: Pairing>(&mut self) -> Result<P::ScalarField, Error> {
+        P::ScalarField::from_bytes(self.read()?).ok_or(Error::BlsScalarMalformed)
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(Error::NotEnoughBytes);
+        }
+
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+
+        Ok(head)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, Error> {
+        self.read::<1>().map(|[byte]| byte)
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<usize, Error> {
+        self.read().map(|bytes| u32::from_le_bytes(bytes) as usize)
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<usize, Error> {
+        self.read().map(|bytes| u64::from_le_bytes(bytes) as usize)
+    }
+
+    /// Check every byte was read
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        match self.0.len() {
+            0 => Ok(()),
+            _ => Err(Error::TrailingBytes),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use zero_kzg::Polynomial;
+use zkstd::common::{FftField, Pairing, PrimeField, Ring};
+
+use crate::proof_system::preprocess::ProverKey;
+use crate::proof_system::proof::ProofEvaluations;
+use crate::proof_system::widget::permutation::compute_first_lagrange_evaluation;
+
+/// Compute the linearization polynomial `r(X)` along with the evaluations
+/// sent in the proof
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn compute<P: Pairing>(
+    group_generator: &P::ScalarField,
+    prover_key: &ProverKey<P>,
+    (alpha, beta, gamma, z_challenge): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (zeta, delta, epsilon, lookup_challenge): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (range_challenge, logic_challenge, fixed_base_challenge, var_base_challenge): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    custom_challenge: &P::ScalarField,
+    [a_poly, b_poly, o_poly, d_poly]: [&Polynomial<P::ScalarField>; 4],
+    [z_poly, p_poly]: [&Polynomial<P::ScalarField>; 2],
+    [f_poly, h_1_poly, h_2_poly, table_poly]: [&Polynomial<P::ScalarField>; 4],
+    [t_low_poly, t_mid_poly, t_high_poly, t_4_poly]: [&Polynomial<P::ScalarField>; 4],
+) -> (Polynomial<P::ScalarField>, ProofEvaluations<P>) {
+    let n = prover_key.n;
+    let omega_z = *z_challenge * group_generator;
+
+    // Compute evaluations
+    let a_eval = a_poly.evaluate(z_challenge);
+    let b_eval = b_poly.evaluate(z_challenge);
+    let o_eval = o_poly.evaluate(z_challenge);
+    let d_eval = d_poly.evaluate(z_challenge);
+
+    let a_next_eval = a_poly.evaluate(&omega_z);
+    let b_next_eval = b_poly.evaluate(&omega_z);
+    let d_next_eval = d_poly.evaluate(&omega_z);
+
+    let s_sigma_1_eval = prover_key.permutation.s_sigma_1.0.evaluate(z_challenge);
+    let s_sigma_2_eval = prover_key.permutation.s_sigma_2.0.evaluate(z_challenge);
+    let s_sigma_3_eval = prover_key.permutation.s_sigma_3.0.evaluate(z_challenge);
+
+    let q_arith_eval = prover_key.arithmetic.q_arith.0.evaluate(z_challenge);
+    let q_c_eval = prover_key.arithmetic.q_c.0.evaluate(z_challenge);
+    let q_l_eval = prover_key.arithmetic.q_l.0.evaluate(z_challenge);
+    let q_r_eval = prover_key.arithmetic.q_r.0.evaluate(z_challenge);
+
+    let perm_eval = z_poly.evaluate(&omega_z);
+
+    let f_eval = f_poly.evaluate(z_challenge);
+    let table_eval = table_poly.evaluate(z_challenge);
+    let table_next_eval = table_poly.evaluate(&omega_z);
+    let h_1_eval = h_1_poly.evaluate(z_challenge);
+    let h_1_next_eval = h_1_poly.evaluate(&omega_z);
+    let h_2_eval = h_2_poly.evaluate(z_challenge);
+    let lookup_perm_eval = p_poly.evaluate(&omega_z);
+
+    let evaluations = ProofEvaluations {
+        a_eval,
+        b_eval,
+        o_eval,
+        d_eval,
+        a_next_eval,
+        b_next_eval,
+        d_next_eval,
+        q_arith_eval,
+        q_c_eval,
+        q_l_eval,
+        q_r_eval,
+        s_sigma_1_eval,
+        s_sigma_2_eval,
+        s_sigma_3_eval,
+        perm_eval,
+        f_eval,
+        table_eval,
+        table_next_eval,
+        h_1_eval,
+        h_1_next_eval,
+        h_2_eval,
+        lookup_perm_eval,
+    };
+
+    let gate_constraints = [
+        prover_key
+            .range
+            .compute_linearization(range_challenge, &evaluations),
+        prover_key
+            .logic
+            .compute_linearization(logic_challenge, &evaluations),
+        prover_key
+            .fixed_base
+            .compute_linearization(fixed_base_challenge, &evaluations),
+        prover_key
+            .variable_base
+            .compute_linearization(var_base_challenge, &evaluations),
+    ]
+    .iter()
+    .fold(
+        prover_key
+            .arithmetic
+            .compute_linearization((&a_eval, &b_eval, &o_eval, &d_eval), &q_arith_eval),
+        |acc, poly| &acc + poly,
+    );
+
+    let (gate_constraints, _) =
+        prover_key
+            .custom
+            .iter()
+            .fold((gate_constraints, *custom_challenge), |(acc, sep), gate| {
+                let poly = gate.compute_linearization(&sep, &evaluations);
+
+                (&acc + &poly, sep * custom_challenge)
+            });
+
+    let permutation = prover_key.permutation.compute_linearization(
+        n,
+        z_challenge,
+        (alpha, beta, gamma),
+        (&a_eval, &b_eval, &o_eval, &d_eval),
+        (&s_sigma_1_eval, &s_sigma_2_eval, &s_sigma_3_eval),
+        &perm_eval,
+        z_poly,
+    );
+
+    let lookup = prover_key.lookup.compute_linearization(
+        lookup_challenge,
+        (zeta, delta, epsilon),
+        &compute_first_lagrange_evaluation::<P>(n, z_challenge),
+        &evaluations,
+        p_poly,
+    );
+
+    // Z_H(z) * (t_low(X) + z^n * t_mid(X) + z^{2n} * t_high(X) + z^{3n} *
+    // t_4(X))
+    let z_n = z_challenge.pow(n as u64);
+    let z_two_n = z_n.square();
+    let z_three_n = z_two_n * z_n;
+    let vanishing_eval = z_n - P::ScalarField::one();
+
+    let quotient_term = t_low_poly
+        + &(&(t_mid_poly * &z_n) + &(&(t_high_poly * &z_two_n) + &(t_4_poly * &z_three_n)));
+    let quotient_term = &quotient_term * &-vanishing_eval;
+
+    let r_poly = &(&(&gate_constraints + &permutation) + &lookup) + &quotient_term;
+
+    (r_poly, evaluations)
+}
@@ -0,0 +1,550 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Methods to preprocess the constraint system for use in a proof
+
+use parity_scale_codec::alloc::borrow::Cow;
+use parity_scale_codec::{Decode, Encode, Input, Output};
+use zero_kzg::{Commitment, Fft, KeyPair, Polynomial};
+use zkstd::common::{vec, CurveGroup, Group, Pairing, Ring, Vec};
+
+use crate::composer::{Builder, PublicInput, PublicInputLayout};
+use crate::constraint_system::custom::Term;
+use crate::constraint_system::{CustomGate, CustomSelector};
+use crate::error::Error;
+use crate::proof_system::bytes::{self, Reader};
+use crate::proof_system::widget::ecc::{curve_addition, fixed_base};
+use crate::proof_system::widget::{arithmetic, custom, logic, lookup, permutation, range};
+use crate::transcript::Transcript;
+
+/// Selector and sigma polynomials of a preprocessed circuit
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    /// Circuit size
+    pub(crate) n: usize,
+    /// ProverKey for arithmetic gate
+    pub(crate) arithmetic: arithmetic::ProverKey<P>,
+    /// ProverKey for range gates
+    pub(crate) range: range::ProverKey<P>,
+    /// ProverKey for logic gates
+    pub(crate) logic: logic::ProverKey<P>,
+    /// ProverKey for fixed base curve addition gates
+    pub(crate) fixed_base: fixed_base::ProverKey<P>,
+    /// ProverKey for variable base curve addition gates
+    pub(crate) variable_base: curve_addition::ProverKey<P>,
+    /// ProverKey for lookup gates
+    pub(crate) lookup: lookup::ProverKey<P>,
+    /// ProverKeys for custom gates in the registration order
+    pub(crate) custom: Vec<custom::ProverKey<P>>,
+    /// ProverKey for permutation checks
+    pub(crate) permutation: permutation::ProverKey<P>,
+}
+
+impl<P: Pairing> ProverKey<P> {
+    /// Interpolate the selectors of the gates of `builder` and the sigma
+    /// permutations over a domain of size `n`
+    pub(crate) fn new(builder: &Builder<P>, n: usize) -> Self {
+        let k = n.trailing_zeros() as usize;
+        let fft = Fft::<P::ScalarField>::new(k);
+        let fft_8n = Fft::<P::ScalarField>::new(k + 3);
+
+        let mut q_m = vec![P::ScalarField::zero(); n];
+        let mut q_l = vec![P::ScalarField::zero(); n];
+        let mut q_r = vec![P::ScalarField::zero(); n];
+        let mut q_o = vec![P::ScalarField::zero(); n];
+        let mut q_d = vec![P::ScalarField::zero(); n];
+        let mut q_c = vec![P::ScalarField::zero(); n];
+        let mut q_arith = vec![P::ScalarField::zero(); n];
+        let mut q_range = vec![P::ScalarField::zero(); n];
+        let mut q_logic = vec![P::ScalarField::zero(); n];
+        let mut q_fixed_group_add = vec![P::ScalarField::zero(); n];
+        let mut q_variable_group_add = vec![P::ScalarField::zero(); n];
+        let mut q_lookup = vec![P::ScalarField::zero(); n];
+
+        builder.constraints.iter().enumerate().for_each(|(i, c)| {
+            q_m[i] = c.q_m;
+            q_l[i] = c.q_l;
+            q_r[i] = c.q_r;
+            q_o[i] = c.q_o;
+            q_d[i] = c.q_d;
+            q_c[i] = c.q_c;
+            q_arith[i] = c.q_arith;
+            q_range[i] = c.q_range;
+            q_logic[i] = c.q_logic;
+            q_fixed_group_add[i] = c.q_fixed_group_add;
+            q_variable_group_add[i] = c.q_variable_group_add;
+            q_lookup[i] = c.q_lookup;
+        });
+
+        // selector polynomial and its evaluations over the 8n coset
+        let selector = |evals: Vec<P::ScalarField>| {
+            let mut poly = Polynomial::new(evals);
+            fft.idft(&mut poly);
+            let poly = Polynomial::from_coefficients_vec(poly.0);
+
+            let mut coset = poly.clone();
+            fft_8n.coset_dft(&mut coset);
+
+            (poly, coset.0)
+        };
+
+        let arithmetic = arithmetic::ProverKey {
+            q_m: selector(q_m),
+            q_l: selector(q_l),
+            q_r: selector(q_r),
+            q_o: selector(q_o),
+            q_d: selector(q_d),
+            q_c: selector(q_c),
+            q_arith: selector(q_arith),
+        };
+
+        let range = range::ProverKey {
+            q_range: selector(q_range),
+        };
+
+        // the constant selector picks the logic operation of the gate
+        let logic = logic::ProverKey {
+            q_c: arithmetic.q_c.clone(),
+            q_logic: selector(q_logic),
+        };
+
+        // the wnaf rounds hold the coordinates of the fixed base point in the
+        // left, right and constant selectors
+        let fixed_base = fixed_base::ProverKey {
+            q_l: arithmetic.q_l.clone(),
+            q_r: arithmetic.q_r.clone(),
+            q_c: arithmetic.q_c.clone(),
+            q_fixed_group_add: selector(q_fixed_group_add),
+        };
+
+        let variable_base = curve_addition::ProverKey {
+            q_variable_group_add: selector(q_variable_group_add),
+        };
+
+        // the table is padded with its last row, which the queries of the
+        // gates other than the lookup ones hold as well
+        let mut table = builder.lookup_table.rows().to_vec();
+        let padding = table.last().copied().unwrap_or([P::ScalarField::zero(); 3]);
+        table.resize(n, padding);
+
+        let column = |j: usize| selector(table.iter().map(|row| row[j]).collect());
+        let lookup = lookup::ProverKey {
+            q_lookup: selector(q_lookup),
+            table_1: column(0),
+            table_2: column(1),
+            table_3: column(2),
+            table,
+        };
+
+        let custom = builder
+            .custom_gates
+            .iter()
+            .enumerate()
+            .map(|(k, gate)| {
+                let mut q_custom = vec![P::ScalarField::zero(); n];
+                builder
+                    .constraints
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| c.q_custom == Some(CustomSelector(k)))
+                    .for_each(|(i, _)| q_custom[i] = P::ScalarField::one());
+
+                custom::ProverKey {
+                    terms: gate.terms.clone(),
+                    q_custom: selector(q_custom),
+                }
+            })
+            .collect();
+
+        let [s_sigma_1, s_sigma_2, s_sigma_3, s_sigma_4] =
+            builder.perm.compute_sigma_polynomials(n, &fft);
+
+        let coset = |poly: Polynomial<P::ScalarField>| {
+            let mut evals = poly.clone();
+            fft_8n.coset_dft(&mut evals);
+
+            (poly, evals.0)
+        };
+
+        // evaluations of the linear polynomial `X` over the 8n coset
+        let mut linear_evaluations =
+            Polynomial::new(vec![P::ScalarField::zero(), P::ScalarField::one()]);
+        fft_8n.coset_dft(&mut linear_evaluations);
+
+        let permutation = permutation::ProverKey {
+            s_sigma_1: coset(s_sigma_1),
+            s_sigma_2: coset(s_sigma_2),
+            s_sigma_3: coset(s_sigma_3),
+            s_sigma_4: coset(s_sigma_4),
+            linear_evaluations: linear_evaluations.0,
+        };
+
+        Self {
+            n,
+            arithmetic,
+            range,
+            logic,
+            fixed_base,
+            variable_base,
+            lookup,
+            custom,
+            permutation,
+        }
+    }
+
+    /// Commit to the selector and sigma polynomials with the commit key of
+    /// `keypair`
+    pub(crate) fn verifier_data(
+        &self,
+        keypair: &KeyPair<P>,
+        public_input_indexes: Vec<usize>,
+        public_input_layout: PublicInputLayout,
+    ) -> Result<VerifierData<P>, Error> {
+        let arithmetic = arithmetic::VerifierKey {
+            q_m: commit(keypair, &self.arithmetic.q_m.0)?,
+            q_l: commit(keypair, &self.arithmetic.q_l.0)?,
+            q_r: commit(keypair, &self.arithmetic.q_r.0)?,
+            q_o: commit(keypair, &self.arithmetic.q_o.0)?,
+            q_d: commit(keypair, &self.arithmetic.q_d.0)?,
+            q_c: commit(keypair, &self.arithmetic.q_c.0)?,
+            q_arith: commit(keypair, &self.arithmetic.q_arith.0)?,
+        };
+
+        let range = range::VerifierKey {
+            q_range: commit(keypair, &self.range.q_range.0)?,
+        };
+
+        let logic = logic::VerifierKey {
+            q_logic: commit(keypair, &self.logic.q_logic.0)?,
+        };
+
+        let fixed_base = fixed_base::VerifierKey {
+            q_fixed_group_add: commit(keypair, &self.fixed_base.q_fixed_group_add.0)?,
+        };
+
+        let variable_base = curve_addition::VerifierKey {
+            q_variable_group_add: commit(keypair, &self.variable_base.q_variable_group_add.0)?,
+        };
+
+        let lookup = lookup::VerifierKey {
+            q_lookup: commit(keypair, &self.lookup.q_lookup.0)?,
+            table_1: commit(keypair, &self.lookup.table_1.0)?,
+            table_2: commit(keypair, &self.lookup.table_2.0)?,
+            table_3: commit(keypair, &self.lookup.table_3.0)?,
+        };
+
+        let custom = self
+            .custom
+            .iter()
+            .map(|gate| {
+                Ok(custom::VerifierKey {
+                    terms: gate.terms.clone(),
+                    q_custom: commit(keypair, &gate.q_custom.0)?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let permutation = permutation::VerifierKey {
+            s_sigma_1: commit(keypair, &self.permutation.s_sigma_1.0)?,
+            s_sigma_2: commit(keypair, &self.permutation.s_sigma_2.0)?,
+            s_sigma_3: commit(keypair, &self.permutation.s_sigma_3.0)?,
+            s_sigma_4: commit(keypair, &self.permutation.s_sigma_4.0)?,
+        };
+
+        Ok(VerifierData {
+            n: self.n,
+            arithmetic,
+            range,
+            logic,
+            fixed_base,
+            variable_base,
+            lookup,
+            custom,
+            permutation,
+            public_input_indexes,
+            public_input_layout,
+        })
+    }
+}
+
+/// Selector commitments and public input layout of a preprocessed circuit
+///
+/// This is everything needed to verify a proof of the circuit. It is encoded
+/// with 48 bytes compressed points, 32 bytes little endian scalars and little
+/// endian integers as
+///
+/// | Bytes     | Content                                                   |
+/// |-----------|-----------------------------------------------------------|
+/// | 8         | circuit size `n` as `u64`                                 |
+/// | 19 * 48   | selector and sigma commitments                            |
+/// | 4 + 4 * k | `k` as `u32` followed by the public input indexes as `u32`|
+/// | 4 + ...   | number of custom gates as `u32` followed by each gate     |
+/// | 4 + ...   | number of declared public inputs as `u32` followed by each|
+///
+/// with the commitments in the `q_m`, `q_l`, `q_r`, `q_o`, `q_d`, `q_c`,
+/// `q_arith`, `q_range`, `q_logic`, `q_fixed_group_add`,
+/// `q_variable_group_add`, `q_lookup`, `table_1`, `table_2`, `table_3`,
+/// `s_sigma_1`, `s_sigma_2`, `s_sigma_3`, `s_sigma_4` order. A custom gate is
+/// its selector commitment, its number of terms as `u32` and each term as its
+/// coefficient followed by the 7 bytes exponents of the wires. A declared
+/// public input is `0` for a scalar or `1` for a point as `u8`, followed by
+/// the length of its name as `u32` and its UTF-8 name.
+///
+/// Its SCALE encoding is these bytes as a `Vec<u8>`, and the decoding
+/// rejects the points out of the prime order subgroup.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VerifierData<P: Pairing> {
+    /// Circuit size
+    pub(crate) n: usize,
+    /// VerifierKey for arithmetic gate
+    pub(crate) arithmetic: arithmetic::VerifierKey<P>,
+    /// VerifierKey for range gates
+    pub(crate) range: range::VerifierKey<P>,
+    /// VerifierKey for logic gates
+    pub(crate) logic: logic::VerifierKey<P>,
+    /// VerifierKey for fixed base curve addition gates
+    pub(crate) fixed_base: fixed_base::VerifierKey<P>,
+    /// VerifierKey for variable base curve addition gates
+    pub(crate) variable_base: curve_addition::VerifierKey<P>,
+    /// VerifierKey for lookup gates
+    pub(crate) lookup: lookup::VerifierKey<P>,
+    /// VerifierKeys for custom gates in the registration order
+    pub(crate) custom: Vec<custom::VerifierKey<P>>,
+    /// VerifierKey for permutation checks
+    pub(crate) permutation: permutation::VerifierKey<P>,
+    /// Sparse representation of the public inputs
+    pub(crate) public_input_indexes: Vec<usize>,
+    /// Public inputs declared by the circuit
+    pub(crate) public_input_layout: PublicInputLayout,
+}
+
+impl<P: Pairing> VerifierData<P> {
+    /// Circuit size
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Hash of the encoded verifier data, which the transcript of the
+    /// circuit is bound to
+    pub fn hash<T: Transcript<P>>(&self) -> P::ScalarField {
+        let mut transcript = T::new(b"verifier_data");
+        transcript.append_message(b"bytes", &self.to_bytes());
+
+        transcript.challenge_scalar(b"hash")
+    }
+
+    /// Indexes of the gates holding a public input
+    pub fn public_input_indexes(&self) -> &[usize] {
+        &self.public_input_indexes
+    }
+
+    /// Public inputs declared by the circuit
+    pub fn public_input_layout(&self) -> &PublicInputLayout {
+        &self.public_input_layout
+    }
+
+    fn commitments(&self) -> [&Commitment<P>; 19] {
+        [
+            &self.arithmetic.q_m,
+            &self.arithmetic.q_l,
+            &self.arithmetic.q_r,
+            &self.arithmetic.q_o,
+            &self.arithmetic.q_d,
+            &self.arithmetic.q_c,
+            &self.arithmetic.q_arith,
+            &self.range.q_range,
+            &self.logic.q_logic,
+            &self.fixed_base.q_fixed_group_add,
+            &self.variable_base.q_variable_group_add,
+            &self.lookup.q_lookup,
+            &self.lookup.table_1,
+            &self.lookup.table_2,
+            &self.lookup.table_3,
+            &self.permutation.s_sigma_1,
+            &self.permutation.s_sigma_2,
+            &self.permutation.s_sigma_3,
+            &self.permutation.s_sigma_4,
+        ]
+    }
+
+    /// Serialize the verifier data
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&(self.n as u64).to_le_bytes());
+        self.commitments()
+            .iter()
+            .for_each(|commitment| bytes::write_commitment(&mut bytes, commitment));
+
+        bytes::write_u32(&mut bytes, self.public_input_indexes.len());
+        self.public_input_indexes
+            .iter()
+            .for_each(|index| bytes::write_u32(&mut bytes, *index));
+
+        bytes::write_u32(&mut bytes, self.custom.len());
+        self.custom.iter().for_each(|gate| {
+            bytes::write_commitment(&mut bytes, &gate.q_custom);
+            bytes::write_u32(&mut bytes, gate.terms.len());
+            gate.terms.iter().for_each(|term| {
+                bytes::write_scalar::<P>(&mut bytes, &term.coeff);
+                bytes.extend_from_slice(&term.powers);
+            });
+        });
+
+        bytes::write_u32(&mut bytes, self.public_input_layout.inputs().len());
+        self.public_input_layout.inputs().iter().for_each(|input| {
+            let kind = match input {
+                PublicInput::Scalar(_) => 0,
+                PublicInput::Point(_) => 1,
+            };
+            bytes.push(kind);
+            bytes::write_u32(&mut bytes, input.name().len());
+            bytes.extend_from_slice(input.name().as_bytes());
+        });
+
+        bytes
+    }
+
+    /// Deserialize the verifier data
+    ///
+    /// Returns an error if a point or a scalar is malformed, if the bytes
+    /// are too short or too long, if the size of the circuit isn't a power
+    /// of two holding the public input gates, if the public input indexes
+    /// aren't strictly increasing, or if a declared public input is neither a
+    /// scalar nor a point or its name isn't UTF-8.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+
+        let n = reader.u64()?;
+        let arithmetic = arithmetic::VerifierKey {
+            q_m: reader.commitment()?,
+            q_l: reader.commitment()?,
+            q_r: reader.commitment()?,
+            q_o: reader.commitment()?,
+            q_d: reader.commitment()?,
+            q_c: reader.commitment()?,
+            q_arith: reader.commitment()?,
+        };
+        let range = range::VerifierKey {
+            q_range: reader.commitment()?,
+        };
+        let logic = logic::VerifierKey {
+            q_logic: reader.commitment()?,
+        };
+        let fixed_base = fixed_base::VerifierKey {
+            q_fixed_group_add: reader.commitment()?,
+        };
+        let variable_base = curve_addition::VerifierKey {
+            q_variable_group_add: reader.commitment()?,
+        };
+        let lookup = lookup::VerifierKey {
+            q_lookup: reader.commitment()?,
+            table_1: reader.commitment()?,
+            table_2: reader.commitment()?,
+            table_3: reader.commitment()?,
+        };
+        let permutation = permutation::VerifierKey {
+            s_sigma_1: reader.commitment()?,
+            s_sigma_2: reader.commitment()?,
+            s_sigma_3: reader.commitment()?,
+            s_sigma_4: reader.commitment()?,
+        };
+
+        let public_input_indexes = (0..reader.u32()?)
+            .map(|_| reader.u32())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let custom = (0..reader.u32()?)
+            .map(|_| {
+                let q_custom = reader.commitment()?;
+                let terms = (0..reader.u32()?)
+                    .map(|_| {
+                        let coeff = reader.scalar::<P>()?;
+                        let mut powers = [0; 7];
+                        for power in powers.iter_mut() {
+                            *power = reader.u8()?;
+                        }
+
+                        Ok(Term { coeff, powers })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                Ok(custom::VerifierKey { terms, q_custom })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut public_input_layout = PublicInputLayout::default();
+        for _ in 0..reader.u32()? {
+            let kind = reader.u8()?;
+            let len = reader.u32()?;
+            let name = core::str::from_utf8(reader.bytes(len)?)
+                .map_err(|_| Error::InvalidVerifierDataBytes)?;
+
+            public_input_layout.push(match kind {
+                0 => PublicInput::Scalar(Cow::Owned(name.into())),
+                1 => PublicInput::Point(Cow::Owned(name.into())),
+                _ => return Err(Error::InvalidVerifierDataBytes),
+            });
+        }
+
+        reader.finish()?;
+
+        let degree = |term: &Term<P>| term.powers.iter().map(|e| *e as usize).sum::<usize>();
+        if !n.is_power_of_two()
+            || public_input_indexes.iter().any(|index| *index >= n)
+            || !public_input_indexes.windows(2).all(|w| w[0] < w[1])
+            || custom
+                .iter()
+                .flat_map(|gate| gate.terms.iter())
+                .any(|term| degree(term) > CustomGate::<P>::MAX_DEGREE)
+        {
+            return Err(Error::InvalidVerifierDataBytes);
+        }
+
+        Ok(Self {
+            n,
+            arithmetic,
+            range,
+            logic,
+            fixed_base,
+            variable_base,
+            lookup,
+            custom,
+            permutation,
+            public_input_indexes,
+            public_input_layout,
+        })
+    }
+}
+
+impl<P: Pairing> Encode for VerifierData<P> {
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.to_bytes().encode_to(dest)
+    }
+}
+
+impl<P: Pairing> Decode for VerifierData<P> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+        let bytes = Vec::<u8>::decode(input)?;
+
+        Self::from_bytes(&bytes).map_err(|_| "invalid verifier data bytes".into())
+    }
+}
+
+// The commit key rejects polynomials of degree zero, which happens for
+// selectors that are unused or constant over the whole domain
+fn commit<P: Pairing>(
+    keypair: &KeyPair<P>,
+    poly: &Polynomial<P::ScalarField>,
+) -> Result<Commitment<P>, Error> {
+    match poly.len() {
+        0 => Ok(Commitment::default()),
+        1 => Ok(Commitment::new(
+            P::G1Projective::ADDITIVE_GENERATOR * poly[0],
+        )),
+        _ => Ok(keypair.commit(poly)?),
+    }
+}
@@ -0,0 +1,688 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! A Proof stores the commitments to all of the elements that are needed to
+//! univocally identify a prove of some statement.
+
+use parity_scale_codec::{Decode, Encode, Input, Output};
+use zero_kzg::Commitment;
+use zero_pairing::msm_variable_base;
+use zkstd::common::{
+    vec, CurveGroup, FftField, Group, Pairing, PairingRange, PrimeField, Ring, Vec,
+};
+
+use crate::error::Error;
+use crate::proof_system::bytes::{self, Reader, POINT_SIZE, SCALAR_SIZE};
+use crate::proof_system::preprocess::VerifierData;
+use crate::proof_system::widget::lookup;
+use crate::proof_system::widget::permutation::compute_first_lagrange_evaluation;
+use crate::transcript::Transcript;
+
+/// A Proof is a composition of `Commitment`s to the Witness, Permutation,
+/// Quotient, Shifted and Opening polynomials as well as the
+/// `ProofEvaluations`.
+///
+/// The proof is encoded in [`Proof::SIZE`] bytes, first the commitments
+/// as 48 bytes compressed points in the `a`, `b`, `o`, `d`, `f`, `h_1`,
+/// `h_2`, `z`, `p`, `t_low`, `t_mid`, `t_high`, `t_4`, `w_z`, `w_zw`
+/// order, then the evaluations as 32 bytes little endian scalars in the
+/// order of their declaration. Its SCALE encoding is the same bytes, and
+/// the decoding rejects the points out of the prime order subgroup.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Proof<P: Pairing> {
+    /// Commitment to the witness polynomial for the left wires.
+    pub(crate) a_comm: Commitment<P>,
+    /// Commitment to the witness polynomial for the right wires.
+    pub(crate) b_comm: Commitment<P>,
+    /// Commitment to the witness polynomial for the output wires.
+    pub(crate) o_comm: Commitment<P>,
+    /// Commitment to the witness polynomial for the fourth wires.
+    pub(crate) d_comm: Commitment<P>,
+
+    /// Commitment to the lookup query polynomial.
+    pub(crate) f_comm: Commitment<P>,
+    /// Commitment to the first half of the sorted lookup polynomial.
+    pub(crate) h_1_comm: Commitment<P>,
+    /// Commitment to the second half of the sorted lookup polynomial.
+    pub(crate) h_2_comm: Commitment<P>,
+
+    /// Commitment to the permutation polynomial.
+    pub(crate) z_comm: Commitment<P>,
+    /// Commitment to the lookup grand product polynomial.
+    pub(crate) p_comm: Commitment<P>,
+
+    /// Commitment to the quotient polynomial.
+    pub(crate) t_low_comm: Commitment<P>,
+    /// Commitment to the quotient polynomial.
+    pub(crate) t_mid_comm: Commitment<P>,
+    /// Commitment to the quotient polynomial.
+    pub(crate) t_high_comm: Commitment<P>,
+    /// Commitment to the quotient polynomial.
+    pub(crate) t_4_comm: Commitment<P>,
+
+    /// Commitment to the opening polynomial.
+    pub(crate) w_z_chall_comm: Commitment<P>,
+    /// Commitment to the shifted opening polynomial.
+    pub(crate) w_z_chall_w_comm: Commitment<P>,
+
+    /// Subset of all of the evaluations added to the proof.
+    pub(crate) evaluations: ProofEvaluations<P>,
+}
+
+/// Subset of the evaluations of the polynomials at the evaluation challenge
+/// `z` which are sent along the proof.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) struct ProofEvaluations<P: Pairing> {
+    // Evaluation of the witness polynomial for the left wire at `z`
+    pub(crate) a_eval: P::ScalarField,
+    // Evaluation of the witness polynomial for the right wire at `z`
+    pub(crate) b_eval: P::ScalarField,
+    // Evaluation of the witness polynomial for the output wire at `z`
+    pub(crate) o_eval: P::ScalarField,
+    // Evaluation of the witness polynomial for the fourth wire at `z`
+    pub(crate) d_eval: P::ScalarField,
+
+    // Evaluation of the witness polynomial for the left wire at `z * omega`
+    pub(crate) a_next_eval: P::ScalarField,
+    // Evaluation of the witness polynomial for the right wire at `z * omega`
+    pub(crate) b_next_eval: P::ScalarField,
+    // Evaluation of the witness polynomial for the fourth wire at `z * omega`
+    pub(crate) d_next_eval: P::ScalarField,
+
+    // Evaluation of the arithmetic selector polynomial at `z`
+    pub(crate) q_arith_eval: P::ScalarField,
+    // Evaluation of the constant selector polynomial at `z`
+    pub(crate) q_c_eval: P::ScalarField,
+    // Evaluation of the left selector polynomial at `z`
+    pub(crate) q_l_eval: P::ScalarField,
+    // Evaluation of the right selector polynomial at `z`
+    pub(crate) q_r_eval: P::ScalarField,
+
+    // Evaluation of the left sigma polynomial at `z`
+    pub(crate) s_sigma_1_eval: P::ScalarField,
+    // Evaluation of the right sigma polynomial at `z`
+    pub(crate) s_sigma_2_eval: P::ScalarField,
+    // Evaluation of the out sigma polynomial at `z`
+    pub(crate) s_sigma_3_eval: P::ScalarField,
+
+    // Evaluation of the permutation polynomial at `z * omega`
+    pub(crate) perm_eval: P::ScalarField,
+
+    // Evaluation of the lookup query polynomial at `z`
+    pub(crate) f_eval: P::ScalarField,
+    // Evaluation of the compressed table polynomial at `z`
+    pub(crate) table_eval: P::ScalarField,
+    // Evaluation of the compressed table polynomial at `z * omega`
+    pub(crate) table_next_eval: P::ScalarField,
+    // Evaluation of the first half of the sorted lookup polynomial at `z`
+    pub(crate) h_1_eval: P::ScalarField,
+    // Evaluation of the first half of the sorted lookup polynomial at `z *
+    // omega`
+    pub(crate) h_1_next_eval: P::ScalarField,
+    // Evaluation of the second half of the sorted lookup polynomial at `z`
+    pub(crate) h_2_eval: P::ScalarField,
+    // Evaluation of the lookup grand product polynomial at `z * omega`
+    pub(crate) lookup_perm_eval: P::ScalarField,
+}
+
+/// Number of commitments in the proof
+const COMMITMENTS: usize = 15;
+/// Number of evaluations sent along the proof
+const EVALUATIONS: usize = 22;
+
+impl<P: Pairing> ProofEvaluations<P> {
+    fn scalars(&self) -> [&P::ScalarField; EVALUATIONS] {
+        [
+            &self.a_eval,
+            &self.b_eval,
+            &self.o_eval,
+            &self.d_eval,
+            &self.a_next_eval,
+            &self.b_next_eval,
+            &self.d_next_eval,
+            &self.q_arith_eval,
+            &self.q_c_eval,
+            &self.q_l_eval,
+            &self.q_r_eval,
+            &self.s_sigma_1_eval,
+            &self.s_sigma_2_eval,
+            &self.s_sigma_3_eval,
+            &self.perm_eval,
+            &self.f_eval,
+            &self.table_eval,
+            &self.table_next_eval,
+            &self.h_1_eval,
+            &self.h_1_next_eval,
+            &self.h_2_eval,
+            &self.lookup_perm_eval,
+        ]
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, Error> {
+        Ok(Self {
+            a_eval: reader.scalar::<P>()?,
+            b_eval: reader.scalar::<P>()?,
+            o_eval: reader.scalar::<P>()?,
+            d_eval: reader.scalar::<P>()?,
+            a_next_eval: reader.scalar::<P>()?,
+            b_next_eval: reader.scalar::<P>()?,
+            d_next_eval: reader.scalar::<P>()?,
+            q_arith_eval: reader.scalar::<P>()?,
+            q_c_eval: reader.scalar::<P>()?,
+            q_l_eval: reader.scalar::<P>()?,
+            q_r_eval: reader.scalar::<P>()?,
+            s_sigma_1_eval: reader.scalar::<P>()?,
+            s_sigma_2_eval: reader.scalar::<P>()?,
+            s_sigma_3_eval: reader.scalar::<P>()?,
+            perm_eval: reader.scalar::<P>()?,
+            f_eval: reader.scalar::<P>()?,
+            table_eval: reader.scalar::<P>()?,
+            table_next_eval: reader.scalar::<P>()?,
+            h_1_eval: reader.scalar::<P>()?,
+            h_1_next_eval: reader.scalar::<P>()?,
+            h_2_eval: reader.scalar::<P>()?,
+            lookup_perm_eval: reader.scalar::<P>()?,
+        })
+    }
+}
+
+impl<P: Pairing> Proof<P> {
+    /// Size of the encoded proof
+    pub const SIZE: usize = COMMITMENTS * POINT_SIZE + EVALUATIONS * SCALAR_SIZE;
+
+    fn commitments(&self) -> [&Commitment<P>; COMMITMENTS] {
+        [
+            &self.a_comm,
+            &self.b_comm,
+            &self.o_comm,
+            &self.d_comm,
+            &self.f_comm,
+            &self.h_1_comm,
+            &self.h_2_comm,
+            &self.z_comm,
+            &self.p_comm,
+            &self.t_low_comm,
+            &self.t_mid_comm,
+            &self.t_high_comm,
+            &self.t_4_comm,
+            &self.w_z_chall_comm,
+            &self.w_z_chall_w_comm,
+        ]
+    }
+
+    /// Serialize the proof into its [`Proof::SIZE`] bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SIZE);
+
+        self.commitments()
+            .iter()
+            .for_each(|commitment| bytes::write_commitment(&mut bytes, commitment));
+        self.evaluations
+            .scalars()
+            .iter()
+            .for_each(|scalar| bytes::write_scalar::<P>(&mut bytes, scalar));
+
+        bytes
+    }
+
+    /// Deserialize a proof from its [`Proof::SIZE`] bytes
+    ///
+    /// Returns an error if the length doesn't match, or if a point or a
+    /// scalar is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(bytes);
+
+        let proof = Self {
+            a_comm: reader.commitment()?,
+            b_comm: reader.commitment()?,
+            o_comm: reader.commitment()?,
+            d_comm: reader.commitment()?,
+            f_comm: reader.commitment()?,
+            h_1_comm: reader.commitment()?,
+            h_2_comm: reader.commitment()?,
+            z_comm: reader.commitment()?,
+            p_comm: reader.commitment()?,
+            t_low_comm: reader.commitment()?,
+            t_mid_comm: reader.commitment()?,
+            t_high_comm: reader.commitment()?,
+            t_4_comm: reader.commitment()?,
+            w_z_chall_comm: reader.commitment()?,
+            w_z_chall_w_comm: reader.commitment()?,
+            evaluations: ProofEvaluations::read(&mut reader)?,
+        };
+
+        reader.finish()?;
+
+        Ok(proof)
+    }
+
+    /// Fold the openings at `z` and `z * omega` into the `(left, right)`
+    /// points the proof holds for if `e(left, [x]) == e(right, [1])`
+    pub(crate) fn opening<T: Transcript<P>>(
+        &self,
+        verifier_data: &VerifierData<P>,
+        transcript: &mut T,
+        public_inputs: &[P::ScalarField],
+    ) -> (P::G1Projective, P::G1Projective) {
+        let n = verifier_data.n;
+        let k = n.trailing_zeros() as usize;
+        let group_generator =
+            (0..P::ScalarField::S - k).fold(P::ScalarField::ROOT_OF_UNITY, |acc, _| acc.square());
+
+        // In order for the Verifier and Prover to have the same view in the
+        // non-interactive setting Both parties must commit the same
+        // elements into the transcript Below the verifier will simulate
+        // an interaction with the prover by adding the same elements
+        // that the prover added into the transcript, hence generating the
+        // same challenges
+        //
+        // Add commitment to witness polynomials to transcript
+        transcript.append_commitment(b"a_w", &self.a_comm);
+        transcript.append_commitment(b"b_w", &self.b_comm);
+        transcript.append_commitment(b"o_w", &self.o_comm);
+        transcript.append_commitment(b"d_w", &self.d_comm);
+
+        // Compute table compression challenge zeta
+        let zeta: P::ScalarField = transcript.challenge_scalar(b"zeta");
+        transcript.append_scalar(b"zeta", &zeta);
+
+        // Add commitment to lookup query polynomial to transcript
+        transcript.append_commitment(b"f", &self.f_comm);
+
+        // Add commitments to sorted lookup polynomials to transcript
+        transcript.append_commitment(b"h_1", &self.h_1_comm);
+        transcript.append_commitment(b"h_2", &self.h_2_comm);
+
+        // Compute beta and gamma challenges
+        let beta: P::ScalarField = transcript.challenge_scalar(b"beta");
+        transcript.append_scalar(b"beta", &beta);
+
+        let gamma: P::ScalarField = transcript.challenge_scalar(b"gamma");
+        transcript.append_scalar(b"gamma", &gamma);
+
+        // Compute delta and epsilon lookup challenges
+        let delta: P::ScalarField = transcript.challenge_scalar(b"delta");
+        transcript.append_scalar(b"delta", &delta);
+
+        let epsilon: P::ScalarField = transcript.challenge_scalar(b"epsilon");
+        transcript.append_scalar(b"epsilon", &epsilon);
+
+        // Add commitments to permutation polynomials to transcript
+        transcript.append_commitment(b"z", &self.z_comm);
+        transcript.append_commitment(b"p", &self.p_comm);
+
+        // Compute quotient challenge
+        let alpha: P::ScalarField = transcript.challenge_scalar(b"alpha");
+        transcript.append_scalar(b"alpha", &alpha);
+
+        let range_sep_challenge: P::ScalarField =
+            transcript.challenge_scalar(b"range_sep_challenge");
+        transcript.append_scalar(b"range_sep_challenge", &range_sep_challenge);
+
+        let logic_sep_challenge: P::ScalarField =
+            transcript.challenge_scalar(b"logic_sep_challenge");
+        transcript.append_scalar(b"logic_sep_challenge", &logic_sep_challenge);
+
+        let fixed_base_sep_challenge: P::ScalarField =
+            transcript.challenge_scalar(b"fixed_base_sep_challenge");
+        transcript.append_scalar(b"fixed_base_sep_challenge", &fixed_base_sep_challenge);
+
+        let var_base_sep_challenge: P::ScalarField =
+            transcript.challenge_scalar(b"var_base_sep_challenge");
+        transcript.append_scalar(b"var_base_sep_challenge", &var_base_sep_challenge);
+
+        let lookup_sep_challenge: P::ScalarField =
+            transcript.challenge_scalar(b"lookup_sep_challenge");
+        transcript.append_scalar(b"lookup_sep_challenge", &lookup_sep_challenge);
+
+        let custom_sep_challenge: P::ScalarField =
+            transcript.challenge_scalar(b"custom_sep_challenge");
+        transcript.append_scalar(b"custom_sep_challenge", &custom_sep_challenge);
+
+        // Add commitment to quotient polynomial to transcript
+        transcript.append_commitment(b"t_low", &self.t_low_comm);
+        transcript.append_commitment(b"t_mid", &self.t_mid_comm);
+        transcript.append_commitment(b"t_high", &self.t_high_comm);
+        transcript.append_commitment(b"t_4", &self.t_4_comm);
+
+        // Compute evaluation challenge z
+        let z_challenge: P::ScalarField = transcript.challenge_scalar(b"z_challenge");
+        transcript.append_scalar(b"z_challenge", &z_challenge);
+
+        // Compute zero polynomial evaluated at challenge `z`
+        let z_n = z_challenge.pow(n as u64);
+        let z_h_eval = z_n - P::ScalarField::one();
+
+        // Compute first lagrange polynomial evaluated at challenge `z`
+        let l1_eval = compute_first_lagrange_evaluation::<P>(n, &z_challenge);
+
+        // Compute the public input polynomial evaluated at challenge `z`
+        let pi_eval = compute_barycentric_eval::<P>(
+            &verifier_data.public_input_indexes,
+            public_inputs,
+            &z_challenge,
+            &group_generator,
+            n,
+        );
+
+        // Compute quotient polynomial evaluated at challenge `z`
+        let r0 = self.compute_r0(&pi_eval, &l1_eval, (&alpha, &beta, &gamma))
+            + lookup::compute_constant_term(
+                &lookup_sep_challenge,
+                (&delta, &epsilon),
+                &l1_eval,
+                &self.evaluations,
+            );
+
+        // Add evaluations to transcript
+        transcript.append_scalar(b"a_eval", &self.evaluations.a_eval);
+        transcript.append_scalar(b"b_eval", &self.evaluations.b_eval);
+        transcript.append_scalar(b"o_eval", &self.evaluations.o_eval);
+        transcript.append_scalar(b"d_eval", &self.evaluations.d_eval);
+        transcript.append_scalar(b"a_next_eval", &self.evaluations.a_next_eval);
+        transcript.append_scalar(b"b_next_eval", &self.evaluations.b_next_eval);
+        transcript.append_scalar(b"d_next_eval", &self.evaluations.d_next_eval);
+        transcript.append_scalar(b"q_arith_eval", &self.evaluations.q_arith_eval);
+        transcript.append_scalar(b"q_c_eval", &self.evaluations.q_c_eval);
+        transcript.append_scalar(b"q_l_eval", &self.evaluations.q_l_eval);
+        transcript.append_scalar(b"q_r_eval", &self.evaluations.q_r_eval);
+        transcript.append_scalar(b"s_sigma_1_eval", &self.evaluations.s_sigma_1_eval);
+        transcript.append_scalar(b"s_sigma_2_eval", &self.evaluations.s_sigma_2_eval);
+        transcript.append_scalar(b"s_sigma_3_eval", &self.evaluations.s_sigma_3_eval);
+        transcript.append_scalar(b"perm_eval", &self.evaluations.perm_eval);
+        transcript.append_scalar(b"f_eval", &self.evaluations.f_eval);
+        transcript.append_scalar(b"table_eval", &self.evaluations.table_eval);
+        transcript.append_scalar(b"table_next_eval", &self.evaluations.table_next_eval);
+        transcript.append_scalar(b"h_1_eval", &self.evaluations.h_1_eval);
+        transcript.append_scalar(b"h_1_next_eval", &self.evaluations.h_1_next_eval);
+        transcript.append_scalar(b"h_2_eval", &self.evaluations.h_2_eval);
+        transcript.append_scalar(b"lookup_perm_eval", &self.evaluations.lookup_perm_eval);
+
+        // Compute the commitment to the linearization polynomial `[r]_1`
+        let mut scalars = Vec::with_capacity(32);
+        let mut points = Vec::with_capacity(32);
+
+        verifier_data.arithmetic.compute_linearization_commitment(
+            &mut scalars,
+            &mut points,
+            &self.evaluations,
+        );
+        verifier_data.range.compute_linearization_commitment(
+            &range_sep_challenge,
+            &mut scalars,
+            &mut points,
+            &self.evaluations,
+        );
+        verifier_data.logic.compute_linearization_commitment(
+            &logic_sep_challenge,
+            &mut scalars,
+            &mut points,
+            &self.evaluations,
+        );
+        verifier_data.fixed_base.compute_linearization_commitment(
+            &fixed_base_sep_challenge,
+            &mut scalars,
+            &mut points,
+            &self.evaluations,
+        );
+        verifier_data
+            .variable_base
+            .compute_linearization_commitment(
+                &var_base_sep_challenge,
+                &mut scalars,
+                &mut points,
+                &self.evaluations,
+            );
+        verifier_data
+            .custom
+            .iter()
+            .fold(custom_sep_challenge, |sep, gate| {
+                gate.compute_linearization_commitment(
+                    &sep,
+                    &mut scalars,
+                    &mut points,
+                    &self.evaluations,
+                );
+
+                sep * custom_sep_challenge
+            });
+        verifier_data.permutation.compute_linearization_commitment(
+            &mut scalars,
+            &mut points,
+            &self.evaluations,
+            &z_challenge,
+            (&alpha, &beta, &gamma),
+            &l1_eval,
+            self.z_comm.0,
+        );
+        verifier_data.lookup.compute_linearization_commitment(
+            &lookup_sep_challenge,
+            (&zeta, &delta, &epsilon),
+            &l1_eval,
+            &mut scalars,
+            &mut points,
+            &self.evaluations,
+            self.p_comm.0,
+        );
+
+        // -Z_H(z) * (t_low + z^n * t_mid + z^{2n} * t_high + z^{3n} * t_4)
+        let z_two_n = z_n.square();
+        let z_three_n = z_two_n * z_n;
+        let minus_z_h_eval = -z_h_eval;
+
+        scalars.push(minus_z_h_eval);
+        points.push(self.t_low_comm.0);
+
+        scalars.push(minus_z_h_eval * z_n);
+        points.push(self.t_mid_comm.0);
+
+        scalars.push(minus_z_h_eval * z_two_n);
+        points.push(self.t_high_comm.0);
+
+        scalars.push(minus_z_h_eval * z_three_n);
+        points.push(self.t_4_comm.0);
+
+        // Compute aggregate witness to polynomials evaluated at the
+        // evaluation challenge `z`
+        let v_challenge: P::ScalarField = transcript.challenge_scalar(b"v_challenge");
+        transcript.append_scalar(b"v_challenge", &v_challenge);
+
+        // Compute aggregate witness to polynomials evaluated at the shifted
+        // evaluation challenge
+        let v_w_challenge: P::ScalarField = transcript.challenge_scalar(b"v_w_challenge");
+        transcript.append_scalar(b"v_w_challenge", &v_w_challenge);
+
+        // Separate the two openings with a random challenge
+        transcript.append_commitment(b"w_z", &self.w_z_chall_comm);
+        transcript.append_commitment(b"w_z_w", &self.w_z_chall_w_comm);
+        let u_challenge: P::ScalarField = transcript.challenge_scalar(b"u_challenge");
+
+        let table_comm = verifier_data.lookup.compress_table(&zeta);
+
+        // The linearization polynomial is the first one of the opening at `z`
+        // and evaluates to `-r0`
+        let mut v_power = v_challenge;
+        let mut aggregate_eval = -r0;
+        [
+            (self.evaluations.a_eval, self.a_comm.0),
+            (self.evaluations.b_eval, self.b_comm.0),
+            (self.evaluations.o_eval, self.o_comm.0),
+            (self.evaluations.d_eval, self.d_comm.0),
+            (
+                self.evaluations.s_sigma_1_eval,
+                verifier_data.permutation.s_sigma_1.0,
+            ),
+            (
+                self.evaluations.s_sigma_2_eval,
+                verifier_data.permutation.s_sigma_2.0,
+            ),
+            (
+                self.evaluations.s_sigma_3_eval,
+                verifier_data.permutation.s_sigma_3.0,
+            ),
+            (
+                self.evaluations.q_arith_eval,
+                verifier_data.arithmetic.q_arith.0,
+            ),
+            (self.evaluations.q_c_eval, verifier_data.arithmetic.q_c.0),
+            (self.evaluations.q_l_eval, verifier_data.arithmetic.q_l.0),
+            (self.evaluations.q_r_eval, verifier_data.arithmetic.q_r.0),
+            (self.evaluations.f_eval, self.f_comm.0),
+            (self.evaluations.h_1_eval, self.h_1_comm.0),
+            (self.evaluations.h_2_eval, self.h_2_comm.0),
+            (self.evaluations.table_eval, table_comm),
+        ]
+        .iter()
+        .for_each(|(eval, comm)| {
+            aggregate_eval += *eval * v_power;
+            scalars.push(v_power);
+            points.push(*comm);
+            v_power *= v_challenge;
+        });
+
+        // The opening at `z * omega` covers the permutation polynomials, the
+        // wires read from the next row by the custom gates and the shifted
+        // lookup polynomials
+        let shifted_z_challenge = z_challenge * group_generator;
+        let mut v_w_power = u_challenge;
+        let mut shifted_aggregate_eval = P::ScalarField::zero();
+        [
+            (self.evaluations.perm_eval, self.z_comm.0),
+            (self.evaluations.a_next_eval, self.a_comm.0),
+            (self.evaluations.b_next_eval, self.b_comm.0),
+            (self.evaluations.d_next_eval, self.d_comm.0),
+            (self.evaluations.lookup_perm_eval, self.p_comm.0),
+            (self.evaluations.h_1_next_eval, self.h_1_comm.0),
+            (self.evaluations.table_next_eval, table_comm),
+        ]
+        .iter()
+        .for_each(|(eval, comm)| {
+            shifted_aggregate_eval += *eval * v_w_power;
+            scalars.push(v_w_power);
+            points.push(*comm);
+            v_w_power *= v_w_challenge;
+        });
+
+        // Move the evaluation and the opening points to the right side of
+        // the pairing equation
+        scalars.push(-(aggregate_eval + shifted_aggregate_eval));
+        points.push(P::G1Affine::ADDITIVE_GENERATOR);
+
+        scalars.push(z_challenge);
+        points.push(self.w_z_chall_comm.0);
+
+        scalars.push(u_challenge * shifted_z_challenge);
+        points.push(self.w_z_chall_w_comm.0);
+
+        let right = msm_variable_base::<P>(&points, &scalars);
+        let left = msm_variable_base::<P>(
+            &[self.w_z_chall_comm.0, self.w_z_chall_w_comm.0],
+            &[P::ScalarField::one(), u_challenge],
+        );
+
+        (left, right)
+    }
+
+    fn compute_r0(
+        &self,
+        pi_eval: &P::ScalarField,
+        l1_eval: &P::ScalarField,
+        (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let alpha_sq = alpha.square();
+
+        // PI(z) - L1(z) * alpha^2 - alpha * (a_eval + beta * sigma_1 + gamma)
+        // * (b_eval + beta * sigma_2 + gamma) * (o_eval + beta * sigma_3 +
+        // gamma) * (d_eval + gamma) * z_hat_eval
+        let a = {
+            let beta_sig1 = *beta * self.evaluations.s_sigma_1_eval;
+            let b_0 = self.evaluations.a_eval + beta_sig1 + gamma;
+
+            let beta_sig2 = *beta * self.evaluations.s_sigma_2_eval;
+            let b_1 = self.evaluations.b_eval + beta_sig2 + gamma;
+
+            let beta_sig3 = *beta * self.evaluations.s_sigma_3_eval;
+            let b_2 = self.evaluations.o_eval + beta_sig3 + gamma;
+
+            let b_3 = (self.evaluations.d_eval + gamma) * self.evaluations.perm_eval * alpha;
+
+            b_0 * b_1 * b_2 * b_3
+        };
+
+        let b = *l1_eval * alpha_sq;
+
+        *pi_eval - a - b
+    }
+}
+
+impl<P: Pairing> Encode for Proof<P> {
+    fn size_hint(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.to_bytes());
+    }
+}
+
+impl<P: Pairing> Decode for Proof<P> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+        let mut bytes = vec![0; Self::SIZE];
+        input.read(&mut bytes)?;
+
+        Self::from_bytes(&bytes).map_err(|_| "invalid proof bytes".into())
+    }
+}
+
+/// Check `e(left, [x]) == e(right, [1])` with a single final exponentiation
+pub(crate) fn pairing_check<P: Pairing>(
+    left: P::G1Projective,
+    right: P::G1Projective,
+    opening_key: &P::G2Affine,
+) -> Result<(), Error> {
+    let pairing = P::multi_miller_loop(&[
+        (P::G1Affine::from(left), P::G2PairngRepr::from(*opening_key)),
+        (
+            P::G1Affine::from(-right),
+            P::G2PairngRepr::from(P::G2Affine::ADDITIVE_GENERATOR),
+        ),
+    ])
+    .final_exp();
+
+    if pairing == <P::PairingRange as PairingRange>::Gt::ADDITIVE_IDENTITY {
+        Ok(())
+    } else {
+        Err(Error::ProofVerificationError)
+    }
+}
+
+// Evaluates the public input polynomial at `point` from its sparse values
+// using the barycentric formula `L_i(z) = w^i * (z^n - 1) / (n * (z - w^i))`
+fn compute_barycentric_eval<P: Pairing>(
+    public_input_indexes: &[usize],
+    public_inputs: &[P::ScalarField],
+    point: &P::ScalarField,
+    group_generator: &P::ScalarField,
+    n: usize,
+) -> P::ScalarField {
+    let numerator = (point.pow(n as u64) - P::ScalarField::one())
+        * P::ScalarField::from(n as u64)
+            .invert()
+            .expect("domain size is not zero");
+
+    public_input_indexes
+        .iter()
+        .zip(public_inputs.iter())
+        .map(|(idx, pi)| {
+            let root = group_generator.pow(*idx as u64);
+            let denominator = (*point - root)
+                .invert()
+                .expect("evaluation challenge is not a root of unity");
+
+            *pi * root * numerator * denominator
+        })
+        .sum()
+}
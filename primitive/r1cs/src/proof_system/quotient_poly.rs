@@ -0,0 +1,264 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use zero_kzg::{Fft, Polynomial};
+use zkstd::common::{vec, FftField, Group, Pairing, PrimeField, Ring, Vec};
+
+use crate::proof_system::preprocess::ProverKey;
+
+/// Computes the quotient polynomial `t(X)`
+///
+/// The circuit satisfiability, permutation and lookup identities are
+/// evaluated over the `8n` coset and divided by the vanishing polynomial
+/// `Z_H(X) = X^n - 1`
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn compute<P: Pairing>(
+    fft: &Fft<P::ScalarField>,
+    prover_key: &ProverKey<P>,
+    [z_poly, p_poly]: [&Polynomial<P::ScalarField>; 2],
+    [a_poly, b_poly, o_poly, d_poly]: [&Polynomial<P::ScalarField>; 4],
+    [f_poly, h_1_poly, h_2_poly]: [&Polynomial<P::ScalarField>; 3],
+    table_eval_8n: &[P::ScalarField],
+    public_inputs_poly: &Polynomial<P::ScalarField>,
+    (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    (zeta, delta, epsilon, lookup_challenge): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (range_challenge, logic_challenge, fixed_base_challenge, var_base_challenge): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    custom_challenge: &P::ScalarField,
+) -> Polynomial<P::ScalarField> {
+    let n = fft.size();
+    let fft_8n = Fft::<P::ScalarField>::new(n.trailing_zeros() as usize + 3);
+
+    let coset = |poly: &Polynomial<P::ScalarField>| {
+        let mut evals = poly.clone();
+        fft_8n.coset_dft(&mut evals);
+
+        evals.0
+    };
+
+    let polys = [
+        z_poly,
+        a_poly,
+        b_poly,
+        o_poly,
+        d_poly,
+        public_inputs_poly,
+        p_poly,
+        f_poly,
+        h_1_poly,
+        h_2_poly,
+    ];
+    let [z_eval_8n, a_w_eval_8n, b_w_eval_8n, o_w_eval_8n, d_w_eval_8n, pi_eval_8n, p_eval_8n, f_eval_8n, h_1_eval_8n, h_2_eval_8n]: [Vec<P::ScalarField>; 10] =
+        cfg_iter!(polys)
+            .map(|poly| coset(poly))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("an evaluation per polynomial");
+
+    // evaluations of `L_1(X)` over the 8n coset
+    let mut l1_eval_8n = Polynomial::new(vec![P::ScalarField::zero(); n]);
+    l1_eval_8n[0] = P::ScalarField::one();
+    fft.idft(&mut l1_eval_8n);
+    fft_8n.coset_dft(&mut l1_eval_8n);
+
+    let t_1 = compute_circuit_satisfiability_equation(
+        prover_key,
+        [&a_w_eval_8n, &b_w_eval_8n, &o_w_eval_8n, &d_w_eval_8n],
+        &pi_eval_8n,
+        (
+            range_challenge,
+            logic_challenge,
+            fixed_base_challenge,
+            var_base_challenge,
+        ),
+        custom_challenge,
+    );
+    let t_2 = compute_permutation_checks(
+        prover_key,
+        [&a_w_eval_8n, &b_w_eval_8n, &o_w_eval_8n, &d_w_eval_8n],
+        &z_eval_8n,
+        &l1_eval_8n,
+        (alpha, beta, gamma),
+    );
+    let t_3 = compute_lookup_checks(
+        prover_key,
+        [&a_w_eval_8n, &b_w_eval_8n, &o_w_eval_8n],
+        [&f_eval_8n, &h_1_eval_8n, &h_2_eval_8n],
+        table_eval_8n,
+        &p_eval_8n,
+        &l1_eval_8n,
+        lookup_challenge,
+        (zeta, delta, epsilon),
+    );
+
+    let v_h_inv = compute_vanishing_inverse_on_coset::<P>(n);
+
+    let quotient = t_1
+        .iter()
+        .zip(t_2.iter())
+        .zip(t_3.iter())
+        .enumerate()
+        .map(|(i, ((t_1, t_2), t_3))| (*t_1 + t_2 + t_3) * v_h_inv[i % v_h_inv.len()])
+        .collect();
+
+    let mut quotient = Polynomial::new(quotient);
+    fft_8n.coset_idft(&mut quotient);
+
+    quotient
+}
+
+// Ensures that the circuit is satisfied
+#[allow(clippy::too_many_arguments)]
+fn compute_circuit_satisfiability_equation<P: Pairing>(
+    prover_key: &ProverKey<P>,
+    [a_w_eval_8n, b_w_eval_8n, o_w_eval_8n, d_w_eval_8n]: [&[P::ScalarField]; 4],
+    pi_eval_8n: &[P::ScalarField],
+    (range_challenge, logic_challenge, fixed_base_challenge, var_base_challenge): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    custom_challenge: &P::ScalarField,
+) -> Vec<P::ScalarField> {
+    let size = pi_eval_8n.len();
+
+    cfg_into_iter!(0..size)
+        .map(|i| {
+            // the next row of the domain is 8 steps ahead on the 8n coset
+            let wires = (
+                &a_w_eval_8n[i],
+                &b_w_eval_8n[i],
+                &o_w_eval_8n[i],
+                &d_w_eval_8n[i],
+            );
+            let next_wires = (
+                &a_w_eval_8n[(i + 8) % size],
+                &b_w_eval_8n[(i + 8) % size],
+                &d_w_eval_8n[(i + 8) % size],
+            );
+
+            let a = prover_key.arithmetic.compute_quotient_i(i, wires);
+            let r = prover_key
+                .range
+                .compute_quotient_i(i, range_challenge, wires, next_wires.2);
+            let l = prover_key
+                .logic
+                .compute_quotient_i(i, logic_challenge, wires, next_wires);
+            let f = prover_key.fixed_base.compute_quotient_i(
+                i,
+                fixed_base_challenge,
+                wires,
+                next_wires,
+            );
+            let c = prover_key.variable_base.compute_quotient_i(
+                i,
+                var_base_challenge,
+                wires,
+                next_wires,
+            );
+
+            // the k-th custom gate is separated by the k + 1-th power of
+            // the challenge
+            let (custom, _) = prover_key.custom.iter().fold(
+                (P::ScalarField::zero(), *custom_challenge),
+                |(acc, sep), gate| {
+                    let identity = gate.compute_quotient_i(i, &sep, wires, next_wires);
+
+                    (acc + identity, sep * custom_challenge)
+                },
+            );
+
+            a + r + l + f + c + custom + pi_eval_8n[i]
+        })
+        .collect()
+}
+
+fn compute_permutation_checks<P: Pairing>(
+    prover_key: &ProverKey<P>,
+    [a_w_eval_8n, b_w_eval_8n, o_w_eval_8n, d_w_eval_8n]: [&[P::ScalarField]; 4],
+    z_eval_8n: &[P::ScalarField],
+    l1_eval_8n: &[P::ScalarField],
+    (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+) -> Vec<P::ScalarField> {
+    let size = z_eval_8n.len();
+    let alpha_sq = alpha.square();
+
+    cfg_into_iter!(0..size)
+        .map(|i| {
+            prover_key.permutation.compute_quotient_i(
+                i,
+                (
+                    &a_w_eval_8n[i],
+                    &b_w_eval_8n[i],
+                    &o_w_eval_8n[i],
+                    &d_w_eval_8n[i],
+                ),
+                (&z_eval_8n[i], &z_eval_8n[(i + 8) % size]),
+                (alpha, &(l1_eval_8n[i] * alpha_sq)),
+                (beta, gamma),
+            )
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn compute_lookup_checks<P: Pairing>(
+    prover_key: &ProverKey<P>,
+    [a_w_eval_8n, b_w_eval_8n, o_w_eval_8n]: [&[P::ScalarField]; 3],
+    [f_eval_8n, h_1_eval_8n, h_2_eval_8n]: [&[P::ScalarField]; 3],
+    table_eval_8n: &[P::ScalarField],
+    p_eval_8n: &[P::ScalarField],
+    l1_eval_8n: &[P::ScalarField],
+    lookup_challenge: &P::ScalarField,
+    challenges: (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+) -> Vec<P::ScalarField> {
+    let size = p_eval_8n.len();
+
+    cfg_into_iter!(0..size)
+        .map(|i| {
+            let next = (i + 8) % size;
+
+            prover_key.lookup.compute_quotient_i(
+                i,
+                lookup_challenge,
+                challenges,
+                (&a_w_eval_8n[i], &b_w_eval_8n[i], &o_w_eval_8n[i]),
+                (&f_eval_8n[i], &l1_eval_8n[i]),
+                (&table_eval_8n[i], &table_eval_8n[next]),
+                (&h_1_eval_8n[i], &h_1_eval_8n[next], &h_2_eval_8n[i]),
+                (&p_eval_8n[i], &p_eval_8n[next]),
+            )
+        })
+        .collect()
+}
+
+// The vanishing polynomial `X^n - 1` only takes 8 distinct values over the
+// 8n coset, so only those are inverted
+fn compute_vanishing_inverse_on_coset<P: Pairing>(n: usize) -> Vec<P::ScalarField> {
+    let coset_n = P::ScalarField::MULTIPLICATIVE_GENERATOR.pow(n as u64);
+    let root_8 = Fft::<P::ScalarField>::new(3).elements;
+
+    root_8
+        .iter()
+        .map(|w| {
+            (coset_n * w - P::ScalarField::one())
+                .invert()
+                .expect("the coset is disjoint from the evaluation domain")
+        })
+        .collect()
+}
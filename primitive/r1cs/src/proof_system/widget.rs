@@ -0,0 +1,16 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Gate identities contributing to the quotient and the linearization
+//! polynomials
+
+pub(crate) mod arithmetic;
+pub(crate) mod custom;
+pub(crate) mod ecc;
+pub(crate) mod logic;
+pub(crate) mod lookup;
+pub(crate) mod permutation;
+pub(crate) mod range;
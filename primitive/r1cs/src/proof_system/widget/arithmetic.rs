@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{Pairing, Vec};
+
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Arithmetic gate selectors in coefficient form along with their
+/// evaluations over the `8n` coset
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) q_m: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_l: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_r: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_o: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_d: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_c: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_arith: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+    ) -> P::ScalarField {
+        let q_m_i = self.q_m.1[index];
+        let q_l_i = self.q_l.1[index];
+        let q_r_i = self.q_r.1[index];
+        let q_o_i = self.q_o.1[index];
+        let q_d_i = self.q_d.1[index];
+        let q_c_i = self.q_c.1[index];
+        let q_arith_i = self.q_arith.1[index];
+
+        // (a(x)b(x)q_M(x) + a(x)q_L(x) + b(X)q_R(x) + o(X)q_O(X) + d(x)q_D(X) +
+        // Q_C(X)) * Q_Arith(X)
+        let a_1 = *a_w_i * b_w_i * q_m_i;
+        let a_2 = *a_w_i * q_l_i;
+        let a_3 = *b_w_i * q_r_i;
+        let a_4 = *o_w_i * q_o_i;
+        let a_5 = *d_w_i * q_d_i;
+
+        (a_1 + a_2 + a_3 + a_4 + a_5 + q_c_i) * q_arith_i
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        (a_eval, b_eval, o_eval, d_eval): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        q_arith_eval: &P::ScalarField,
+    ) -> Polynomial<P::ScalarField> {
+        let q_m_poly = &self.q_m.0;
+        let q_l_poly = &self.q_l.0;
+        let q_r_poly = &self.q_r.0;
+        let q_o_poly = &self.q_o.0;
+        let q_d_poly = &self.q_d.0;
+        let q_c_poly = &self.q_c.0;
+
+        // (a_eval * b_eval * q_m_poly + a_eval * q_l + b_eval * q_r + o_eval
+        // * q_o + d_eval * q_d + q_c) * q_arith_eval
+        let a = q_m_poly * &(*a_eval * b_eval);
+        let b = q_l_poly * a_eval;
+        let c = q_r_poly * b_eval;
+        let d = q_o_poly * o_eval;
+        let e = q_d_poly * d_eval;
+
+        let poly = a + b + c + d + e + q_c_poly.clone();
+
+        &poly * q_arith_eval
+    }
+}
+
+/// Commitments to the arithmetic gate selectors
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) q_m: Commitment<P>,
+    pub(crate) q_l: Commitment<P>,
+    pub(crate) q_r: Commitment<P>,
+    pub(crate) q_o: Commitment<P>,
+    pub(crate) q_d: Commitment<P>,
+    pub(crate) q_c: Commitment<P>,
+    pub(crate) q_arith: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+    ) {
+        let q_arith_eval = evaluations.q_arith_eval;
+
+        scalars.push(evaluations.a_eval * evaluations.b_eval * q_arith_eval);
+        points.push(self.q_m.0);
+
+        scalars.push(evaluations.a_eval * q_arith_eval);
+        points.push(self.q_l.0);
+
+        scalars.push(evaluations.b_eval * q_arith_eval);
+        points.push(self.q_r.0);
+
+        scalars.push(evaluations.o_eval * q_arith_eval);
+        points.push(self.q_o.0);
+
+        scalars.push(evaluations.d_eval * q_arith_eval);
+        points.push(self.q_d.0);
+
+        scalars.push(q_arith_eval);
+        points.push(self.q_c.0);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Gates registered outside of the crate
+//!
+//! The identity of the `k`-th registered gate is separated from the others by
+//! the `k + 1`-th power of the custom separation challenge.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{Pairing, Vec};
+
+use crate::constraint_system::custom::{evaluate, Term};
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Identity and selector in coefficient form along with its evaluations over
+/// the `8n` coset of a custom gate
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) terms: Vec<Term<P>>,
+    pub(crate) q_custom: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        custom_separation_challenge: &P::ScalarField,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        (a_w_i_next, b_w_i_next, d_w_i_next): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let q_custom_i = self.q_custom.1[index];
+
+        evaluate::<P>(
+            &self.terms,
+            &[
+                *a_w_i,
+                *b_w_i,
+                *o_w_i,
+                *d_w_i,
+                *a_w_i_next,
+                *b_w_i_next,
+                *d_w_i_next,
+            ],
+        ) * custom_separation_challenge
+            * q_custom_i
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        custom_separation_challenge: &P::ScalarField,
+        evaluations: &ProofEvaluations<P>,
+    ) -> Polynomial<P::ScalarField> {
+        let q_custom_poly = &self.q_custom.0;
+
+        let identity = compute_identity(&self.terms, evaluations) * custom_separation_challenge;
+
+        q_custom_poly * &identity
+    }
+}
+
+/// Identity and commitment to the selector of a custom gate
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) terms: Vec<Term<P>>,
+    pub(crate) q_custom: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        custom_separation_challenge: &P::ScalarField,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+    ) {
+        let identity = compute_identity(&self.terms, evaluations) * custom_separation_challenge;
+
+        scalars.push(identity);
+        points.push(self.q_custom.0);
+    }
+}
+
+// The identity evaluated with the wires at `z` and `z * omega`
+fn compute_identity<P: Pairing>(
+    terms: &[Term<P>],
+    evaluations: &ProofEvaluations<P>,
+) -> P::ScalarField {
+    evaluate::<P>(
+        terms,
+        &[
+            evaluations.a_eval,
+            evaluations.b_eval,
+            evaluations.o_eval,
+            evaluations.d_eval,
+            evaluations.a_next_eval,
+            evaluations.b_next_eval,
+            evaluations.d_next_eval,
+        ],
+    )
+}
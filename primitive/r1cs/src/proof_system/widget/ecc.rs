@@ -0,0 +1,17 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Widgets for the embedded curve operations
+
+pub(crate) mod curve_addition;
+pub(crate) mod fixed_base;
+
+use zkstd::common::{Pairing, TwistedEdwardsCurve};
+
+/// The `d` parameter of the embedded twisted edwards curve
+pub(crate) fn edwards_d<P: Pairing>() -> P::ScalarField {
+    P::ScalarField::from(<P::JubjubAffine as TwistedEdwardsCurve>::PARAM_D)
+}
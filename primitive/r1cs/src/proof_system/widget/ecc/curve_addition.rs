@@ -0,0 +1,136 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{Pairing, PrimeField, Vec};
+
+use super::edwards_d;
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Variable base curve addition selector in coefficient form along with its
+/// evaluations over the `8n` coset
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) q_variable_group_add: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        curve_add_separation_challenge: &P::ScalarField,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        (a_w_i_next, b_w_i_next, d_w_i_next): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let q_variable_group_add_i = self.q_variable_group_add.1[index];
+
+        compute_identity::<P>(
+            curve_add_separation_challenge,
+            (a_w_i, b_w_i, o_w_i, d_w_i),
+            (a_w_i_next, b_w_i_next, d_w_i_next),
+        ) * q_variable_group_add_i
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        curve_add_separation_challenge: &P::ScalarField,
+        evaluations: &ProofEvaluations<P>,
+    ) -> Polynomial<P::ScalarField> {
+        let q_variable_group_add_poly = &self.q_variable_group_add.0;
+
+        let identity = compute_identity::<P>(
+            curve_add_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            (
+                &evaluations.a_next_eval,
+                &evaluations.b_next_eval,
+                &evaluations.d_next_eval,
+            ),
+        );
+
+        q_variable_group_add_poly * &identity
+    }
+}
+
+/// Commitment to the variable base curve addition selector
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) q_variable_group_add: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        curve_add_separation_challenge: &P::ScalarField,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+    ) {
+        let identity = compute_identity::<P>(
+            curve_add_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            (
+                &evaluations.a_next_eval,
+                &evaluations.b_next_eval,
+                &evaluations.d_next_eval,
+            ),
+        );
+
+        scalars.push(identity);
+        points.push(self.q_variable_group_add.0);
+    }
+}
+
+// The gate reads `(x_1, y_1, x_2, y_2)` on its row and `(x_3, y_3, x_1 *
+// y_2)` on the next one, and checks `(x_3, y_3) = (x_1, y_1) + (x_2, y_2)`
+pub(crate) fn compute_identity<P: Pairing>(
+    curve_add_separation_challenge: &P::ScalarField,
+    (x_1, y_1, x_2, y_2): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (x_3, y_3, x1_y2): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+) -> P::ScalarField {
+    let kappa = curve_add_separation_challenge.square();
+    let edwards_d = edwards_d::<P>();
+
+    // Check that `x1 * y2` is correct
+    let xy_consistency = *x_1 * y_2 - x1_y2;
+
+    let y1_x2 = *y_1 * x_2;
+    let y1_y2 = *y_1 * y_2;
+    let x1_x2 = *x_1 * x_2;
+
+    // Check that `x_3` is correct
+    let x3_lhs = *x1_y2 + y1_x2;
+    let x3_rhs = *x_3 + (*x_3 * edwards_d * x1_y2 * y1_x2);
+    let x3_consistency = (x3_lhs - x3_rhs) * kappa;
+
+    // Check that `y_3` is correct
+    let y3_lhs = y1_y2 + x1_x2;
+    let y3_rhs = *y_3 - (*y_3 * edwards_d * x1_y2 * y1_x2);
+    let y3_consistency = (y3_lhs - y3_rhs) * kappa.square();
+
+    (xy_consistency + x3_consistency + y3_consistency) * curve_add_separation_challenge
+}
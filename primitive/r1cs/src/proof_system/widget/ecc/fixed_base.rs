@@ -0,0 +1,180 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{Pairing, PrimeField, Ring, Vec};
+
+use super::edwards_d;
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Fixed base scalar multiplication selectors in coefficient form along with
+/// their evaluations over the `8n` coset
+///
+/// `q_l`, `q_r` and `q_c` hold the coordinates of the wnaf point of each round
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) q_l: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_r: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_c: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_fixed_group_add: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        ecc_separation_challenge: &P::ScalarField,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        (a_w_i_next, b_w_i_next, d_w_i_next): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let q_fixed_group_add_i = self.q_fixed_group_add.1[index];
+
+        compute_identity::<P>(
+            ecc_separation_challenge,
+            (a_w_i, b_w_i, o_w_i, d_w_i),
+            (a_w_i_next, b_w_i_next, d_w_i_next),
+            (&self.q_l.1[index], &self.q_r.1[index], &self.q_c.1[index]),
+        ) * q_fixed_group_add_i
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        ecc_separation_challenge: &P::ScalarField,
+        evaluations: &ProofEvaluations<P>,
+    ) -> Polynomial<P::ScalarField> {
+        let q_fixed_group_add_poly = &self.q_fixed_group_add.0;
+
+        let identity = compute_identity::<P>(
+            ecc_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            (
+                &evaluations.a_next_eval,
+                &evaluations.b_next_eval,
+                &evaluations.d_next_eval,
+            ),
+            (
+                &evaluations.q_l_eval,
+                &evaluations.q_r_eval,
+                &evaluations.q_c_eval,
+            ),
+        );
+
+        q_fixed_group_add_poly * &identity
+    }
+}
+
+/// Commitment to the fixed base scalar multiplication selector
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) q_fixed_group_add: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        ecc_separation_challenge: &P::ScalarField,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+    ) {
+        let identity = compute_identity::<P>(
+            ecc_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            (
+                &evaluations.a_next_eval,
+                &evaluations.b_next_eval,
+                &evaluations.d_next_eval,
+            ),
+            (
+                &evaluations.q_l_eval,
+                &evaluations.q_r_eval,
+                &evaluations.q_c_eval,
+            ),
+        );
+
+        scalars.push(identity);
+        points.push(self.q_fixed_group_add.0);
+    }
+}
+
+// Each round reads the accumulated point `(acc_x, acc_y)`, the product of the
+// coordinates of the point to add `xy_alpha` and the accumulated scalar on
+// its row, and the accumulated point and scalar of the next round on the next
+// row
+pub(crate) fn compute_identity<P: Pairing>(
+    ecc_separation_challenge: &P::ScalarField,
+    (acc_x, acc_y, xy_alpha, accumulated_bit): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (acc_x_next, acc_y_next, accumulated_bit_next): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (x_beta, y_beta, xy_beta): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+) -> P::ScalarField {
+    let kappa = ecc_separation_challenge.square();
+    let kappa_sq = kappa.square();
+    let kappa_cu = kappa_sq * kappa;
+    let edwards_d = edwards_d::<P>();
+
+    let bit = extract_bit::<P>(accumulated_bit, accumulated_bit_next);
+
+    // Check bit consistency
+    let bit_consistency = check_bit_consistency::<P>(bit);
+
+    let y_alpha = bit.square() * (*y_beta - P::ScalarField::one()) + P::ScalarField::one();
+    let x_alpha = *x_beta * bit;
+
+    // xy_alpha consistency check
+    let xy_consistency = ((bit * xy_beta) - xy_alpha) * kappa;
+
+    // x accumulator consistency check
+    let x_3 = acc_x_next;
+    let lhs = *x_3 + (*x_3 * xy_alpha * acc_x * acc_y * edwards_d);
+    let rhs = (*acc_x * y_alpha) + (*acc_y * x_alpha);
+    let x_acc_consistency = (lhs - rhs) * kappa_sq;
+
+    // y accumulator consistency check
+    let y_3 = acc_y_next;
+    let lhs = *y_3 - (*y_3 * xy_alpha * acc_x * acc_y * edwards_d);
+    let rhs = (*acc_y * y_alpha) + (*acc_x * x_alpha);
+    let y_acc_consistency = (lhs - rhs) * kappa_cu;
+
+    (bit_consistency + x_acc_consistency + y_acc_consistency + xy_consistency)
+        * ecc_separation_challenge
+}
+
+// Extracts the bit value from the accumulated bit.
+fn extract_bit<P: Pairing>(curr_acc: &P::ScalarField, next_acc: &P::ScalarField) -> P::ScalarField {
+    // Next - 2 * current
+    *next_acc - curr_acc - curr_acc
+}
+
+// Ensures that the bit is either +1, -1 or 0
+fn check_bit_consistency<P: Pairing>(bit: P::ScalarField) -> P::ScalarField {
+    let one = P::ScalarField::one();
+    bit * (bit - one) * (bit + one)
+}
@@ -0,0 +1,181 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{Pairing, PrimeField, Vec};
+
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Logic and constant selectors in coefficient form along with their
+/// evaluations over the `8n` coset
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) q_c: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) q_logic: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        logic_separation_challenge: &P::ScalarField,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        (a_w_i_next, b_w_i_next, d_w_i_next): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let q_logic_i = self.q_logic.1[index];
+        let q_c_i = &self.q_c.1[index];
+
+        compute_identity::<P>(
+            logic_separation_challenge,
+            (a_w_i, b_w_i, o_w_i, d_w_i),
+            (a_w_i_next, b_w_i_next, d_w_i_next),
+            q_c_i,
+        ) * q_logic_i
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        logic_separation_challenge: &P::ScalarField,
+        evaluations: &ProofEvaluations<P>,
+    ) -> Polynomial<P::ScalarField> {
+        let q_logic_poly = &self.q_logic.0;
+
+        let identity = compute_identity::<P>(
+            logic_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            (
+                &evaluations.a_next_eval,
+                &evaluations.b_next_eval,
+                &evaluations.d_next_eval,
+            ),
+            &evaluations.q_c_eval,
+        );
+
+        q_logic_poly * &identity
+    }
+}
+
+/// Commitment to the logic selector
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) q_logic: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        logic_separation_challenge: &P::ScalarField,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+    ) {
+        let identity = compute_identity::<P>(
+            logic_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            (
+                &evaluations.a_next_eval,
+                &evaluations.b_next_eval,
+                &evaluations.d_next_eval,
+            ),
+            &evaluations.q_c_eval,
+        );
+
+        scalars.push(identity);
+        points.push(self.q_logic.0);
+    }
+}
+
+// The left, right and output accumulators grow by one base 4 digit (quad)
+// per row, the output wire holds the product of the left and right quads and
+// the constant selector picks the operation: `1` for AND, `-1` for XOR
+pub(crate) fn compute_identity<P: Pairing>(
+    logic_separation_challenge: &P::ScalarField,
+    (a_w, b_w, o_w, d_w): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    (a_w_next, b_w_next, d_w_next): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    q_c: &P::ScalarField,
+) -> P::ScalarField {
+    let four = P::ScalarField::from(4);
+    let kappa = logic_separation_challenge.square();
+    let kappa_sq = kappa.square();
+    let kappa_cu = kappa_sq * kappa;
+    let kappa_qu = kappa_cu * kappa;
+
+    let a = *a_w_next - four * a_w;
+    let c_0 = delta::<P>(a);
+
+    let b = *b_w_next - four * b_w;
+    let c_1 = delta::<P>(b) * kappa;
+
+    let d = *d_w_next - four * d_w;
+    let c_2 = delta::<P>(d) * kappa_sq;
+
+    let w = *o_w;
+    let c_3 = (w - a * b) * kappa_cu;
+
+    let c_4 = delta_xor_and::<P>(&a, &b, &w, &d, q_c) * kappa_qu;
+
+    (c_0 + c_1 + c_2 + c_3 + c_4) * logic_separation_challenge
+}
+
+// Computes f(f-1)(f-2)(f-3)
+fn delta<P: Pairing>(f: P::ScalarField) -> P::ScalarField {
+    let f_1 = f - P::ScalarField::from(1);
+    let f_2 = f - P::ScalarField::from(2);
+    let f_3 = f - P::ScalarField::from(3);
+
+    f * f_1 * f_2 * f_3
+}
+
+// The polynomial interpolating the AND and XOR tables of two quads `a` and
+// `b` with `w = a * b`, which vanishes iff `c` is `a & b` when `q_c` is `1`
+// and `a ^ b` when `q_c` is `-1`
+#[allow(non_snake_case)]
+fn delta_xor_and<P: Pairing>(
+    a: &P::ScalarField,
+    b: &P::ScalarField,
+    w: &P::ScalarField,
+    c: &P::ScalarField,
+    q_c: &P::ScalarField,
+) -> P::ScalarField {
+    let nine = P::ScalarField::from(9);
+    let two = P::ScalarField::from(2);
+    let three = P::ScalarField::from(3);
+    let four = P::ScalarField::from(4);
+    let eighteen = P::ScalarField::from(18);
+    let eighty_one = P::ScalarField::from(81);
+    let eighty_three = P::ScalarField::from(83);
+
+    let F = *w
+        * (*w * (four * w - eighteen * (*a + b) + eighty_one)
+            + eighteen * (a.square() + b.square())
+            - eighty_one * (*a + b)
+            + eighty_three);
+    let E = three * (*a + b + c) - (two * F);
+    let B = *q_c * ((nine * c) - three * (*a + b));
+
+    B + E
+}
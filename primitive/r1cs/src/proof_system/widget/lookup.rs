@@ -0,0 +1,312 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Plookup argument for the lookup gates
+//!
+//! The rows of the table and the wires of the lookup gates are compressed
+//! with the challenge `zeta` into `t(X)` and the query polynomial `f(X)`,
+//! which holds the padding row of the table outside the lookup gates. The
+//! queries and the table sorted together are split alternately into `h_1(X)`
+//! and `h_2(X)`, and the grand product `p(X)` proves that the sorted values
+//! are a permutation of both of them.
+
+use hashbrown::HashMap;
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zero_pairing::msm_variable_base;
+use zkstd::common::{Group, Pairing, PrimeField, Ring, SigUtils, Vec};
+
+use crate::error::Error;
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Lookup selector and table columns in coefficient form along with their
+/// evaluations over the `8n` coset
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) q_lookup: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) table_1: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) table_2: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) table_3: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    /// Rows of the table padded with its last one to the circuit size
+    pub(crate) table: Vec<[P::ScalarField; 3]>,
+}
+
+impl<P: Pairing> ProverKey<P> {
+    /// Compressed rows of the table over the domain
+    pub(crate) fn compress_rows(&self, zeta: &P::ScalarField) -> Vec<P::ScalarField> {
+        self.table
+            .iter()
+            .map(|[t_1, t_2, t_3]| compress::<P>(zeta, (t_1, t_2, t_3)))
+            .collect()
+    }
+
+    /// Compressed table `t(X)` along with its evaluations over the `8n` coset
+    pub(crate) fn compress_table(
+        &self,
+        zeta: &P::ScalarField,
+    ) -> (Polynomial<P::ScalarField>, Vec<P::ScalarField>) {
+        let zeta_sq = zeta.square();
+
+        let poly = &(&self.table_1.0 + &(&self.table_2.0 * zeta)) + &(&self.table_3.0 * &zeta_sq);
+        let evals = self
+            .table_1
+            .1
+            .iter()
+            .zip(self.table_2.1.iter())
+            .zip(self.table_3.1.iter())
+            .map(|((t_1, t_2), t_3)| compress::<P>(zeta, (t_1, t_2, t_3)))
+            .collect();
+
+        (poly, evals)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        lookup_separation_challenge: &P::ScalarField,
+        (zeta, delta, epsilon): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        (a_w_i, b_w_i, o_w_i): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        (f_i, l1_i): (&P::ScalarField, &P::ScalarField),
+        (t_i, t_i_next): (&P::ScalarField, &P::ScalarField),
+        (h_1_i, h_1_i_next, h_2_i): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        (p_i, p_i_next): (&P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let q_lookup_i = self.q_lookup.1[index];
+        let sep_sq = lookup_separation_challenge.square();
+        let sep_cu = sep_sq * lookup_separation_challenge;
+
+        // q_lookup(X) * (a(X) + zeta * b(X) + zeta^2 * o(X) - f(X))
+        let gate = (compress::<P>(zeta, (a_w_i, b_w_i, o_w_i)) - f_i)
+            * q_lookup_i
+            * lookup_separation_challenge;
+
+        // L_1(X) * (p(X) - 1)
+        let start = (*p_i - P::ScalarField::one()) * l1_i * sep_sq;
+
+        let product = (*p_i * numerator::<P>((delta, epsilon), f_i, (t_i, t_i_next))
+            - *p_i_next * denominator::<P>((delta, epsilon), (h_1_i, h_1_i_next), h_2_i))
+            * sep_cu;
+
+        gate + start + product
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        lookup_separation_challenge: &P::ScalarField,
+        challenges: (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        l1_eval: &P::ScalarField,
+        evaluations: &ProofEvaluations<P>,
+        p_poly: &Polynomial<P::ScalarField>,
+    ) -> Polynomial<P::ScalarField> {
+        let (q_lookup_scalar, p_scalar) = linearization_scalars::<P>(
+            lookup_separation_challenge,
+            challenges,
+            l1_eval,
+            evaluations,
+        );
+
+        &(&self.q_lookup.0 * &q_lookup_scalar) + &(p_poly * &p_scalar)
+    }
+}
+
+/// Commitments to the lookup selector and to the table columns
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) q_lookup: Commitment<P>,
+    pub(crate) table_1: Commitment<P>,
+    pub(crate) table_2: Commitment<P>,
+    pub(crate) table_3: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    /// Commitment to the compressed table `t(X)`
+    pub(crate) fn compress_table(&self, zeta: &P::ScalarField) -> P::G1Affine {
+        P::G1Affine::from(msm_variable_base::<P>(
+            &[self.table_1.0, self.table_2.0, self.table_3.0],
+            &[P::ScalarField::one(), *zeta, zeta.square()],
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        lookup_separation_challenge: &P::ScalarField,
+        challenges: (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        l1_eval: &P::ScalarField,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+        p_comm: P::G1Affine,
+    ) {
+        let (q_lookup_scalar, p_scalar) = linearization_scalars::<P>(
+            lookup_separation_challenge,
+            challenges,
+            l1_eval,
+            evaluations,
+        );
+
+        scalars.push(q_lookup_scalar);
+        points.push(self.q_lookup.0);
+
+        scalars.push(p_scalar);
+        points.push(p_comm);
+    }
+}
+
+/// Terms of the lookup identities which are not linear in a committed
+/// polynomial, added to the constant `r0` of the linearization
+pub(crate) fn compute_constant_term<P: Pairing>(
+    lookup_separation_challenge: &P::ScalarField,
+    (delta, epsilon): (&P::ScalarField, &P::ScalarField),
+    l1_eval: &P::ScalarField,
+    evaluations: &ProofEvaluations<P>,
+) -> P::ScalarField {
+    let sep_sq = lookup_separation_challenge.square();
+    let sep_cu = sep_sq * lookup_separation_challenge;
+
+    let denominator = denominator::<P>(
+        (delta, epsilon),
+        (&evaluations.h_1_eval, &evaluations.h_1_next_eval),
+        &evaluations.h_2_eval,
+    );
+
+    -(*l1_eval * sep_sq) - evaluations.lookup_perm_eval * denominator * sep_cu
+}
+
+// Scalars of `q_lookup(X)` and `p(X)` in the linearization
+fn linearization_scalars<P: Pairing>(
+    lookup_separation_challenge: &P::ScalarField,
+    (zeta, delta, epsilon): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+    l1_eval: &P::ScalarField,
+    evaluations: &ProofEvaluations<P>,
+) -> (P::ScalarField, P::ScalarField) {
+    let sep_sq = lookup_separation_challenge.square();
+    let sep_cu = sep_sq * lookup_separation_challenge;
+
+    let query = compress::<P>(
+        zeta,
+        (
+            &evaluations.a_eval,
+            &evaluations.b_eval,
+            &evaluations.o_eval,
+        ),
+    );
+    let q_lookup_scalar = (query - evaluations.f_eval) * lookup_separation_challenge;
+
+    let numerator = numerator::<P>(
+        (delta, epsilon),
+        &evaluations.f_eval,
+        (&evaluations.table_eval, &evaluations.table_next_eval),
+    );
+    let p_scalar = *l1_eval * sep_sq + numerator * sep_cu;
+
+    (q_lookup_scalar, p_scalar)
+}
+
+/// Sort the queries `f` by the rows of the compressed table `t` and split
+/// the result alternately into `h_1` and `h_2`
+///
+/// Returns [`Error::LookupQueryNotInTable`] if a query isn't in the table.
+pub(crate) fn compute_sorted_halves<P: Pairing>(
+    f: &[P::ScalarField],
+    t: &[P::ScalarField],
+) -> Result<[Vec<P::ScalarField>; 2], Error> {
+    let mut queries = HashMap::new();
+    f.iter()
+        .for_each(|query| *queries.entry(query.to_bytes()).or_insert(0) += 1);
+
+    // every query follows the first row it is equal to
+    let mut sorted = Vec::with_capacity(f.len() + t.len());
+    for row in t {
+        sorted.push(*row);
+
+        if let Some(count) = queries.remove(&row.to_bytes()) {
+            sorted.extend((0..count).map(|_| *row));
+        }
+    }
+
+    if !queries.is_empty() {
+        return Err(Error::LookupQueryNotInTable);
+    }
+
+    let h_1 = sorted.iter().step_by(2).copied().collect();
+    let h_2 = sorted.iter().skip(1).step_by(2).copied().collect();
+
+    Ok([h_1, h_2])
+}
+
+/// Evaluations of the grand product `p(X)` over the domain
+///
+/// `p(w^{i+1}) = p(w^i) * (1 + delta) * (epsilon + f_i) * (epsilon * (1 +
+/// delta) + t_i + delta * t_{i+1}) / ((epsilon * (1 + delta) + h_1_i + delta
+/// * h_2_i) * (epsilon * (1 + delta) + h_2_i + delta * h_1_{i+1}))`
+pub(crate) fn compute_grand_product_vec<P: Pairing>(
+    (delta, epsilon): (&P::ScalarField, &P::ScalarField),
+    f: &[P::ScalarField],
+    t: &[P::ScalarField],
+    (h_1, h_2): (&[P::ScalarField], &[P::ScalarField]),
+) -> Vec<P::ScalarField> {
+    let n = f.len();
+    let mut acc = P::ScalarField::one();
+    let mut p = Vec::with_capacity(n);
+    p.push(acc);
+
+    for i in 0..n - 1 {
+        let numerator = numerator::<P>((delta, epsilon), &f[i], (&t[i], &t[i + 1]));
+        let denominator = denominator::<P>((delta, epsilon), (&h_1[i], &h_1[i + 1]), &h_2[i]);
+
+        acc *= numerator
+            * denominator
+                .invert()
+                .expect("the lookup challenges are random");
+        p.push(acc);
+    }
+
+    p
+}
+
+/// `a + zeta * b + zeta^2 * c`
+pub(crate) fn compress<P: Pairing>(
+    zeta: &P::ScalarField,
+    (a, b, c): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+) -> P::ScalarField {
+    *a + (*b + *c * zeta) * zeta
+}
+
+// (1 + delta) * (epsilon + f) * (epsilon * (1 + delta) + t + delta * t_next)
+fn numerator<P: Pairing>(
+    (delta, epsilon): (&P::ScalarField, &P::ScalarField),
+    f: &P::ScalarField,
+    (t, t_next): (&P::ScalarField, &P::ScalarField),
+) -> P::ScalarField {
+    let one_plus_delta = *delta + P::ScalarField::one();
+    let epsilon_one_plus_delta = *epsilon * one_plus_delta;
+
+    one_plus_delta * (*epsilon + f) * (epsilon_one_plus_delta + t + *delta * t_next)
+}
+
+// (epsilon * (1 + delta) + h_1 + delta * h_2) * (epsilon * (1 + delta) + h_2 +
+// delta * h_1_next)
+fn denominator<P: Pairing>(
+    (delta, epsilon): (&P::ScalarField, &P::ScalarField),
+    (h_1, h_1_next): (&P::ScalarField, &P::ScalarField),
+    h_2: &P::ScalarField,
+) -> P::ScalarField {
+    let epsilon_one_plus_delta = *epsilon * (*delta + P::ScalarField::one());
+
+    (epsilon_one_plus_delta + h_1 + *delta * h_2)
+        * (epsilon_one_plus_delta + h_2 + *delta * h_1_next)
+}
@@ -0,0 +1,335 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{FftField, Group, Pairing, PrimeField, Ring, Vec};
+
+use crate::permutation::{K1, K2, K3};
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Sigma polynomials in coefficient form along with their evaluations over
+/// the `8n` coset
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) s_sigma_1: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) s_sigma_2: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) s_sigma_3: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    pub(crate) s_sigma_4: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+    /// Evaluations of `X` over the `8n` coset
+    pub(crate) linear_evaluations: Vec<P::ScalarField>,
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        (z_i, z_i_next): (&P::ScalarField, &P::ScalarField),
+        (alpha, l1_alpha_sq): (&P::ScalarField, &P::ScalarField),
+        (beta, gamma): (&P::ScalarField, &P::ScalarField),
+    ) -> P::ScalarField {
+        let wires = (a_w_i, b_w_i, o_w_i, d_w_i);
+
+        let a = self.compute_quotient_identity_range_check_i(index, wires, z_i, alpha, beta, gamma);
+        let b =
+            self.compute_quotient_copy_range_check_i(index, wires, z_i_next, alpha, beta, gamma);
+        let c = self.compute_quotient_term_check_one_i(z_i, l1_alpha_sq);
+
+        a + b + c
+    }
+
+    // (a(x) + beta * X + gamma) (b(X) + beta * k1 * X + gamma) (o(X) + beta *
+    // k2 * X + gamma)(d(X) + beta * k3 * X + gamma)z(X) * alpha
+    fn compute_quotient_identity_range_check_i(
+        &self,
+        index: usize,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        z_i: &P::ScalarField,
+        alpha: &P::ScalarField,
+        beta: &P::ScalarField,
+        gamma: &P::ScalarField,
+    ) -> P::ScalarField {
+        let x = self.linear_evaluations[index];
+
+        (*a_w_i + (*beta * x) + gamma)
+            * (*b_w_i + (*beta * P::ScalarField::from(K1) * x) + gamma)
+            * (*o_w_i + (*beta * P::ScalarField::from(K2) * x) + gamma)
+            * (*d_w_i + (*beta * P::ScalarField::from(K3) * x) + gamma)
+            * z_i
+            * alpha
+    }
+
+    // (a(x) + beta* Sigma1(X) + gamma) (b(X) + beta * Sigma2(X) + gamma) (o(X)
+    // + beta * Sigma3(X) + gamma)(d(X) + beta * Sigma4(X) + gamma) Z(X.omega) *
+    // alpha
+    fn compute_quotient_copy_range_check_i(
+        &self,
+        index: usize,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        z_i_next: &P::ScalarField,
+        alpha: &P::ScalarField,
+        beta: &P::ScalarField,
+        gamma: &P::ScalarField,
+    ) -> P::ScalarField {
+        let s_sigma_1_eval = self.s_sigma_1.1[index];
+        let s_sigma_2_eval = self.s_sigma_2.1[index];
+        let s_sigma_3_eval = self.s_sigma_3.1[index];
+        let s_sigma_4_eval = self.s_sigma_4.1[index];
+
+        let product = (*a_w_i + (*beta * s_sigma_1_eval) + gamma)
+            * (*b_w_i + (*beta * s_sigma_2_eval) + gamma)
+            * (*o_w_i + (*beta * s_sigma_3_eval) + gamma)
+            * (*d_w_i + (*beta * s_sigma_4_eval) + gamma)
+            * z_i_next
+            * alpha;
+
+        -product
+    }
+
+    // L_1(X)[Z(X) - 1]
+    fn compute_quotient_term_check_one_i(
+        &self,
+        z_i: &P::ScalarField,
+        l1_alpha_sq: &P::ScalarField,
+    ) -> P::ScalarField {
+        (*z_i - P::ScalarField::one()) * l1_alpha_sq
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_linearization(
+        &self,
+        n: usize,
+        z_challenge: &P::ScalarField,
+        (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        (a_eval, b_eval, o_eval, d_eval): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        (sigma_1_eval, sigma_2_eval, sigma_3_eval): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        z_eval: &P::ScalarField,
+        z_poly: &Polynomial<P::ScalarField>,
+    ) -> Polynomial<P::ScalarField> {
+        let a = self.compute_linearizer_identity_range_check(
+            (a_eval, b_eval, o_eval, d_eval),
+            z_challenge,
+            (alpha, beta, gamma),
+            z_poly,
+        );
+        let b = self.compute_linearizer_copy_range_check(
+            (a_eval, b_eval, o_eval),
+            z_eval,
+            sigma_1_eval,
+            sigma_2_eval,
+            sigma_3_eval,
+            (alpha, beta, gamma),
+            &self.s_sigma_4.0,
+        );
+
+        let l1_eval = compute_first_lagrange_evaluation::<P>(n, z_challenge);
+        let c = self.compute_linearizer_check_is_one(&l1_eval, &alpha.square(), z_poly);
+
+        a + b + c
+    }
+
+    // (a_eval + beta * z_challenge + gamma)(b_eval + beta * K1 *
+    // z_challenge + gamma)(o_eval + beta * K2 * z_challenge + gamma)(d_eval
+    // + beta * K3 * z_challenge + gamma) * alpha * z(X)
+    fn compute_linearizer_identity_range_check(
+        &self,
+        (a_eval, b_eval, o_eval, d_eval): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        z_challenge: &P::ScalarField,
+        (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        z_poly: &Polynomial<P::ScalarField>,
+    ) -> Polynomial<P::ScalarField> {
+        let beta_z = *beta * z_challenge;
+
+        // a_eval + beta * z_challenge + gamma
+        let mut a_0 = *a_eval + beta_z;
+        a_0 += gamma;
+
+        // b_eval + beta * K1 * z_challenge + gamma
+        let beta_z_k1 = P::ScalarField::from(K1) * beta_z;
+        let mut a_1 = *b_eval + beta_z_k1;
+        a_1 += gamma;
+
+        // o_eval + beta * K2 * z_challenge + gamma
+        let beta_z_k2 = P::ScalarField::from(K2) * beta_z;
+        let mut a_2 = *o_eval + beta_z_k2;
+        a_2 += gamma;
+
+        // d_eval + beta * K3 * z_challenge + gamma
+        let beta_z_k3 = P::ScalarField::from(K3) * beta_z;
+        let mut a_3 = *d_eval + beta_z_k3;
+        a_3 += gamma;
+
+        let mut a = a_0 * a_1;
+        a *= a_2;
+        a *= a_3;
+        a *= alpha;
+
+        z_poly * &a
+    }
+
+    // -(a_eval + beta * sigma_1 + gamma)(b_eval + beta * sigma_2 + gamma)
+    // (o_eval + beta * sigma_3 + gamma) * beta * z_eval * alpha * Sigma_4(X)
+    #[allow(clippy::too_many_arguments)]
+    fn compute_linearizer_copy_range_check(
+        &self,
+        (a_eval, b_eval, o_eval): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        z_eval: &P::ScalarField,
+        sigma_1_eval: &P::ScalarField,
+        sigma_2_eval: &P::ScalarField,
+        sigma_3_eval: &P::ScalarField,
+        (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        s_sigma_4_poly: &Polynomial<P::ScalarField>,
+    ) -> Polynomial<P::ScalarField> {
+        // a_eval + beta * sigma_1 + gamma
+        let beta_sigma_1 = *beta * sigma_1_eval;
+        let mut a_0 = *a_eval + beta_sigma_1;
+        a_0 += gamma;
+
+        // b_eval + beta * sigma_2 + gamma
+        let beta_sigma_2 = *beta * sigma_2_eval;
+        let mut a_1 = *b_eval + beta_sigma_2;
+        a_1 += gamma;
+
+        // o_eval + beta * sigma_3 + gamma
+        let beta_sigma_3 = *beta * sigma_3_eval;
+        let mut a_2 = *o_eval + beta_sigma_3;
+        a_2 += gamma;
+
+        let beta_z_eval = *beta * z_eval;
+
+        let mut a = a_0 * a_1 * a_2;
+        a *= beta_z_eval;
+        a *= alpha;
+
+        s_sigma_4_poly * &-a
+    }
+
+    fn compute_linearizer_check_is_one(
+        &self,
+        lagrange_coefficient: &P::ScalarField,
+        alpha_sq: &P::ScalarField,
+        z_coeffs: &Polynomial<P::ScalarField>,
+    ) -> Polynomial<P::ScalarField> {
+        // Evaluate l_1(z) * alpha^2 * Z(X)
+        z_coeffs * &(*lagrange_coefficient * alpha_sq)
+    }
+}
+
+/// Commitments to the sigma polynomials
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) s_sigma_1: Commitment<P>,
+    pub(crate) s_sigma_2: Commitment<P>,
+    pub(crate) s_sigma_3: Commitment<P>,
+    pub(crate) s_sigma_4: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+        z_challenge: &P::ScalarField,
+        (alpha, beta, gamma): (&P::ScalarField, &P::ScalarField, &P::ScalarField),
+        l1_eval: &P::ScalarField,
+        z_comm: P::G1Affine,
+    ) {
+        let alpha_sq = alpha.square();
+
+        // (a_eval + beta * z + gamma)(b_eval + beta * z * k1 +
+        // gamma)(o_eval + beta * k2 * z + gamma)(d_eval + beta * k3 * z +
+        // gamma) * alpha
+        let x = {
+            let beta_z = *beta * z_challenge;
+            let q_0 = evaluations.a_eval + beta_z + gamma;
+
+            let beta_k1_z = *beta * P::ScalarField::from(K1) * z_challenge;
+            let q_1 = evaluations.b_eval + beta_k1_z + gamma;
+
+            let beta_k2_z = *beta * P::ScalarField::from(K2) * z_challenge;
+            let q_2 = evaluations.o_eval + beta_k2_z + gamma;
+
+            let beta_k3_z = *beta * P::ScalarField::from(K3) * z_challenge;
+            let q_3 = (evaluations.d_eval + beta_k3_z + gamma) * alpha;
+
+            q_0 * q_1 * q_2 * q_3
+        };
+
+        // l1(z) * alpha^2
+        let r = *l1_eval * alpha_sq;
+
+        scalars.push(x + r);
+        points.push(z_comm);
+
+        // -(a_eval + beta * sigma_1_eval + gamma)(b_eval + beta *
+        // sigma_2_eval + gamma)(o_eval + beta * sigma_3_eval + gamma) *
+        // alpha * beta * z_eval
+        let y = {
+            let beta_sigma_1 = *beta * evaluations.s_sigma_1_eval;
+            let q_0 = evaluations.a_eval + beta_sigma_1 + gamma;
+
+            let beta_sigma_2 = *beta * evaluations.s_sigma_2_eval;
+            let q_1 = evaluations.b_eval + beta_sigma_2 + gamma;
+
+            let beta_sigma_3 = *beta * evaluations.s_sigma_3_eval;
+            let q_2 = evaluations.o_eval + beta_sigma_3 + gamma;
+
+            let q_3 = *beta * evaluations.perm_eval * alpha;
+
+            -(q_0 * q_1 * q_2 * q_3)
+        };
+
+        scalars.push(y);
+        points.push(self.s_sigma_4.0);
+    }
+}
+
+/// Evaluates the first lagrange polynomial `L_1(X) = (X^n - 1) / n(X - 1)`
+/// at `z`
+pub(crate) fn compute_first_lagrange_evaluation<P: Pairing>(
+    n: usize,
+    z: &P::ScalarField,
+) -> P::ScalarField {
+    let n_fr = P::ScalarField::from(n as u64);
+    let z_n = z.pow(n as u64) - P::ScalarField::one();
+    let denom = n_fr * (*z - P::ScalarField::one());
+
+    z_n * denom
+        .invert()
+        .expect("evaluation challenge is not a root of unity")
+}
@@ -0,0 +1,128 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use parity_scale_codec::{Decode, Encode};
+use zero_kzg::{Commitment, Polynomial};
+use zkstd::common::{Pairing, PrimeField, Vec};
+
+use crate::proof_system::proof::ProofEvaluations;
+
+/// Range selector in coefficient form along with its evaluations over the
+/// `8n` coset
+#[derive(Debug, Clone)]
+pub(crate) struct ProverKey<P: Pairing> {
+    pub(crate) q_range: (Polynomial<P::ScalarField>, Vec<P::ScalarField>),
+}
+
+impl<P: Pairing> ProverKey<P> {
+    pub(crate) fn compute_quotient_i(
+        &self,
+        index: usize,
+        range_separation_challenge: &P::ScalarField,
+        (a_w_i, b_w_i, o_w_i, d_w_i): (
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+            &P::ScalarField,
+        ),
+        d_w_i_next: &P::ScalarField,
+    ) -> P::ScalarField {
+        let q_range_i = self.q_range.1[index];
+
+        compute_identity::<P>(
+            range_separation_challenge,
+            (a_w_i, b_w_i, o_w_i, d_w_i),
+            d_w_i_next,
+        ) * q_range_i
+    }
+
+    pub(crate) fn compute_linearization(
+        &self,
+        range_separation_challenge: &P::ScalarField,
+        evaluations: &ProofEvaluations<P>,
+    ) -> Polynomial<P::ScalarField> {
+        let q_range_poly = &self.q_range.0;
+
+        let identity = compute_identity::<P>(
+            range_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            &evaluations.d_next_eval,
+        );
+
+        q_range_poly * &identity
+    }
+}
+
+/// Commitment to the range selector
+#[derive(Debug, PartialEq, Eq, Clone, Decode, Encode)]
+pub(crate) struct VerifierKey<P: Pairing> {
+    pub(crate) q_range: Commitment<P>,
+}
+
+impl<P: Pairing> VerifierKey<P> {
+    pub(crate) fn compute_linearization_commitment(
+        &self,
+        range_separation_challenge: &P::ScalarField,
+        scalars: &mut Vec<P::ScalarField>,
+        points: &mut Vec<P::G1Affine>,
+        evaluations: &ProofEvaluations<P>,
+    ) {
+        let identity = compute_identity::<P>(
+            range_separation_challenge,
+            (
+                &evaluations.a_eval,
+                &evaluations.b_eval,
+                &evaluations.o_eval,
+                &evaluations.d_eval,
+            ),
+            &evaluations.d_next_eval,
+        );
+
+        scalars.push(identity);
+        points.push(self.q_range.0);
+    }
+}
+
+// Delta([o(X) - 4 * d(X)]) + Delta([b(X) - 4 * o(X)]) + Delta([a(X) - 4 *
+// b(X)]) + Delta([d(Xg) - 4 * a(X)])
+//
+// The accumulator of each wire is the previous one shifted by a base 4 digit
+pub(crate) fn compute_identity<P: Pairing>(
+    range_separation_challenge: &P::ScalarField,
+    (a_w, b_w, o_w, d_w): (
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+        &P::ScalarField,
+    ),
+    d_w_next: &P::ScalarField,
+) -> P::ScalarField {
+    let four = P::ScalarField::from(4);
+    let kappa = range_separation_challenge.square();
+    let kappa_sq = kappa.square();
+    let kappa_cu = kappa_sq * kappa;
+
+    let b_1 = delta::<P>(*o_w - four * d_w);
+    let b_2 = delta::<P>(*b_w - four * o_w) * kappa;
+    let b_3 = delta::<P>(*a_w - four * b_w) * kappa_sq;
+    let b_4 = delta::<P>(*d_w_next - four * a_w) * kappa_cu;
+
+    (b_1 + b_2 + b_3 + b_4) * range_separation_challenge
+}
+
+// Computes f(f-1)(f-2)(f-3)
+fn delta<P: Pairing>(f: P::ScalarField) -> P::ScalarField {
+    let f_1 = f - P::ScalarField::from(1);
+    let f_2 = f - P::ScalarField::from(2);
+    let f_3 = f - P::ScalarField::from(3);
+
+    f * f_1 * f_2 * f_3
+}
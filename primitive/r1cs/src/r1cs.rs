@@ -0,0 +1,30 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rank-1 constraint system
+//!
+//! A circuit is a set of constraints `<A_i, z> * <B_i, z> = <C_i, z>` where
+//! `A`, `B` and `C` are sparse matrices over the scalar field and
+//! `z = (1, x, w)` is the assignment of the instance `x` and the witness `w`.
+
+mod circuit;
+mod constraint_system;
+mod linear_combination;
+mod matrix;
+
+pub use circuit::R1csCircuit;
+pub use constraint_system::R1cs;
+pub use linear_combination::{LinearCombination, Variable};
+pub use matrix::SparseMatrix;
@@ -0,0 +1,32 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt::Debug;
+
+use zkstd::common::Pairing;
+
+use super::R1cs;
+use crate::error::Error;
+
+/// Circuit implementation that can be synthesized into a rank-1 constraint
+/// system
+///
+/// The default implementation will be used to generate the proving arguments,
+/// so it must allocate the same variables and constraints as any other
+/// instance of the circuit.
+pub trait R1csCircuit<P: Pairing>: Default + Debug {
+    /// Circuit definition
+    fn synthesize(&self, cs: &mut R1cs<P>) -> Result<(), Error>;
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::ops::Index;
+
+use zkstd::common::{vec, Pairing, Ring, Vec};
+
+use super::{LinearCombination, SparseMatrix, Variable};
+
+/// Rank-1 constraint system along with the assignment of its variables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1cs<P: Pairing> {
+    pub(crate) a: SparseMatrix<P::ScalarField>,
+    pub(crate) b: SparseMatrix<P::ScalarField>,
+    pub(crate) c: SparseMatrix<P::ScalarField>,
+    /// Instance assignment, starting with the constant one
+    pub(crate) instance: Vec<P::ScalarField>,
+    /// Witness assignment
+    pub(crate) witness: Vec<P::ScalarField>,
+}
+
+impl<P: Pairing> Default for R1cs<P> {
+    fn default() -> Self {
+        Self {
+            a: SparseMatrix::default(),
+            b: SparseMatrix::default(),
+            c: SparseMatrix::default(),
+            instance: vec![P::ScalarField::one()],
+            witness: Vec::new(),
+        }
+    }
+}
+
+impl<P: Pairing> Index<Variable> for R1cs<P> {
+    type Output = P::ScalarField;
+
+    fn index(&self, variable: Variable) -> &Self::Output {
+        match variable {
+            Variable::Instance(i) => &self.instance[i],
+            Variable::Witness(i) => &self.witness[i],
+        }
+    }
+}
+
+impl<P: Pairing> R1cs<P> {
+    /// The constant one, allocated as the first instance variable
+    pub const ONE: Variable = Variable::Instance(0);
+
+    /// Allocate a public input and return its variable
+    pub fn alloc_instance<F: Into<P::ScalarField>>(&mut self, value: F) -> Variable {
+        self.instance.push(value.into());
+
+        Variable::Instance(self.instance.len() - 1)
+    }
+
+    /// Allocate a private input and return its variable
+    pub fn alloc_witness<F: Into<P::ScalarField>>(&mut self, value: F) -> Variable {
+        self.witness.push(value.into());
+
+        Variable::Witness(self.witness.len() - 1)
+    }
+
+    /// Constrain `a * b = c`
+    pub fn enforce<A, B, C>(&mut self, a: A, b: B, c: C)
+    where
+        A: Into<LinearCombination<P::ScalarField>>,
+        B: Into<LinearCombination<P::ScalarField>>,
+        C: Into<LinearCombination<P::ScalarField>>,
+    {
+        self.a.push(a.into());
+        self.b.push(b.into());
+        self.c.push(c.into());
+    }
+
+    /// Constraints count
+    pub fn constraints(&self) -> usize {
+        self.a.0.len()
+    }
+
+    /// Instance variables count, including the constant one
+    pub fn instance_len(&self) -> usize {
+        self.instance.len()
+    }
+
+    /// Witness variables count
+    pub fn witness_len(&self) -> usize {
+        self.witness.len()
+    }
+
+    /// Public inputs of the circuit, without the constant one
+    pub fn public_inputs(&self) -> Vec<P::ScalarField> {
+        self.instance[1..].to_vec()
+    }
+
+    /// The `A`, `B` and `C` constraint matrices
+    pub fn matrices(&self) -> [&SparseMatrix<P::ScalarField>; 3] {
+        [&self.a, &self.b, &self.c]
+    }
+
+    /// Check whether the assignment satisfies every constraint
+    pub fn is_sat(&self) -> bool {
+        let a = self.a.evaluate(&self.instance, &self.witness);
+        let b = self.b.evaluate(&self.instance, &self.witness);
+        let c = self.c.evaluate(&self.instance, &self.witness);
+
+        a.iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .all(|((a, b), c)| *a * b == *c)
+    }
+}
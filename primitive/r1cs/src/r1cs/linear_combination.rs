@@ -0,0 +1,133 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::ops::{Add, Neg, Sub};
+
+use zkstd::common::{vec, PrimeField, Vec};
+
+/// Variable allocated in a rank-1 constraint system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variable {
+    /// Public input, where index `0` is the constant one
+    Instance(usize),
+    /// Private input
+    Witness(usize),
+}
+
+/// Weighted sum of variables, one row of a constraint matrix
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LinearCombination<F: PrimeField>(pub(crate) Vec<(Variable, F)>);
+
+impl<F: PrimeField> LinearCombination<F> {
+    /// Empty linear combination, which evaluates to zero
+    pub fn zero() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Terms of the linear combination
+    pub fn terms(&self) -> &[(Variable, F)] {
+        &self.0
+    }
+
+    /// Evaluate the linear combination with the assignment of the instance
+    /// and witness variables
+    pub fn evaluate(&self, instance: &[F], witness: &[F]) -> F {
+        self.0.iter().fold(F::zero(), |acc, (variable, coeff)| {
+            let value = match variable {
+                Variable::Instance(i) => instance[*i],
+                Variable::Witness(i) => witness[*i],
+            };
+
+            acc + *coeff * value
+        })
+    }
+}
+
+impl<F: PrimeField> From<Variable> for LinearCombination<F> {
+    fn from(variable: Variable) -> Self {
+        Self(vec![(variable, F::one())])
+    }
+}
+
+impl<F: PrimeField> From<(F, Variable)> for LinearCombination<F> {
+    fn from((coeff, variable): (F, Variable)) -> Self {
+        Self(vec![(variable, coeff)])
+    }
+}
+
+impl<F: PrimeField> Add<(F, Variable)> for LinearCombination<F> {
+    type Output = Self;
+
+    fn add(mut self, (coeff, variable): (F, Variable)) -> Self {
+        self.0.push((variable, coeff));
+
+        self
+    }
+}
+
+impl<F: PrimeField> Sub<(F, Variable)> for LinearCombination<F> {
+    type Output = Self;
+
+    fn sub(self, (coeff, variable): (F, Variable)) -> Self {
+        self + (-coeff, variable)
+    }
+}
+
+impl<F: PrimeField> Add<Variable> for LinearCombination<F> {
+    type Output = Self;
+
+    fn add(self, variable: Variable) -> Self {
+        self + (F::one(), variable)
+    }
+}
+
+impl<F: PrimeField> Sub<Variable> for LinearCombination<F> {
+    type Output = Self;
+
+    fn sub(self, variable: Variable) -> Self {
+        self - (F::one(), variable)
+    }
+}
+
+impl<F: PrimeField> Add for LinearCombination<F> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self.0.extend(rhs.0);
+
+        self
+    }
+}
+
+impl<F: PrimeField> Sub for LinearCombination<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<F: PrimeField> Neg for LinearCombination<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|(variable, coeff)| (variable, -coeff))
+                .collect(),
+        )
+    }
+}
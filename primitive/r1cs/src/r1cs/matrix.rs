@@ -0,0 +1,65 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use zkstd::common::{vec, PrimeField, Vec};
+
+use super::{LinearCombination, Variable};
+
+/// Constraint matrix stored as one sparse row per constraint
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SparseMatrix<F: PrimeField>(pub(crate) Vec<LinearCombination<F>>);
+
+impl<F: PrimeField> SparseMatrix<F> {
+    /// Rows of the matrix
+    pub fn rows(&self) -> &[LinearCombination<F>] {
+        &self.0
+    }
+
+    /// Multiply the matrix by the assignment `z = (instance, witness)`
+    pub fn evaluate(&self, instance: &[F], witness: &[F]) -> Vec<F> {
+        self.0
+            .iter()
+            .map(|row| row.evaluate(instance, witness))
+            .collect()
+    }
+
+    pub(crate) fn push(&mut self, row: LinearCombination<F>) {
+        self.0.push(row)
+    }
+
+    /// Multiply the transposed matrix by `coeffs`, one per row, and return
+    /// the instance columns followed by the witness columns
+    pub(crate) fn evaluate_columns(
+        &self,
+        coeffs: &[F],
+        instance_len: usize,
+        witness_len: usize,
+    ) -> Vec<F> {
+        let mut columns = vec![F::zero(); instance_len + witness_len];
+
+        self.0.iter().zip(coeffs).for_each(|(row, coeff)| {
+            row.0.iter().for_each(|(variable, value)| {
+                let column = match variable {
+                    Variable::Instance(i) => *i,
+                    Variable::Witness(i) => instance_len + i,
+                };
+
+                columns[column] += *value * *coeff;
+            })
+        });
+
+        columns
+    }
+}
@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Fiat-Shamir transcript of the proof system, implemented over the
+//! [Merlin Transcript](MerlinTranscript) by default.
+
+use merlin::Transcript as MerlinTranscript;
+use zero_kzg::Commitment;
+use zkstd::common::{FftField, Pairing, SigUtils};
+
+use crate::proof_system::VerifierData;
+
+/// Label every transcript of the proof system is initialized with
+pub(crate) const TRANSCRIPT_LABEL: &[u8] = b"zero-r1cs";
+
+/// Transcript the prover and the verifier derive the challenges of a proof
+/// from
+///
+/// The transcript of a circuit is bound to the label it is compiled with
+/// and to the hash of its [`VerifierData`], so the proofs of a circuit don't
+/// verify for another one.
+pub trait Transcript<P: Pairing>: Clone {
+    /// Create a transcript separated by `label`.
+    fn new(label: &'static [u8]) -> Self;
+
+    /// Append a `message` with the given `label`.
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]);
+
+    /// Compute a `label`ed challenge variable.
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> P::ScalarField;
+
+    /// Append a `commitment` with the given `label`.
+    fn append_commitment(&mut self, label: &'static [u8], comm: &Commitment<P>) {
+        self.append_message(label, &comm.0.to_bytes());
+    }
+
+    /// Append a scalar with the given `label`.
+    fn append_scalar(&mut self, label: &'static [u8], s: &P::ScalarField) {
+        self.append_message(label, &s.to_bytes())
+    }
+}
+
+impl<P: Pairing> Transcript<P> for MerlinTranscript {
+    fn new(label: &'static [u8]) -> Self {
+        MerlinTranscript::new(label)
+    }
+
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        MerlinTranscript::append_message(self, label, message)
+    }
+
+    fn challenge_scalar(&mut self, label: &'static [u8]) -> P::ScalarField {
+        let mut buf = [0u8; 64];
+        self.challenge_bytes(label, &mut buf);
+
+        P::ScalarField::from_bytes_wide(&buf)
+    }
+}
+
+/// Initialize the transcript of the circuit compiled with `label` into
+/// `verifier_data`
+pub(crate) fn circuit_transcript<P, T>(label: &[u8], verifier_data: &VerifierData<P>) -> T
+where
+    P: Pairing,
+    T: Transcript<P>,
+{
+    let mut transcript = T::new(TRANSCRIPT_LABEL);
+
+    transcript.append_message(b"dom-sep", b"circuit");
+    transcript.append_message(b"label", label);
+    transcript.append_message(b"n", &(verifier_data.n as u64).to_le_bytes());
+    transcript.append_scalar(b"verifier_data", &verifier_data.hash::<T>());
+
+    transcript
+}
@@ -0,0 +1,188 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_jubjub::{Fp as JubJubScalar, JubjubAffine, JubjubExtended};
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::{CurveGroup, Group, PrimeField};
+
+const BITS: usize = 16;
+
+#[derive(Debug, Default)]
+struct LessThanCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    less: BlsScalar,
+}
+
+impl Circuit<TatePairing> for LessThanCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+
+        composer.component_range(a, BITS);
+        composer.component_range(b, BITS);
+        let less = composer.component_less_than(a, b, BITS);
+
+        composer.assert_equal_constant(less, BlsScalar::zero(), Some(-self.less));
+
+        Ok(())
+    }
+}
+
+fn new_builder() -> Builder<TatePairing> {
+    Builder::initialized(1 << 8)
+}
+
+#[test]
+fn is_zero_works() {
+    [BlsScalar::zero(), BlsScalar::random(OsRng)]
+        .iter()
+        .for_each(|value| {
+            let mut builder = new_builder();
+            let a = builder.append_witness(*value);
+
+            let is_zero = builder.component_is_zero(a);
+
+            assert_eq!(builder[is_zero] == BlsScalar::one(), value.is_zero());
+            builder.check_satisfied().expect("the gates are satisfied");
+        });
+}
+
+#[test]
+fn is_equal_works() {
+    let a = BlsScalar::random(OsRng);
+
+    [(a, true), (a + BlsScalar::one(), false)]
+        .iter()
+        .for_each(|(b, equal)| {
+            let mut builder = new_builder();
+            let a = builder.append_witness(a);
+            let b = builder.append_witness(*b);
+
+            let is_equal = builder.component_is_equal(a, b);
+
+            assert_eq!(builder[is_equal] == BlsScalar::one(), *equal);
+            builder.check_satisfied().expect("the gates are satisfied");
+        });
+}
+
+#[test]
+fn is_zero_rejects_a_wrong_bit() {
+    let mut builder = new_builder();
+    let a = builder.append_witness(BlsScalar::random(OsRng));
+    let is_zero = builder.component_is_zero(a);
+
+    // claim a non zero value is zero
+    builder.assert_equal_constant(is_zero, BlsScalar::one(), None);
+
+    assert!(builder.check_satisfied().is_err());
+}
+
+#[test]
+fn select_works() {
+    let a = BlsScalar::random(OsRng);
+    let b = BlsScalar::random(OsRng);
+    let p = JubjubAffine::from(JubjubExtended::ADDITIVE_GENERATOR * JubJubScalar::random(OsRng));
+    let q = JubjubAffine::from(JubjubExtended::ADDITIVE_GENERATOR * JubJubScalar::random(OsRng));
+
+    [(BlsScalar::one(), a, p), (BlsScalar::zero(), b, q)]
+        .iter()
+        .for_each(|(bit, value, point)| {
+            let mut builder = new_builder();
+            let bit = builder.append_witness(*bit);
+            builder.component_boolean(bit);
+            let a = builder.append_witness(a);
+            let b = builder.append_witness(b);
+            let p = builder.append_point(p);
+            let q = builder.append_point(q);
+
+            let selected = builder.component_select(bit, a, b);
+            let selected_point = builder.component_select_point(bit, p, q);
+
+            assert_eq!(builder[selected], *value);
+            builder.assert_equal_public_point(selected_point, *point);
+            builder.check_satisfied().expect("the gates are satisfied");
+        });
+}
+
+#[test]
+fn inverse_and_div_work() {
+    let a = BlsScalar::random(OsRng);
+    let b = BlsScalar::random(OsRng);
+    let mut builder = new_builder();
+    let a = builder.append_witness(a);
+    let b = builder.append_witness(b);
+
+    let inv = builder.component_inverse(b);
+    let quotient = builder.component_div(a, b);
+
+    assert_eq!(builder[inv] * builder[b], BlsScalar::one());
+    assert_eq!(builder[quotient] * builder[b], builder[a]);
+    builder.check_satisfied().expect("the gates are satisfied");
+
+    // zero has no inverse
+    let mut builder = new_builder();
+    let zero = builder.append_witness(BlsScalar::zero());
+    builder.component_inverse(zero);
+
+    assert!(builder.check_satisfied().is_err());
+}
+
+#[test]
+fn less_than_works() {
+    let mut pp = KeyPair::<TatePairing>::setup(7, BlsScalar::random(OsRng));
+    let (prover, verifier) =
+        Compiler::compile::<LessThanCircuit, TatePairing>(&mut pp, b"less than")
+            .expect("failed to compile circuit");
+
+    let max = BlsScalar::from(0xffff);
+    [
+        (BlsScalar::zero(), BlsScalar::one(), true, true),
+        (BlsScalar::one(), BlsScalar::zero(), false, true),
+        (max, max, false, true),
+        (BlsScalar::zero(), max, true, true),
+        (max, BlsScalar::zero(), false, true),
+        (max, BlsScalar::zero(), true, false),
+        (BlsScalar::one(), BlsScalar::one(), true, false),
+    ]
+    .iter()
+    .for_each(|(a, b, less, valid)| {
+        let circuit = LessThanCircuit {
+            a: *a,
+            b: *b,
+            less: BlsScalar::from(*less as u64),
+        };
+        let result = prover
+            .prove(&mut OsRng, &circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+        assert_eq!(result.is_ok(), *valid, "{a:?} < {b:?} is {less}");
+    });
+}
+
+#[test]
+fn decomposition_works() {
+    let value = BlsScalar::from(0b1011_0110);
+    let mut builder = new_builder();
+    let a = builder.append_witness(value);
+
+    let bits = builder.component_decomposition::<8>(a);
+    let packed = builder.component_pack(&bits[..4]);
+
+    bits.iter()
+        .zip([0, 1, 1, 0, 1, 1, 0, 1])
+        .for_each(|(bit, expected)| assert_eq!(builder[*bit], BlsScalar::from(expected)));
+    assert_eq!(builder[packed], BlsScalar::from(0b0110));
+    builder.check_satisfied().expect("the value fits in 8 bits");
+
+    // the value doesn't fit in the bits
+    let mut builder = new_builder();
+    let a = builder.append_witness(value);
+    builder.component_decomposition::<7>(a);
+
+    assert!(builder.check_satisfied().is_err());
+}
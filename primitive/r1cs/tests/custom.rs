@@ -0,0 +1,206 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+// a * b^2 - o = 0
+fn mul_square() -> CustomGate<TatePairing> {
+    CustomGate::new("mul_square")
+        .term(BlsScalar::one(), &[Wire::A, Wire::B, Wire::B])
+        .term(-BlsScalar::one(), &[Wire::O])
+}
+
+// d + a * b - d' = 0, reading the fourth wire of the next gate
+fn mul_accumulate() -> CustomGate<TatePairing> {
+    CustomGate::new("mul_accumulate")
+        .term(BlsScalar::one(), &[Wire::D])
+        .term(BlsScalar::one(), &[Wire::A, Wire::B])
+        .term(-BlsScalar::one(), &[Wire::NextD])
+}
+
+// x * y^2 = z and the inner product of xs and ys is the public sum
+#[derive(Debug, Default)]
+struct CustomCircuit {
+    x: BlsScalar,
+    y: BlsScalar,
+    z: BlsScalar,
+    xs: [BlsScalar; 3],
+    ys: [BlsScalar; 3],
+    sum: BlsScalar,
+}
+
+impl CustomCircuit {
+    fn new(x: u64, y: u64, xs: [u64; 3], ys: [u64; 3]) -> Self {
+        let sum = xs.iter().zip(ys.iter()).map(|(x, y)| x * y).sum::<u64>();
+
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: (x * y * y).into(),
+            xs: xs.map(BlsScalar::from),
+            ys: ys.map(BlsScalar::from),
+            sum: sum.into(),
+        }
+    }
+}
+
+impl Circuit<TatePairing> for CustomCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let mul_square = composer.register_gate(mul_square());
+        let mul_accumulate = composer.register_gate(mul_accumulate());
+
+        let x = composer.append_witness(self.x);
+        let y = composer.append_witness(self.y);
+        let z = composer.append_witness(self.z);
+
+        composer.append_custom_gate(mul_square, Constraint::new().a(x).b(y).o(z));
+
+        // the accumulator of every gate is read by the previous one
+        let mut acc = BlsScalar::zero();
+        let mut acc_w = C::ZERO;
+        for (x, y) in self.xs.iter().zip(self.ys.iter()) {
+            let a = composer.append_witness(*x);
+            let b = composer.append_witness(*y);
+
+            composer.append_custom_gate(mul_accumulate, Constraint::new().a(a).b(b).d(acc_w));
+
+            acc += *x * y;
+            acc_w = composer.append_witness(acc);
+        }
+
+        // the last accumulator is read from the gate right after the loop
+        composer.append_gate(Constraint::new().d(acc_w));
+
+        let sum = composer.append_public(self.sum);
+        composer.assert_equal(acc_w, sum);
+
+        Ok(())
+    }
+}
+
+fn check(values: &[(CustomCircuit, bool)]) {
+    let mut pp = KeyPair::<TatePairing>::setup(8, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<CustomCircuit, TatePairing>(&mut pp, b"custom")
+        .expect("failed to compile circuit");
+
+    values.iter().for_each(|(circuit, valid)| {
+        let proof = prover
+            .prove(&mut OsRng, circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+        assert_eq!(proof.is_ok(), *valid, "custom gates of {circuit:?}");
+    });
+}
+
+#[test]
+fn custom_gates_work() {
+    check(&[
+        (CustomCircuit::new(3, 5, [1, 2, 3], [4, 5, 6]), true),
+        (CustomCircuit::new(0, 7, [0, 0, 0], [9, 9, 9]), true),
+    ]);
+}
+
+#[test]
+fn custom_gates_verifier_data_bytes() {
+    let mut pp = KeyPair::<TatePairing>::setup(8, BlsScalar::random(OsRng));
+    let (_, verifier) = Compiler::compile::<CustomCircuit, TatePairing>(&mut pp, b"custom")
+        .expect("failed to compile circuit");
+
+    let bytes = verifier.verifier_data().to_bytes();
+
+    assert_eq!(
+        VerifierData::from_bytes(&bytes).ok().as_ref(),
+        Some(verifier.verifier_data())
+    );
+}
+
+#[test]
+fn custom_gates_with_wrong_witness() {
+    let mut wrong_square = CustomCircuit::new(3, 5, [1, 2, 3], [4, 5, 6]);
+    wrong_square.z += BlsScalar::one();
+
+    let mut wrong_sum = CustomCircuit::new(3, 5, [1, 2, 3], [4, 5, 6]);
+    wrong_sum.sum += BlsScalar::one();
+
+    check(&[(wrong_square, false), (wrong_sum, false)]);
+}
+
+#[test]
+fn custom_gate_unsatisfied() {
+    let mut builder = Builder::initialized(1 << 8);
+    let gates = builder.constraints();
+
+    let mut circuit = CustomCircuit::new(3, 5, [1, 2, 3], [4, 5, 6]);
+    circuit.z += BlsScalar::one();
+    circuit.circuit(&mut builder).expect("the circuit is built");
+
+    match builder.check_satisfied() {
+        Err(Error::UnsatisfiedGate { index }) => assert_eq!(index, gates),
+        result => panic!("unexpected result {result:?}"),
+    }
+
+    // the diagnostics name the registered gate which fails
+    let diagnostics = builder.gate_diagnostics(gates).expect("the gate exists");
+    assert_eq!(
+        diagnostics.custom_selector,
+        Some(builder.register_gate(mul_square()))
+    );
+}
+
+#[test]
+fn custom_gate_registered_once() {
+    let mut builder = Builder::<TatePairing>::initialized(1 << 8);
+
+    let selector = builder.register_gate(mul_square());
+
+    assert_eq!(builder.register_gate(mul_square()), selector);
+    assert_ne!(builder.register_gate(mul_accumulate()), selector);
+}
+
+#[test]
+#[should_panic]
+fn custom_gate_registered_with_another_identity() {
+    let mut builder = Builder::<TatePairing>::initialized(1 << 8);
+
+    builder.register_gate(mul_square());
+    builder.register_gate(CustomGate::new("mul_square").term(BlsScalar::one(), &[Wire::O]));
+}
+
+#[test]
+fn custom_gates_json() {
+    let circuit = CustomCircuit::new(3, 5, [1, 2, 3], [4, 5, 6]);
+    let builder = Compiler::build(&circuit).expect("the circuit is built");
+
+    let json = builder.to_json();
+    let imported = Builder::<TatePairing>::from_json(&json).expect("failed to import circuit");
+
+    assert_eq!(imported.to_json(), json);
+
+    // only the shape is exported, so another instance gives the same file
+    let other = CustomCircuit::new(4, 7, [2, 3, 4], [5, 6, 7]);
+    let other = Compiler::build(&other).expect("the circuit is built");
+    assert_eq!(other.to_json(), json);
+
+    // the imported gates prove the circuit for its compiled verifier
+    let mut pp = KeyPair::<TatePairing>::setup(8, BlsScalar::random(OsRng));
+    let (_, verifier) = Compiler::compile::<CustomCircuit, TatePairing>(&mut pp, b"custom")
+        .expect("failed to compile circuit");
+    let prover = Prover::<CustomCircuit, TatePairing>::new(b"custom", &pp, &imported)
+        .expect("failed to preprocess the imported circuit");
+    let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // a wire moved without its copy constraint
+    let moved = json.replacen("\"w_a\": 0", "\"w_a\": 1", 1);
+    assert!(matches!(
+        Builder::<TatePairing>::from_json(&moved),
+        Err(Error::InvalidCircuitJson)
+    ));
+}
@@ -0,0 +1,201 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_jubjub::{Fp as JubJubScalar, JubjubAffine, JubjubExtended};
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::{Curve, CurveGroup, Group};
+
+const BITS: usize = 8;
+
+// value * G = point, sum = value + offset, with value in [0, 2^BITS[
+#[derive(Circuit, Debug, PartialEq)]
+struct DerivedCircuit {
+    #[public_point]
+    point: JubjubAffine,
+    #[public]
+    sum: BlsScalar,
+    #[point]
+    generator: JubjubAffine,
+    #[witness]
+    value: JubJubScalar,
+    #[witness]
+    offset: BlsScalar,
+}
+
+impl DerivedCircuit {
+    fn new(value: u64, offset: u64) -> Self {
+        let value = JubJubScalar::from(value);
+        let offset = BlsScalar::from(offset);
+
+        Self {
+            point: (JubjubExtended::ADDITIVE_GENERATOR * value).into(),
+            sum: BlsScalar::from(value) + offset,
+            generator: JubjubAffine::ADDITIVE_GENERATOR,
+            value,
+            offset,
+        }
+    }
+}
+
+impl Circuit<TatePairing> for DerivedCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let inputs = self.append_inputs(composer);
+
+        let point = composer.component_mul_point(inputs.value, inputs.generator);
+        composer.assert_equal_point(point, inputs.point);
+
+        let constraint = Constraint::new()
+            .left(1)
+            .right(1)
+            .output(-BlsScalar::one())
+            .a(inputs.value)
+            .b(inputs.offset)
+            .o(inputs.sum);
+        composer.append_gate(constraint);
+
+        composer.component_range(inputs.value, BITS);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn derived_default() {
+    assert_eq!(
+        DerivedCircuit::default(),
+        DerivedCircuit {
+            point: JubjubAffine::ADDITIVE_IDENTITY,
+            sum: BlsScalar::zero(),
+            generator: JubjubAffine::ADDITIVE_IDENTITY,
+            value: JubJubScalar::zero(),
+            offset: BlsScalar::zero(),
+        }
+    );
+}
+
+#[test]
+fn derived_public_inputs_order() {
+    let mut pp = KeyPair::<TatePairing>::setup(12, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DerivedCircuit, TatePairing>(&mut pp, b"derive")
+        .expect("failed to compile circuit");
+
+    let circuit = DerivedCircuit::new(200, 7);
+    let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+
+    // the public point followed by the public scalar, all negated
+    assert_eq!(
+        public_inputs,
+        vec![-circuit.point.get_x(), -circuit.point.get_y(), -circuit.sum,]
+    );
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+}
+
+#[test]
+fn derived_invalid_witness() {
+    let mut pp = KeyPair::<TatePairing>::setup(12, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DerivedCircuit, TatePairing>(&mut pp, b"derive")
+        .expect("failed to compile circuit");
+
+    let mut circuit = DerivedCircuit::new(200, 7);
+    circuit.sum += BlsScalar::one();
+
+    let result = prover
+        .prove(&mut OsRng, &circuit)
+        .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+    assert!(result.is_err());
+}
+
+// the public inputs of the circuit, serialized by the prover and the verifier
+struct DerivedPublicInputs {
+    point: JubjubAffine,
+    sum: BlsScalar,
+}
+
+impl PublicInputs<TatePairing> for DerivedPublicInputs {
+    fn append(&self, values: &mut PublicInputValues<TatePairing>) {
+        values.point("point", self.point).scalar("sum", self.sum);
+    }
+}
+
+// the same inputs serialized in the wrong order
+struct SwappedPublicInputs(DerivedPublicInputs);
+
+impl PublicInputs<TatePairing> for SwappedPublicInputs {
+    fn append(&self, values: &mut PublicInputValues<TatePairing>) {
+        values
+            .scalar("sum", self.0.sum)
+            .point("point", self.0.point);
+    }
+}
+
+#[test]
+fn derived_typed_public_inputs() {
+    let mut pp = KeyPair::<TatePairing>::setup(12, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DerivedCircuit, TatePairing>(&mut pp, b"derive")
+        .expect("failed to compile circuit");
+
+    assert_eq!(
+        verifier.public_input_layout().inputs(),
+        &[PublicInput::point("point"), PublicInput::scalar("sum")]
+    );
+    assert_eq!(prover.public_input_layout(), verifier.public_input_layout());
+
+    let circuit = DerivedCircuit::new(200, 7);
+    let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+
+    let typed = DerivedPublicInputs {
+        point: circuit.point,
+        sum: circuit.sum,
+    };
+    assert_eq!(typed.values().scalars(), public_inputs.as_slice());
+    verifier
+        .verify_public_inputs(&proof, &typed)
+        .expect("failed to verify proof");
+
+    match verifier.verify_public_inputs(&proof, &SwappedPublicInputs(typed)) {
+        Err(Error::PublicInputLayoutMismatch { index }) => assert_eq!(index, 0),
+        result => panic!("unexpected result {result:?}"),
+    }
+}
+
+#[test]
+fn typed_public_inputs_from_encoded_verifier_data() {
+    let mut pp = KeyPair::<TatePairing>::setup(12, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DerivedCircuit, TatePairing>(&mut pp, b"derive")
+        .expect("failed to compile circuit");
+
+    let circuit = DerivedCircuit::new(200, 7);
+    let (proof, _) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+
+    // the verifier of a chain only gets the encoded verifier data
+    let bytes = verifier.verifier_data().to_bytes();
+    let verifier_data =
+        VerifierData::<TatePairing>::from_bytes(&bytes).expect("failed to decode verifier data");
+    let verifier =
+        Verifier::<DerivedCircuit, TatePairing>::new(b"derive", verifier_data, pp.opening_key());
+
+    assert_eq!(
+        verifier.public_input_layout().inputs(),
+        &[PublicInput::point("point"), PublicInput::scalar("sum")]
+    );
+
+    let typed = DerivedPublicInputs {
+        point: circuit.point,
+        sum: circuit.sum,
+    };
+    verifier
+        .verify_public_inputs(&proof, &typed)
+        .expect("failed to verify proof");
+
+    match verifier.verify_public_inputs(&proof, &SwappedPublicInputs(typed)) {
+        Err(Error::PublicInputLayoutMismatch { index }) => assert_eq!(index, 0),
+        result => panic!("unexpected result {result:?}"),
+    }
+}
@@ -0,0 +1,224 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_jubjub::{Fp as JubJubScalar, JubjubAffine, JubjubExtended};
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::{Curve, CurveGroup, Group};
+
+fn random_point() -> JubjubAffine {
+    let scalar: BlsScalar = JubJubScalar::random(OsRng).into();
+
+    (JubjubExtended::ADDITIVE_GENERATOR * scalar).into()
+}
+
+#[derive(Debug)]
+struct AddPointCircuit {
+    a: JubjubAffine,
+    b: JubjubAffine,
+    c: JubjubAffine,
+}
+
+impl Default for AddPointCircuit {
+    fn default() -> Self {
+        Self {
+            a: JubjubAffine::ADDITIVE_IDENTITY,
+            b: JubjubAffine::ADDITIVE_IDENTITY,
+            c: JubjubAffine::ADDITIVE_IDENTITY,
+        }
+    }
+}
+
+impl Circuit<TatePairing> for AddPointCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let a = composer.append_point(self.a);
+        let b = composer.append_point(self.b);
+        let c = composer.append_point(self.c);
+
+        let sum = composer.component_add_point(a, b);
+
+        composer.assert_equal_point(sum, c);
+        composer.assert_equal_public_point(sum, self.c);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct MulGeneratorCircuit {
+    scalar: BlsScalar,
+    point: JubjubAffine,
+}
+
+impl Default for MulGeneratorCircuit {
+    fn default() -> Self {
+        Self {
+            scalar: BlsScalar::zero(),
+            point: JubjubAffine::ADDITIVE_IDENTITY,
+        }
+    }
+}
+
+impl Circuit<TatePairing> for MulGeneratorCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let scalar = composer.append_witness(self.scalar);
+
+        let point = composer.component_mul_generator(scalar, JubjubExtended::ADDITIVE_GENERATOR)?;
+
+        composer.assert_equal_public_point(point, self.point);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct MulPointCircuit {
+    scalar: BlsScalar,
+    base: JubjubAffine,
+    point: JubjubAffine,
+}
+
+impl Default for MulPointCircuit {
+    fn default() -> Self {
+        Self {
+            scalar: BlsScalar::zero(),
+            base: JubjubAffine::ADDITIVE_IDENTITY,
+            point: JubjubAffine::ADDITIVE_IDENTITY,
+        }
+    }
+}
+
+impl Circuit<TatePairing> for MulPointCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let scalar = composer.append_witness(self.scalar);
+        let base = composer.append_point(self.base);
+
+        let point = composer.component_mul_point(scalar, base);
+
+        composer.assert_equal_public_point(point, self.point);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn add_point_works() {
+    let mut pp = KeyPair::<TatePairing>::setup(6, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<AddPointCircuit, TatePairing>(&mut pp, b"ecc")
+        .expect("failed to compile circuit");
+
+    let a = random_point();
+    let b = random_point();
+    let c = (a + b).into();
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &AddPointCircuit { a, b, c })
+        .expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the sum doesn't match the claimed point
+    let c = random_point();
+    assert!(prover
+        .prove(&mut OsRng, &AddPointCircuit { a, b, c })
+        .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs))
+        .is_err());
+}
+
+#[test]
+fn mul_generator_works() {
+    let mut pp = KeyPair::<TatePairing>::setup(10, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<MulGeneratorCircuit, TatePairing>(&mut pp, b"ecc")
+        .expect("failed to compile circuit");
+
+    let scalar: BlsScalar = JubJubScalar::random(OsRng).into();
+    let point = (JubjubExtended::ADDITIVE_GENERATOR * scalar).into();
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &MulGeneratorCircuit { scalar, point })
+        .expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the public point is not the product
+    assert!(verifier.verify(&proof, &[BlsScalar::one(); 2]).is_err());
+
+    // scalars out of the jubjub scalar field are rejected
+    let circuit = MulGeneratorCircuit {
+        scalar: -BlsScalar::one(),
+        point,
+    };
+    assert!(matches!(
+        prover.prove(&mut OsRng, &circuit),
+        Err(Error::BlsScalarMalformed)
+    ));
+}
+
+#[test]
+fn mul_point_works() {
+    let mut pp = KeyPair::<TatePairing>::setup(12, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<MulPointCircuit, TatePairing>(&mut pp, b"ecc")
+        .expect("failed to compile circuit");
+
+    let scalar: BlsScalar = JubJubScalar::random(OsRng).into();
+    let base = random_point();
+    let point = (base * scalar).into();
+
+    let (proof, public_inputs) = prover
+        .prove(
+            &mut OsRng,
+            &MulPointCircuit {
+                scalar,
+                base,
+                point,
+            },
+        )
+        .expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the public point is not the product
+    let point = random_point();
+    assert!(prover
+        .prove(
+            &mut OsRng,
+            &MulPointCircuit {
+                scalar,
+                base,
+                point
+            }
+        )
+        .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs))
+        .is_err());
+}
+
+#[test]
+fn neg_point_works() {
+    let a = random_point();
+    let mut builder = Builder::<TatePairing>::initialized(1 << 4);
+    let point = builder.append_point(a);
+
+    let neg = builder.component_neg_point(point);
+    let sum = builder.component_add_point(point, neg);
+
+    assert_eq!(builder[*neg.x()], -a.get_x());
+    builder.assert_equal_point(sum, Builder::<TatePairing>::IDENTITY);
+    builder
+        .check_satisfied()
+        .expect("a point and its negation sum to the identity");
+}
@@ -0,0 +1,123 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_pairing::TatePairing;
+use zero_r1cs::groth16::Groth16;
+use zero_r1cs::prelude::Error;
+use zero_r1cs::r1cs::{LinearCombination, R1cs, R1csCircuit};
+use zkstd::common::{Group, PrimeField};
+
+// x^3 + x + 5 = y
+#[derive(Debug)]
+struct CubicCircuit {
+    x: BlsScalar,
+    y: BlsScalar,
+}
+
+impl CubicCircuit {
+    fn new(x: u64) -> Self {
+        Self {
+            x: BlsScalar::from(x),
+            y: BlsScalar::from(x * x * x + x + 5),
+        }
+    }
+}
+
+impl Default for CubicCircuit {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl R1csCircuit<TatePairing> for CubicCircuit {
+    fn synthesize(&self, cs: &mut R1cs<TatePairing>) -> Result<(), Error> {
+        let y = cs.alloc_instance(self.y);
+        let x = cs.alloc_witness(self.x);
+        let x_sq = cs.alloc_witness(self.x.square());
+        let x_cu = cs.alloc_witness(self.x.square() * self.x);
+
+        cs.enforce(x, x, x_sq);
+        cs.enforce(x_sq, x, x_cu);
+        cs.enforce(
+            LinearCombination::from(x_cu) + x + (BlsScalar::from(5), R1cs::<TatePairing>::ONE),
+            R1cs::<TatePairing>::ONE,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+// a * b = c with every variable public
+#[derive(Debug, Default)]
+struct PublicMulCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl R1csCircuit<TatePairing> for PublicMulCircuit {
+    fn synthesize(&self, cs: &mut R1cs<TatePairing>) -> Result<(), Error> {
+        let a = cs.alloc_instance(self.a);
+        let b = cs.alloc_instance(self.b);
+        let c = cs.alloc_instance(self.c);
+
+        cs.enforce(a, b, c);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn groth16_cubic() {
+    let (prover, verifier) =
+        Groth16::compile::<CubicCircuit, TatePairing, _>(&mut OsRng).expect("failed to setup");
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &CubicCircuit::new(3))
+        .expect("failed to prove");
+
+    assert_eq!(public_inputs, vec![BlsScalar::from(35)]);
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the proof doesn't hold for other public inputs
+    assert!(verifier.verify(&proof, &[BlsScalar::from(36)]).is_err());
+
+    // the public inputs length must match the circuit
+    assert!(matches!(
+        verifier.verify(&proof, &[]),
+        Err(Error::InconsistentPublicInputsLen {
+            expected: 1,
+            provided: 0
+        })
+    ));
+
+    // a wrong witness doesn't produce a valid proof
+    let circuit = CubicCircuit {
+        x: BlsScalar::from(3),
+        y: BlsScalar::from(36),
+    };
+    let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+    assert!(verifier.verify(&proof, &public_inputs).is_err());
+}
+
+#[test]
+fn groth16_public_inputs() {
+    let (prover, verifier) =
+        Groth16::compile::<PublicMulCircuit, TatePairing, _>(&mut OsRng).expect("failed to setup");
+
+    let a = BlsScalar::random(OsRng);
+    let b = BlsScalar::random(OsRng);
+    let circuit = PublicMulCircuit { a, b, c: a * b };
+
+    let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // swapping the public inputs is rejected
+    assert!(verifier.verify(&proof, &[b, a, a * b]).is_err());
+    assert!(verifier.verify(&proof, &[a, b, a + b]).is_err());
+}
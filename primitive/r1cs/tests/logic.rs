@@ -0,0 +1,120 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+#[derive(Debug, Default)]
+struct LogicCircuit<const BITS: usize, const XOR: bool> {
+    a: BlsScalar,
+    b: BlsScalar,
+    result: BlsScalar,
+}
+
+impl<const BITS: usize, const XOR: bool> LogicCircuit<BITS, XOR> {
+    fn new(a: BlsScalar, b: BlsScalar, result: BlsScalar) -> Self {
+        Self { a, b, result }
+    }
+}
+
+impl<const BITS: usize, const XOR: bool> Circuit<TatePairing> for LogicCircuit<BITS, XOR> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let result = composer.append_witness(self.result);
+
+        let output = if XOR {
+            composer.component_xor(a, b, BITS)
+        } else {
+            composer.component_and(a, b, BITS)
+        };
+
+        composer.assert_equal(output, result);
+
+        Ok(())
+    }
+}
+
+fn check<const BITS: usize, const XOR: bool>(values: &[(BlsScalar, BlsScalar, BlsScalar, bool)]) {
+    let mut pp = KeyPair::<TatePairing>::setup(8, BlsScalar::random(OsRng));
+    let (prover, verifier) =
+        Compiler::compile::<LogicCircuit<BITS, XOR>, TatePairing>(&mut pp, b"logic")
+            .expect("failed to compile circuit");
+
+    values.iter().for_each(|(a, b, result, valid)| {
+        let circuit = LogicCircuit::<BITS, XOR>::new(*a, *b, *result);
+        let proof = prover
+            .prove(&mut OsRng, &circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+        assert_eq!(
+            proof.is_ok(),
+            *valid,
+            "{BITS} bits logic of {a:?} and {b:?} into {result:?}"
+        );
+    });
+}
+
+fn and(a: u64, b: u64, valid: bool) -> (BlsScalar, BlsScalar, BlsScalar, bool) {
+    (a.into(), b.into(), (a & b).into(), valid)
+}
+
+fn xor(a: u64, b: u64, valid: bool) -> (BlsScalar, BlsScalar, BlsScalar, bool) {
+    (a.into(), b.into(), (a ^ b).into(), valid)
+}
+
+#[test]
+fn and_works() {
+    check::<0, false>(&[and(0, 0, true), and(1, 0, false)]);
+    check::<2, false>(&[and(3, 2, true), and(1, 2, true), and(4, 1, false)]);
+    check::<16, false>(&[
+        and(0xff0f, 0x0ff0, true),
+        and(0xffff, 0xffff, true),
+        and(0x10000, 0x1, false),
+    ]);
+    check::<64, false>(&[
+        and(u64::MAX, 0x0123_4567_89ab_cdef, true),
+        and(0xdead_beef_0000_0000, 0xffff_0000_ffff_0000, true),
+    ]);
+}
+
+#[test]
+fn xor_works() {
+    check::<0, true>(&[xor(0, 0, true), xor(0, 1, false)]);
+    check::<2, true>(&[xor(3, 2, true), xor(1, 2, true), xor(1, 4, false)]);
+    check::<16, true>(&[
+        xor(0xff0f, 0x0ff0, true),
+        xor(0xffff, 0xffff, true),
+        xor(0x1, 0x10000, false),
+    ]);
+    check::<64, true>(&[
+        xor(u64::MAX, 0x0123_4567_89ab_cdef, true),
+        xor(0xdead_beef_0000_0000, 0xffff_0000_ffff_0000, true),
+    ]);
+}
+
+#[test]
+fn logic_of_the_field() {
+    // the least significant byte of the largest scalar is zero
+    let a = -BlsScalar::one();
+    let b = BlsScalar::from(0xff);
+
+    check::<256, false>(&[(a, b, BlsScalar::zero(), true), (a, b, b, false)]);
+    check::<256, true>(&[(a, a, BlsScalar::zero(), true), (a, b, a + b, true)]);
+}
+
+#[test]
+fn logic_with_wrong_result() {
+    check::<16, false>(&[(0xff.into(), 0xf0.into(), 0xff.into(), false)]);
+    check::<16, true>(&[(0xff.into(), 0xf0.into(), 0xf0.into(), false)]);
+}
+
+#[test]
+#[should_panic]
+fn logic_of_odd_bits() {
+    check::<7, true>(&[]);
+}
@@ -0,0 +1,123 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+const BITS: usize = 4;
+
+// the 4 bits S-box of PRESENT, looked up with a zero middle column
+const SBOX: [u64; 16] = [
+    0xc, 0x5, 0x6, 0xb, 0x9, 0x0, 0xa, 0xd, 0x3, 0xe, 0xf, 0x8, 0x4, 0x7, 0x1, 0x2,
+];
+
+// a ^ b = c and sbox(c) = s, with the xor rows tagged apart from the S-box
+// ones by their middle column
+#[derive(Debug, Default)]
+struct LookupCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+    s: BlsScalar,
+}
+
+impl LookupCircuit {
+    fn new(a: u64, b: u64, c: u64, s: u64) -> Self {
+        Self {
+            a: a.into(),
+            b: b.into(),
+            c: c.into(),
+            s: s.into(),
+        }
+    }
+}
+
+fn table() -> LookupTable<TatePairing> {
+    let mut table = LookupTable::xor(BITS);
+
+    // tag the S-box rows so they don't collide with the xor ones
+    let tag = BlsScalar::from(1 << BITS);
+    SBOX.iter()
+        .enumerate()
+        .for_each(|(x, s)| table.insert(BlsScalar::from(x as u64), tag, BlsScalar::from(*s)));
+
+    table
+}
+
+impl Circuit<TatePairing> for LookupCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        composer.append_lookup_table(&table());
+
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_witness(self.c);
+        let s = composer.append_witness(self.s);
+        let tag = composer.append_constant(BlsScalar::from(1 << BITS));
+
+        composer.component_lookup(a, b, c);
+        composer.component_lookup(c, tag, s);
+
+        Ok(())
+    }
+}
+
+fn check(values: &[(LookupCircuit, bool)]) {
+    let mut pp = KeyPair::<TatePairing>::setup(10, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<LookupCircuit, TatePairing>(&mut pp, b"lookup")
+        .expect("failed to compile circuit");
+
+    values.iter().for_each(|(circuit, valid)| {
+        let proof = prover
+            .prove(&mut OsRng, circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+        assert_eq!(proof.is_ok(), *valid, "lookup of {circuit:?}");
+    });
+}
+
+#[test]
+fn lookup_works() {
+    let valid = |a: u64, b: u64| {
+        let c = a ^ b;
+        (LookupCircuit::new(a, b, c, SBOX[c as usize]), true)
+    };
+
+    check(&[
+        valid(0, 0),
+        valid(0xa, 0x5),
+        valid(0xf, 0xf),
+        valid(0x3, 0xc),
+    ]);
+}
+
+#[test]
+fn lookup_not_in_table() {
+    check(&[
+        // wrong xor
+        (LookupCircuit::new(0x3, 0x5, 0x7, SBOX[0x7]), false),
+        // wrong S-box
+        (LookupCircuit::new(0x3, 0x5, 0x6, SBOX[0x7]), false),
+        // operands out of the table
+        (LookupCircuit::new(0x13, 0x5, 0x16, SBOX[0x6]), false),
+    ]);
+}
+
+#[test]
+fn lookup_unsatisfied_gate() {
+    let mut builder = Builder::initialized(1 << 10);
+    let gates = builder.constraints();
+
+    LookupCircuit::new(0x3, 0x5, 0x7, SBOX[0x7])
+        .circuit(&mut builder)
+        .expect("the circuit is built");
+
+    // the constant tag takes a gate before the lookup ones
+    match builder.check_satisfied() {
+        Err(Error::UnsatisfiedGate { index }) => assert_eq!(index, gates + 1),
+        result => panic!("unexpected result {result:?}"),
+    }
+}
@@ -0,0 +1,121 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+#[derive(Debug, Default)]
+struct RangeCircuit<const BITS: usize> {
+    value: BlsScalar,
+}
+
+impl<const BITS: usize> RangeCircuit<BITS> {
+    fn new(value: BlsScalar) -> Self {
+        Self { value }
+    }
+}
+
+impl<const BITS: usize> Circuit<TatePairing> for RangeCircuit<BITS> {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let value = composer.append_witness(self.value);
+
+        composer.component_range(value, BITS);
+
+        Ok(())
+    }
+}
+
+fn check<const BITS: usize>(values: &[(BlsScalar, bool)]) {
+    let mut pp = KeyPair::<TatePairing>::setup(8, BlsScalar::random(OsRng));
+    let (prover, verifier) =
+        Compiler::compile::<RangeCircuit<BITS>, TatePairing>(&mut pp, b"range")
+            .expect("failed to compile circuit");
+
+    values.iter().for_each(|(value, valid)| {
+        let result = prover
+            .prove(&mut OsRng, &RangeCircuit::<BITS>::new(*value))
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs));
+
+        assert_eq!(result.is_ok(), *valid, "{BITS} bits range of {value:?}");
+    });
+}
+
+#[test]
+fn range_empty() {
+    check::<0>(&[(BlsScalar::zero(), true), (BlsScalar::one(), false)]);
+}
+
+#[test]
+fn range_within_a_gate() {
+    check::<2>(&[(BlsScalar::from(3), true), (BlsScalar::from(4), false)]);
+    check::<8>(&[
+        (BlsScalar::zero(), true),
+        (BlsScalar::from(255), true),
+        (BlsScalar::from(256), false),
+    ]);
+}
+
+#[test]
+fn range_with_padding() {
+    check::<10>(&[
+        (BlsScalar::from(1023), true),
+        (BlsScalar::from(1024), false),
+    ]);
+}
+
+#[test]
+fn range_across_gates() {
+    check::<16>(&[
+        (BlsScalar::from(0xffff), true),
+        (BlsScalar::from(0x10000), false),
+        (-BlsScalar::one(), false),
+    ]);
+    check::<64>(&[
+        (BlsScalar::from(u64::MAX), true),
+        (BlsScalar::from(u64::MAX) + BlsScalar::one(), false),
+    ]);
+}
+
+#[test]
+fn range_of_the_field() {
+    check::<256>(&[(-BlsScalar::one(), true)]);
+}
+
+#[test]
+#[should_panic]
+fn range_of_odd_bits() {
+    check::<7>(&[]);
+}
+
+#[test]
+fn range_report() {
+    let report = Compiler::report::<RangeCircuit<64>, TatePairing>().expect("failed to report");
+    assert_eq!(report.range_gates, 8);
+    assert_eq!(report.public_inputs, 0);
+    assert_eq!(report.domain_size, 16);
+
+    let k = report.domain_size.trailing_zeros() as u64;
+
+    let mut pp = KeyPair::<TatePairing>::setup(k, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<RangeCircuit<64>, TatePairing>(&mut pp, b"range")
+        .expect("failed to compile circuit");
+    let (proof, public_inputs) = prover
+        .prove(
+            &mut OsRng,
+            &RangeCircuit::<64>::new(BlsScalar::from(u64::MAX)),
+        )
+        .expect("failed to prove");
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    let mut pp = KeyPair::<TatePairing>::setup(k - 1, BlsScalar::random(OsRng));
+    assert!(matches!(
+        Compiler::compile::<RangeCircuit<64>, TatePairing>(&mut pp, b"range"),
+        Err(Error::PolynomialDegreeTooLarge)
+    ));
+}
@@ -0,0 +1,135 @@
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+// a * a^-1 = 1 for the public a, which is zero in the default circuit
+#[derive(Debug, Default)]
+struct InverseCircuit {
+    a: BlsScalar,
+}
+
+impl InverseCircuit {
+    fn new(a: u64) -> Self {
+        Self { a: a.into() }
+    }
+}
+
+impl Circuit<TatePairing> for InverseCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let a = composer.append_public(self.a);
+        let inverse = composer
+            .value(a)
+            .map(|a| a.invert().expect("the inverted value isn't zero"));
+        let inverse = composer.append_optional_witness(inverse);
+
+        composer.append_gate(
+            Constraint::new()
+                .mult(1)
+                .constant(-BlsScalar::one())
+                .a(a)
+                .b(inverse),
+        );
+
+        Ok(())
+    }
+}
+
+// whether a is zero and 1 / b with the gadgets, for the public a and b which
+// are zero in the default circuit
+#[derive(Debug, Default)]
+struct GadgetCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+}
+
+impl Circuit<TatePairing> for GadgetCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let a = composer.append_public(self.a);
+        let b = composer.append_public(self.b);
+
+        let is_zero = composer.component_is_zero(a);
+        let expected = BlsScalar::from((self.a == BlsScalar::zero()) as u64);
+        composer.assert_equal_constant(is_zero, 0, Some(-expected));
+
+        let inverse = composer.component_inverse(b);
+        let quotient = composer.component_div(C::ONE, b);
+        composer.assert_equal(inverse, quotient);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn setup_mode_compiles_without_values() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<InverseCircuit, TatePairing>(&mut pp, b"setup")
+        .expect("failed to compile circuit");
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &InverseCircuit::new(3))
+        .expect("failed to prove");
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+}
+
+#[test]
+fn setup_mode_compiles_gadgets() {
+    let mut pp = KeyPair::<TatePairing>::setup(8, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<GadgetCircuit, TatePairing>(&mut pp, b"setup")
+        .expect("failed to compile circuit");
+
+    for (a, b) in [(0, 3), (5, 7)] {
+        let circuit = GadgetCircuit {
+            a: a.into(),
+            b: b.into(),
+        };
+        let (proof, public_inputs) = prover.prove(&mut OsRng, &circuit).expect("failed to prove");
+
+        verifier
+            .verify(&proof, &public_inputs)
+            .expect("failed to verify proof");
+    }
+}
+
+#[test]
+fn setup_mode_records_the_shape() {
+    let setup = Compiler::report::<InverseCircuit, TatePairing>().expect("failed to report");
+    let assigned = Compiler::build::<_, TatePairing>(&InverseCircuit::new(3))
+        .expect("the circuit is built")
+        .report();
+
+    assert_eq!(setup, assigned);
+
+    let mut builder = Builder::<TatePairing>::setup(0);
+    let witness = builder.append_witness(BlsScalar::from(5));
+
+    assert!(builder.is_setup());
+    assert_eq!(builder.value(witness), None);
+    assert_eq!(
+        builder.value(Builder::<TatePairing>::ZERO),
+        Some(BlsScalar::zero())
+    );
+    assert_eq!(
+        builder.value(Builder::<TatePairing>::ONE),
+        Some(BlsScalar::one())
+    );
+}
+
+#[test]
+#[should_panic]
+fn unassigned_witness_when_proving() {
+    let mut builder = Builder::<TatePairing>::initialized(0);
+
+    builder.append_optional_witness(None::<BlsScalar>);
+}
@@ -0,0 +1,255 @@
+use parity_scale_codec::{Decode, Encode};
+use rand::rngs::OsRng;
+use zero_bls12_381::Fr as BlsScalar;
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::Group;
+
+#[derive(Debug)]
+struct DummyCircuit {
+    a: BlsScalar,
+    b: BlsScalar,
+    c: BlsScalar,
+}
+
+impl DummyCircuit {
+    fn new(a: u64, b: u64) -> Self {
+        Self {
+            a: BlsScalar::from(a),
+            b: BlsScalar::from(b),
+            c: BlsScalar::from(a * b),
+        }
+    }
+}
+
+impl Default for DummyCircuit {
+    fn default() -> Self {
+        Self::new(7, 11)
+    }
+}
+
+impl Circuit<TatePairing> for DummyCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let a = composer.append_witness(self.a);
+        let b = composer.append_witness(self.b);
+        let c = composer.append_witness(self.c);
+
+        let constraint = Constraint::new()
+            .mult(1)
+            .output(-BlsScalar::one())
+            .a(a)
+            .b(b)
+            .o(c);
+        composer.append_gate(constraint);
+
+        composer.assert_equal_constant(c, 0, Some(-self.c));
+
+        Ok(())
+    }
+}
+
+#[test]
+fn verify_valid_proof() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &DummyCircuit::new(3, 5))
+        .expect("failed to prove");
+
+    assert_eq!(public_inputs, vec![-BlsScalar::from(15)]);
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+}
+
+#[test]
+fn verify_wrong_public_inputs() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let (proof, _) = prover
+        .prove(&mut OsRng, &DummyCircuit::new(3, 5))
+        .expect("failed to prove");
+
+    assert!(verifier.verify(&proof, &[-BlsScalar::from(16)]).is_err());
+    assert!(matches!(
+        verifier.verify(&proof, &[]),
+        Err(Error::InconsistentPublicInputsLen {
+            expected: 1,
+            provided: 0
+        })
+    ));
+}
+
+#[test]
+fn verify_with_standalone_verifier_data() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &DummyCircuit::new(2, 9))
+        .expect("failed to prove");
+
+    let verifier_data = verifier.verifier_data().clone();
+    let verifier = Verifier::<DummyCircuit, TatePairing>::new(
+        b"verify",
+        verifier_data.clone(),
+        pp.opening_key(),
+    );
+
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+
+    // the transcript is bound to the compile label
+    let verifier =
+        Verifier::<DummyCircuit, TatePairing>::new(b"other", verifier_data, pp.opening_key());
+
+    assert!(verifier.verify(&proof, &public_inputs).is_err());
+}
+
+#[test]
+fn proof_and_verifier_data_bytes() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let (proof, public_inputs) = prover
+        .prove(&mut OsRng, &DummyCircuit::new(4, 6))
+        .expect("failed to prove");
+
+    let bytes = proof.to_bytes();
+    assert_eq!(bytes.len(), Proof::<TatePairing>::SIZE);
+    let proof = Proof::<TatePairing>::from_bytes(&bytes).expect("failed to decode proof");
+    assert_eq!(
+        Proof::decode(&mut proof.encode().as_slice()).ok(),
+        Some(proof.clone())
+    );
+
+    let bytes = verifier.verifier_data().to_bytes();
+    let verifier_data =
+        VerifierData::<TatePairing>::from_bytes(&bytes).expect("failed to decode verifier data");
+    assert_eq!(&verifier_data, verifier.verifier_data());
+    assert_eq!(
+        VerifierData::decode(&mut verifier_data.encode().as_slice()).ok(),
+        Some(verifier_data.clone())
+    );
+
+    let verifier =
+        Verifier::<DummyCircuit, TatePairing>::new(b"verify", verifier_data, pp.opening_key());
+    verifier
+        .verify(&proof, &public_inputs)
+        .expect("failed to verify proof");
+}
+
+#[test]
+fn malformed_proof_bytes() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, _) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let (proof, _) = prover
+        .prove(&mut OsRng, &DummyCircuit::new(4, 6))
+        .expect("failed to prove");
+    let bytes = proof.to_bytes();
+
+    // a point out of the curve or of its prime order subgroup
+    let mut point = bytes.clone();
+    point[47] ^= 1;
+    assert!(matches!(
+        Proof::<TatePairing>::from_bytes(&point),
+        Err(Error::PointMalformed)
+    ));
+    assert!(Proof::<TatePairing>::decode(&mut point.as_slice()).is_err());
+
+    // a scalar greater than the modulus
+    let mut scalar = bytes.clone();
+    scalar[Proof::<TatePairing>::SIZE - 32..].copy_from_slice(&[0xff; 32]);
+    assert!(matches!(
+        Proof::<TatePairing>::from_bytes(&scalar),
+        Err(Error::BlsScalarMalformed)
+    ));
+
+    assert!(matches!(
+        Proof::<TatePairing>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::NotEnoughBytes)
+    ));
+    assert!(matches!(
+        Proof::<TatePairing>::from_bytes(&[bytes.as_slice(), &[0]].concat()),
+        Err(Error::TrailingBytes)
+    ));
+}
+
+#[test]
+fn batch_verify_proofs() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (prover, verifier) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let mut proofs = [(3, 5), (2, 9), (7, 11)]
+        .iter()
+        .map(|(a, b)| {
+            prover
+                .prove(&mut OsRng, &DummyCircuit::new(*a, *b))
+                .expect("failed to prove")
+        })
+        .collect::<Vec<_>>();
+
+    verifier
+        .batch_verify(&proofs)
+        .expect("failed to verify proofs");
+
+    // a single wrong public input rejects the whole batch
+    proofs[1].1[0] += BlsScalar::one();
+    assert!(verifier.batch_verify(&proofs).is_err());
+
+    proofs[1].1.clear();
+    assert!(matches!(
+        verifier.batch_verify(&proofs),
+        Err(Error::InconsistentPublicInputsLen {
+            expected: 1,
+            provided: 0
+        })
+    ));
+
+    assert!(matches!(verifier.batch_verify(&[]), Err(Error::EmptyBatch)));
+}
+
+#[test]
+fn malformed_verifier_data_bytes() {
+    let mut pp = KeyPair::<TatePairing>::setup(5, BlsScalar::random(OsRng));
+    let (_, verifier) = Compiler::compile::<DummyCircuit, TatePairing>(&mut pp, b"verify")
+        .expect("failed to compile circuit");
+
+    let bytes = verifier.verifier_data().to_bytes();
+    let index = verifier.verifier_data().public_input_indexes()[0] as u32;
+    assert!(index > 0);
+
+    // the public input indexes follow the size and the 19 commitments
+    let offset = 8 + 19 * 48;
+    let with_indexes = |indexes: &[u32]| {
+        let mut malformed = bytes[..offset].to_vec();
+        malformed.extend_from_slice(&(indexes.len() as u32).to_le_bytes());
+        indexes
+            .iter()
+            .for_each(|index| malformed.extend_from_slice(&index.to_le_bytes()));
+        malformed.extend_from_slice(&bytes[offset + 8..]);
+        malformed
+    };
+
+    assert!(VerifierData::<TatePairing>::from_bytes(&with_indexes(&[index])).is_ok());
+    for indexes in [[index, index], [index, 0]] {
+        assert!(matches!(
+            VerifierData::<TatePairing>::from_bytes(&with_indexes(&indexes)),
+            Err(Error::InvalidVerifierDataBytes)
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (C) 2023-2024 Invers (JP) INC.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `#[derive(Circuit)]` for the `zero-r1cs` circuits
+//!
+//! The derive reads the inputs of the circuit from the attributes of its
+//! fields and generates
+//!
+//! - a `Default` implementation used to compile the circuit, with zero for
+//!   the scalars, the identity for the points and `Default::default` for the
+//!   fields without attribute
+//! - an `append_inputs` method allocating the inputs into the composer in the
+//!   field declaration order, returning a `<Name>Witnesses` struct which
+//!   holds the allocated witness of each input under the name of its field
+//!
+//! | Attribute         | Field type                  | Allocation                      |
+//! |-------------------|-----------------------------|---------------------------------|
+//! | `#[witness]`      | `Into<P::ScalarField>`      | `Composer::append_witness`      |
+//! | `#[public]`       | `Into<P::ScalarField>`      | `Composer::append_public`       |
+//! | `#[point]`        | `Into<P::JubjubAffine>`     | `Composer::append_point`        |
+//! | `#[public_point]` | `Into<P::JubjubAffine>`     | `Composer::append_public_point` |
+//!
+//! The public inputs of the circuit are then ordered as their fields are
+//! declared, before any public input appended by the circuit itself, and
+//! are declared with `Composer::declare_public_input` under the name of
+//! their field so they can be checked against a typed `PublicInputs`.
+//!
+//! The generated code refers to the `zero_r1cs` and `zkstd` crates, which must
+//! be dependencies of the crate deriving the circuit.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Type};
+
+#[proc_macro_derive(Circuit, attributes(witness, public, point, public_point))]
+pub fn derive_circuit(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// How a field is allocated into the composer
+#[derive(Clone, Copy)]
+enum Input {
+    Witness,
+    Public,
+    Point,
+    PublicPoint,
+}
+
+impl Input {
+    fn from_attributes(attrs: &[Attribute]) -> Result<Option<Self>, Error> {
+        let mut input = None;
+
+        for attr in attrs {
+            let kind = if attr.path().is_ident("witness") {
+                Self::Witness
+            } else if attr.path().is_ident("public") {
+                Self::Public
+            } else if attr.path().is_ident("point") {
+                Self::Point
+            } else if attr.path().is_ident("public_point") {
+                Self::PublicPoint
+            } else {
+                continue;
+            };
+
+            attr.meta.require_path_only()?;
+
+            if input.replace(kind).is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "a circuit input takes a single attribute",
+                ));
+            }
+        }
+
+        Ok(input)
+    }
+
+    fn is_point(self) -> bool {
+        matches!(self, Self::Point | Self::PublicPoint)
+    }
+
+    fn append(self) -> Ident {
+        match self {
+            Self::Witness => format_ident!("append_witness"),
+            Self::Public => format_ident!("append_public"),
+            Self::Point => format_ident!("append_point"),
+            Self::PublicPoint => format_ident!("append_public_point"),
+        }
+    }
+
+    fn declaration(self, name: &str) -> Option<TokenStream2> {
+        match self {
+            Self::Public => Some(quote!(::zero_r1cs::prelude::PublicInput::scalar(#name))),
+            Self::PublicPoint => Some(quote!(::zero_r1cs::prelude::PublicInput::point(#name))),
+            Self::Witness | Self::Point => None,
+        }
+    }
+
+    fn default(self, ty: &Type) -> TokenStream2 {
+        // the curve points default to the identity and the scalars to zero
+        // rather than to their `Default`, which is one
+        if self.is_point() {
+            quote!(<#ty as ::core::default::Default>::default())
+        } else {
+            quote!(<#ty as ::zkstd::common::Group>::zero())
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "a circuit must be a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "a circuit must be a struct with named fields",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let witnesses = format_ident!("{}Witnesses", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|clause| &clause.predicates);
+
+    let mut defaults = Vec::new();
+    let mut witness_fields = Vec::new();
+    let mut bounds = Vec::new();
+    let mut allocations = Vec::new();
+    let mut idents = Vec::new();
+
+    for field in fields {
+        let ident = field.ident.as_ref().expect("the fields are named");
+        let ty = &field.ty;
+
+        let input = match Input::from_attributes(&field.attrs)? {
+            Some(input) => input,
+            None => {
+                defaults.push(quote!(#ident: ::core::default::Default::default()));
+                continue;
+            }
+        };
+
+        let field_vis = &field.vis;
+        let append = input.append();
+        let doc = format!("Allocated `{}`", ident);
+        let (witness, target) = if input.is_point() {
+            (
+                quote!(::zero_r1cs::prelude::WitnessPoint),
+                quote!(P::JubjubAffine),
+            )
+        } else {
+            (
+                quote!(::zero_r1cs::prelude::Witness),
+                quote!(P::ScalarField),
+            )
+        };
+
+        defaults.push({
+            let default = input.default(ty);
+            quote!(#ident: #default)
+        });
+        witness_fields.push(quote! {
+            #[doc = #doc]
+            #field_vis #ident: #witness
+        });
+        bounds.push(quote!(#ty: ::core::convert::Into<#target> + ::core::clone::Clone));
+        allocations.push(quote! {
+            let #ident = composer.#append(::core::clone::Clone::clone(&self.#ident));
+        });
+        if let Some(declaration) = input.declaration(&ident.to_string()) {
+            allocations.push(quote!(composer.declare_public_input(#declaration);));
+        }
+        idents.push(ident);
+    }
+
+    let witnesses_doc = format!("Inputs of [`{}`] allocated into a composer", name);
+
+    Ok(quote! {
+        impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#defaults,)*
+                }
+            }
+        }
+
+        #[doc = #witnesses_doc]
+        #[allow(dead_code)]
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #witnesses {
+            #(#witness_fields,)*
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Allocate the inputs of the circuit in the field declaration
+            /// order, so the public inputs are ordered likewise
+            #vis fn append_inputs<P, C>(&self, composer: &mut C) -> #witnesses
+            where
+                P: ::zkstd::common::Pairing,
+                C: ::zero_r1cs::prelude::Composer<P>,
+                #(#bounds,)*
+                #predicates
+            {
+                #(#allocations)*
+
+                #witnesses {
+                    #(#idents,)*
+                }
+            }
+        }
+    })
+}
@@ -28,6 +28,9 @@ pub(crate) const SAPLING_REDJUBJUB_COFACTOR: Fr = Fr::to_mont_form([
     0x0000000000000000,
 ]);
 
+/// Bits the Poseidon challenge is truncated to, so it is a jubjub scalar
+pub(crate) const CHALLENGE_BITS: usize = 250;
+
 // signing context
 pub(crate) const SIGNING_CTX: &[u8] = b"substrate";
 
@@ -0,0 +1,56 @@
+//! Verification of the signatures of
+//! [`SecretKey::sign_poseidon`](crate::SecretKey::sign_poseidon) in circuits
+//!
+//! The challenge is recomputed with the Poseidon gadget, so a proof can show
+//! a registered key signed a message without revealing the key.
+
+use super::constant::{CHALLENGE_BITS, SAPLING_BASE_POINT};
+
+use zero_bls12_381::Fr;
+use zero_jubjub::JubjubExtended;
+use zero_poseidon::hash_gadget;
+use zero_r1cs::prelude::{Composer, Error, Witness, WitnessPoint};
+use zkstd::common::Pairing;
+
+/// Bits of a scalar of the circuit
+const SCALAR_BITS: usize = 255;
+
+/// Constrain `(r, s)` to be a signature of `message` by `public_key`
+///
+/// The challenge `c` is the Poseidon hash of `r`, `public_key` and `message`
+/// truncated like the native one, and `s · G = R + c · vk` is asserted for
+/// the sapling base point `G`. Unlike the native verification, the equation
+/// isn't multiplied by the cofactor, so the points are expected in the prime
+/// order subgroup, like the ones of the signatures and the keys.
+///
+/// Will error if `s` isn't a jubjub scalar.
+pub fn verify_gadget<P, C>(
+    composer: &mut C,
+    public_key: WitnessPoint,
+    message: Witness,
+    r: WitnessPoint,
+    s: Witness,
+) -> Result<(), Error>
+where
+    P: Pairing<ScalarField = Fr, JubjubExtended = JubjubExtended>,
+    C: Composer<P>,
+{
+    let hash = hash_gadget(
+        composer,
+        &[*r.x(), *r.y(), *public_key.x(), *public_key.y(), message],
+    );
+
+    // the decomposition isn't checked to be canonical, so a prover may pick
+    // the one of `hash` plus the modulus when it's below `2^255`, which gives
+    // at most two challenges per hash and the signature only holds for one
+    let bits = composer.component_decomposition::<SCALAR_BITS>(hash);
+    let challenge = composer.component_pack(&bits[..CHALLENGE_BITS]);
+
+    let s_g = composer.component_mul_generator(s, JubjubExtended::from(SAPLING_BASE_POINT))?;
+    let c_vk = composer.component_mul_point(challenge, public_key);
+    let r_c_vk = composer.component_add_point(r, c_vk);
+
+    composer.assert_equal_point(s_g, r_c_vk);
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use super::constant::{KOGARASHI_PERSONAL, SAPLING_PERSONAL};
+use super::constant::{CHALLENGE_BITS, KOGARASHI_PERSONAL, SAPLING_PERSONAL};
 
 use blake2b_simd::{Params, State};
-use zero_jubjub::Fp;
+use zero_bls12_381::Fr;
+use zero_jubjub::{Fp, JubjubAffine};
+use zkstd::behave::{Curve, SigUtils};
 
 pub(crate) fn sapling_hash(a: &[u8], b: &[u8], c: &[u8]) -> Fp {
     SaplingHash::default()
@@ -36,6 +38,18 @@ impl SaplingHash {
     }
 }
 
+/// Challenge of the signatures verified in circuits, the Poseidon hash of
+/// `R`, `vk` and the message truncated to its low `CHALLENGE_BITS` bits
+pub(crate) fn poseidon_challenge(r: JubjubAffine, vk: JubjubAffine, m: Fr) -> Fp {
+    let hash = zero_poseidon::hash(&[r.get_x(), r.get_y(), vk.get_x(), vk.get_y(), m]);
+
+    let mut bytes = hash.to_bytes();
+    bytes[CHALLENGE_BITS / 8] &= (1 << (CHALLENGE_BITS % 8)) - 1;
+    bytes[CHALLENGE_BITS / 8 + 1..].fill(0);
+
+    Fp::from_bytes(bytes).expect("the truncated challenge is a jubjub scalar")
+}
+
 pub(crate) fn kogarashi_hash(seed: &[u8]) -> Fp {
     KogarashiHash::default().update(seed).finalize()
 }
@@ -17,6 +17,7 @@
 #![doc = include_str!("../README.md")]
 
 mod constant;
+mod gadget;
 mod hash;
 mod keyring;
 mod private_key;
@@ -24,6 +25,7 @@ mod public_key;
 mod signature;
 
 use constant::SIGNING_CTX;
+pub use gadget::verify_gadget;
 use hash::kogarashi_hash;
 pub use private_key::SecretKey;
 pub use public_key::{Public, PublicKey};
@@ -1,10 +1,11 @@
 use super::constant::SAPLING_BASE_POINT;
-use super::hash::sapling_hash;
+use super::hash::{poseidon_challenge, sapling_hash};
 use super::public_key::PublicKey;
 use super::signature::Signature;
 
 use rand_core::RngCore;
-use zero_jubjub::Fp;
+use zero_bls12_381::Fr;
+use zero_jubjub::{Fp, JubjubAffine};
 use zkstd::behave::SigUtils;
 
 #[derive(Clone, Copy, Debug)]
@@ -46,6 +47,29 @@ impl SecretKey {
         Signature::new(R, S)
     }
 
+    /// Sign the scalar `m` with the Poseidon challenge, so the signature
+    /// can be verified in a circuit with
+    /// [`verify_gadget`](crate::verify_gadget)
+    #[allow(non_snake_case)]
+    pub fn sign_poseidon(&self, m: Fr, mut rand: impl RngCore) -> Signature {
+        // T uniformly at random
+        let mut T = [0u8; 80];
+        rand.fill_bytes(&mut T[..]);
+
+        // r = H(T||vk||M)
+        let pk = self.to_public_key();
+        let r = sapling_hash(&T, &pk.to_bytes(), &m.to_bytes());
+
+        // R = r * P_G
+        let R = JubjubAffine::from(r * SAPLING_BASE_POINT);
+
+        // S = r + H_poseidon(R||vk||m) * sk
+        let c = poseidon_challenge(R, JubjubAffine::from(pk.0), m);
+        let S = (r + c * self.0).to_bytes();
+
+        Signature::new(R.to_bytes(), S)
+    }
+
     pub fn to_public_key(&self) -> PublicKey {
         PublicKey(SAPLING_BASE_POINT * self.0)
     }
@@ -1,5 +1,5 @@
 use super::constant::{CRYPTO_ID, SAPLING_BASE_POINT, SAPLING_REDJUBJUB_COFACTOR};
-use super::hash::sapling_hash;
+use super::hash::{poseidon_challenge, sapling_hash};
 use super::signature::Signature;
 use super::Pair;
 
@@ -57,6 +57,26 @@ impl PublicKey {
         (SAPLING_REDJUBJUB_COFACTOR * (-(S * SAPLING_BASE_POINT) + R + c * self.0)).is_identity()
     }
 
+    /// Verify a signature of the scalar `m` by
+    /// [`SecretKey::sign_poseidon`](crate::SecretKey::sign_poseidon)
+    #[allow(non_snake_case)]
+    pub fn validate_poseidon(self, m: Fr, sig: Signature) -> bool {
+        let R = match JubjubAffine::from_bytes(sig.r) {
+            Some(R) => R,
+            None => return false,
+        };
+        let S = match Fr::from_bytes(sig.s) {
+            Some(S) => S,
+            None => return false,
+        };
+
+        // c = H_poseidon(R||vk||m)
+        let c = poseidon_challenge(R, JubjubAffine::from(self.0), m);
+
+        // h_G(-S * P_G + R + c * vk)
+        (SAPLING_REDJUBJUB_COFACTOR * (-(S * SAPLING_BASE_POINT) + R + c * self.0)).is_identity()
+    }
+
     #[allow(non_snake_case)]
     pub fn verify_simple_preaudit_deprecated(
         &self,
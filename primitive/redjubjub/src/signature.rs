@@ -4,6 +4,8 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use sp_core::crypto::CryptoType;
 use sp_runtime_interface::pass_by::PassByInner;
+use zero_bls12_381::Fr;
+use zero_jubjub::JubjubAffine;
 use zkstd::behave::SigUtils;
 
 #[derive(Clone)]
@@ -39,6 +41,16 @@ impl Signature {
         let bytes: [u8; Self::LENGTH] = bytes[..64].try_into().unwrap();
         Self::from_bytes(bytes)
     }
+
+    /// Commitment point `R` of the signature
+    pub fn r(&self) -> Option<JubjubAffine> {
+        JubjubAffine::from_bytes(self.r)
+    }
+
+    /// Response scalar `S` of the signature
+    pub fn s(&self) -> Option<Fr> {
+        Fr::from_bytes(self.s)
+    }
 }
 
 #[derive(Debug, Decode, Encode, PassByInner)]
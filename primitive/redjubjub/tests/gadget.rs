@@ -0,0 +1,106 @@
+use rand::rngs::OsRng;
+use redjubjub::{verify_gadget, SecretKey};
+use zero_bls12_381::Fr as BlsScalar;
+use zero_jubjub::{Fp, JubjubAffine};
+use zero_kzg::KeyPair;
+use zero_pairing::TatePairing;
+use zero_r1cs::prelude::*;
+use zkstd::common::{CurveGroup, Group, SigUtils};
+
+#[derive(Debug)]
+struct SignatureCircuit {
+    public_key: JubjubAffine,
+    message: BlsScalar,
+    r: JubjubAffine,
+    s: BlsScalar,
+}
+
+impl Default for SignatureCircuit {
+    fn default() -> Self {
+        Self {
+            public_key: JubjubAffine::ADDITIVE_GENERATOR,
+            message: BlsScalar::zero(),
+            r: JubjubAffine::ADDITIVE_GENERATOR,
+            s: BlsScalar::zero(),
+        }
+    }
+}
+
+impl Circuit<TatePairing> for SignatureCircuit {
+    fn circuit<C>(&self, composer: &mut C) -> Result<(), Error>
+    where
+        C: Composer<TatePairing>,
+    {
+        let public_key = composer.append_public_point(self.public_key);
+        let message = composer.append_public(self.message);
+        let r = composer.append_point(self.r);
+        let s = composer.append_witness(self.s);
+
+        verify_gadget(composer, public_key, message, r, s)
+    }
+}
+
+fn signed_circuit(message: BlsScalar) -> (SecretKey, SignatureCircuit) {
+    let secret_key = SecretKey::from_bytes(Fp::random(OsRng).to_bytes()).unwrap();
+    let public_key = JubjubAffine::from_bytes(secret_key.to_public_key().to_bytes()).unwrap();
+    let signature = secret_key.sign_poseidon(message, OsRng);
+
+    assert!(secret_key
+        .to_public_key()
+        .validate_poseidon(message, signature.clone()));
+
+    let circuit = SignatureCircuit {
+        public_key,
+        message,
+        r: signature.r().expect("the commitment is a point"),
+        s: signature.s().expect("the response is a scalar"),
+    };
+
+    (secret_key, circuit)
+}
+
+#[test]
+fn verify_gadget_satisfied() {
+    let (_, circuit) = signed_circuit(BlsScalar::random(OsRng));
+    let mut builder = Builder::<TatePairing>::initialized(1 << 13);
+
+    circuit
+        .circuit(&mut builder)
+        .expect("the response is a jubjub scalar");
+
+    builder.check_satisfied().expect("the signature is valid");
+}
+
+#[test]
+fn signature_proof() {
+    let mut pp = KeyPair::<TatePairing>::setup(13, BlsScalar::random(OsRng));
+    let (prover, verifier) =
+        Compiler::compile::<SignatureCircuit, TatePairing>(&mut pp, b"redjubjub")
+            .expect("failed to compile circuit");
+
+    let prove = |circuit: &SignatureCircuit| {
+        prover
+            .prove(&mut OsRng, circuit)
+            .and_then(|(proof, public_inputs)| verifier.verify(&proof, &public_inputs))
+    };
+
+    let (secret_key, circuit) = signed_circuit(BlsScalar::random(OsRng));
+    prove(&circuit).expect("the signature is valid");
+
+    // signature of another message
+    let signature = secret_key.sign_poseidon(BlsScalar::random(OsRng), OsRng);
+    let forged = SignatureCircuit {
+        r: signature.r().unwrap(),
+        s: signature.s().unwrap(),
+        ..circuit
+    };
+    assert!(prove(&forged).is_err());
+
+    // signature by another key
+    let (_, other) = signed_circuit(circuit.message);
+    let forged = SignatureCircuit {
+        public_key: other.public_key,
+        ..circuit
+    };
+    assert!(prove(&forged).is_err());
+}
@@ -17,6 +17,6 @@ use zero_kzg::*;
 #[allow(unused_imports)]
 use zero_pairing::*;
 #[allow(unused_imports)]
-use zero_plonk::*;
+use zero_r1cs::*;
 #[allow(unused_imports)]
 use zkstd::*;